@@ -0,0 +1,91 @@
+package backup
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// Name is the file name of a backup made at now.
+func Name(now time.Time) string {
+	return "nb-" + now.Format("2006-01-02T150405") + ".tar.gz"
+}
+
+// IsBackup is whether name is the name of a backup nb made.
+func IsBackup(name string) bool {
+	ok, _ := filepath.Match("nb-*.tar.gz", name)
+	return ok
+}
+
+// Archive writes every file under root, except the .git folder, to w as a
+// gzipped tarball with paths relative to root. The file at output, which the
+// tarball is being written to, and earlier backups are left out, so that
+// backups made inside the notebook do not contain themselves or each other.
+func Archive(root string, w io.Writer, output string) error {
+	output, _ = filepath.Abs(output)
+
+	gz := gzip.NewWriter(w)
+	tw := tar.NewWriter(gz)
+
+	err := filepath.WalkDir(root, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+
+		if d.IsDir() {
+			if d.Name() == ".git" {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+
+		if !d.Type().IsRegular() || IsBackup(d.Name()) {
+			return nil
+		}
+		if abs, err := filepath.Abs(path); err == nil && abs == output {
+			return nil
+		}
+
+		rel, err := filepath.Rel(root, path)
+		if err != nil {
+			return err
+		}
+
+		info, err := d.Info()
+		if err != nil {
+			return err
+		}
+
+		header, err := tar.FileInfoHeader(info, "")
+		if err != nil {
+			return err
+		}
+		header.Name = filepath.ToSlash(rel)
+
+		if err := tw.WriteHeader(header); err != nil {
+			return err
+		}
+
+		f, err := os.Open(path)
+		if err != nil {
+			return err
+		}
+		defer f.Close()
+
+		_, err = io.Copy(tw, f)
+		return err
+	})
+	if err != nil {
+		return err
+	}
+
+	if err := tw.Close(); err != nil {
+		return err
+	}
+
+	return gz.Close()
+}
@@ -0,0 +1,122 @@
+package board
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+type Column struct {
+	Status string
+	Tasks  []task.Task
+}
+
+// Load gathers the tasks from the logs of the given number of days up to
+// today. A task carried across several logs is only kept from the latest.
+func Load(logDir string, today time.Time, days int) ([]task.Task, error) {
+	all, err := task.FromLogs(logDir)
+	if err != nil {
+		return nil, err
+	}
+
+	cutoff := today.AddDate(0, 0, -days).Format(note.DateFormat)
+
+	latest := map[string]int{}
+	tasks := []task.Task{}
+	for _, t := range all {
+		if day, ok := logpath.Date(logDir, t.Path, today.Location()); !ok || day.Format(note.DateFormat) <= cutoff {
+			continue
+		}
+
+		if i, ok := latest[t.ID()]; ok {
+			tasks[i] = t
+			continue
+		}
+
+		latest[t.ID()] = len(tasks)
+		tasks = append(tasks, t)
+	}
+
+	return tasks, nil
+}
+
+// Columns groups the tasks by status, with todo, doing, and done first and any
+// other statuses after them alphabetically.
+func Columns(tasks []task.Task) []Column {
+	byStatus := map[string][]task.Task{}
+	for _, t := range tasks {
+		byStatus[t.Status] = append(byStatus[t.Status], t)
+	}
+
+	columns := []Column{}
+	for _, status := range []string{task.Todo, task.Doing, task.Done} {
+		columns = append(columns, Column{status, byStatus[status]})
+		delete(byStatus, status)
+	}
+
+	others := []string{}
+	for status := range byStatus {
+		others = append(others, status)
+	}
+	sort.Strings(others)
+
+	for _, status := range others {
+		columns = append(columns, Column{status, byStatus[status]})
+	}
+
+	return columns
+}
+
+// Render draws the columns side by side, each the given width.
+func Render(columns []Column, width int) string {
+	rows := 0
+	for _, c := range columns {
+		if len(c.Tasks) > rows {
+			rows = len(c.Tasks)
+		}
+	}
+
+	var b strings.Builder
+
+	cells := []string{}
+	for _, c := range columns {
+		cells = append(cells, fmt.Sprintf("%s (%d)", strings.ToUpper(c.Status), len(c.Tasks)))
+	}
+	writeRow(&b, cells, width)
+
+	cells = cells[:0]
+	for range columns {
+		cells = append(cells, strings.Repeat("─", width-1))
+	}
+	writeRow(&b, cells, width)
+
+	for i := 0; i < rows; i++ {
+		cells = cells[:0]
+		for _, c := range columns {
+			if i < len(c.Tasks) {
+				cells = append(cells, c.Tasks[i].ID()+" "+c.Tasks[i].Text)
+			} else {
+				cells = append(cells, "")
+			}
+		}
+		writeRow(&b, cells, width)
+	}
+
+	return b.String()
+}
+
+func writeRow(b *strings.Builder, cells []string, width int) {
+	for _, cell := range cells {
+		runes := []rune(cell)
+		if len(runes) > width-1 {
+			runes = append(runes[:width-2], '…')
+		}
+		b.WriteString(string(runes) + strings.Repeat(" ", width-len(runes)))
+	}
+	b.WriteString("\n")
+}
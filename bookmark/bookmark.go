@@ -0,0 +1,99 @@
+package bookmark
+
+import (
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Bookmark is a line such as
+// "- [ ] [Title](https://example.com) #go #reading (2025-03-04)". An unchecked
+// box means the link has not been read yet.
+type Bookmark struct {
+	Title string
+	URL   string
+	Tags  []string
+	Added string
+	Read  bool
+}
+
+var line = regexp.MustCompile(`^- \[([ xX])\] \[(.*)\]\((\S+)\)(.*)$`)
+
+// Path is the note bookmarks are added to: Bookmarks.md, or a note per month
+// in the Bookmarks folder.
+func Path(root string, now time.Time, perMonth bool) string {
+	if perMonth {
+		return filepath.Join(root, "Bookmarks", now.Format("2006-01")+".md")
+	}
+
+	return filepath.Join(root, "Bookmarks.md")
+}
+
+func (b Bookmark) String() string {
+	mark := " "
+	if b.Read {
+		mark = "x"
+	}
+
+	s := "- [" + mark + "] [" + b.Title + "](" + b.URL + ")"
+	for _, tag := range b.Tags {
+		s += " #" + strings.TrimPrefix(tag, "#")
+	}
+
+	return s + " (" + b.Added + ")"
+}
+
+func Add(path string, b Bookmark) error {
+	return note.Capture(path, "", b.String())
+}
+
+// List reads the bookmarks from Bookmarks.md and the notes in the Bookmarks
+// folder.
+func List(root string) ([]Bookmark, error) {
+	paths, err := filepath.Glob(filepath.Join(root, "Bookmarks", "*.md"))
+	if err != nil {
+		return nil, err
+	}
+	paths = append([]string{filepath.Join(root, "Bookmarks.md")}, paths...)
+
+	bookmarks := []Bookmark{}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if os.IsNotExist(err) {
+			continue
+		} else if err != nil {
+			return nil, err
+		}
+
+		for _, l := range strings.Split(string(content), "\n") {
+			if b, ok := parse(l); ok {
+				bookmarks = append(bookmarks, b)
+			}
+		}
+	}
+
+	return bookmarks, nil
+}
+
+func parse(l string) (Bookmark, bool) {
+	match := line.FindStringSubmatch(strings.TrimSpace(l))
+	if match == nil {
+		return Bookmark{}, false
+	}
+
+	b := Bookmark{Title: match[2], URL: match[3], Read: match[1] != " "}
+	for _, field := range strings.Fields(match[4]) {
+		switch {
+		case strings.HasPrefix(field, "#"):
+			b.Tags = append(b.Tags, strings.TrimPrefix(field, "#"))
+		case strings.HasPrefix(field, "(") && strings.HasSuffix(field, ")"):
+			b.Added = strings.Trim(field, "()")
+		}
+	}
+
+	return b, true
+}
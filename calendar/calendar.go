@@ -0,0 +1,200 @@
+package calendar
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"os"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+// Event is an entry on the calendar. All day events ignore the time of Start
+// and End.
+type Event struct {
+	UID     string
+	Summary string
+	Start   time.Time
+	End     time.Time
+	AllDay  bool
+
+	rule    *rule
+	exdates map[string]bool
+}
+
+// eventLine matches lines such as "- event: 14:00-15:00 Dentist" or
+// "event: 2025-03-04 Conference". Without a date the event is on the day of
+// the log, and without a time it lasts all day.
+var eventLine = regexp.MustCompile(`(?i)^\s*(?:[-*]\s+)?event:\s*(?:(\d{4}-\d{2}-\d{2})\s+)?(?:(\d{1,2}:\d{2})(?:\s*-\s*(\d{1,2}:\d{2}))?\s+)?(.+)$`)
+
+// FromLogs gathers the events written in the logs and the open tasks that have
+// a due date. A task carried across several logs only appears once, and
+// private logs are left out.
+func FromLogs(logDir string) ([]Event, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return nil, err
+	}
+
+	events := []Event{}
+	tasks := map[string]Event{}
+	for _, path := range paths {
+		day, ok := logpath.Date(logDir, path, time.Local)
+		if !ok || note.IsPrivate(path) {
+			continue
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		for _, line := range strings.Split(string(content), "\n") {
+			if e, ok := parseEvent(line, day); ok {
+				events = append(events, e)
+			}
+		}
+
+		for _, t := range task.Parse(path, string(content)) {
+			due, ok := t.Due()
+			if !ok {
+				continue
+			}
+
+			if t.Done {
+				delete(tasks, t.ID())
+				continue
+			}
+
+			tasks[t.ID()] = Event{
+				UID:     t.ID() + "@nb",
+				Summary: "☐ " + t.Text,
+				Start:   due,
+				End:     due.AddDate(0, 0, 1),
+				AllDay:  true,
+			}
+		}
+	}
+
+	for _, e := range tasks {
+		events = append(events, e)
+	}
+
+	return events, nil
+}
+
+func parseEvent(line string, day time.Time) (Event, bool) {
+	match := eventLine.FindStringSubmatch(line)
+	if match == nil {
+		return Event{}, false
+	}
+
+	if match[1] != "" {
+		d, err := time.ParseInLocation(note.DateFormat, match[1], time.Local)
+		if err != nil {
+			return Event{}, false
+		}
+		day = d
+	}
+
+	e := Event{Summary: strings.TrimSpace(match[4]), Start: day, End: day.AddDate(0, 0, 1), AllDay: true}
+
+	if match[2] != "" {
+		start, ok := at(day, match[2])
+		if !ok {
+			return Event{}, false
+		}
+
+		e.AllDay = false
+		e.Start, e.End = start, start.Add(time.Hour)
+
+		if match[3] != "" {
+			if end, ok := at(day, match[3]); ok && end.After(start) {
+				e.End = end
+			}
+		}
+	}
+
+	sum := sha1.Sum([]byte(e.Start.String() + e.Summary))
+	e.UID = hex.EncodeToString(sum[:])[:16] + "@nb"
+
+	return e, true
+}
+
+func at(day time.Time, clock string) (time.Time, bool) {
+	t, err := time.Parse("15:04", clock)
+	if err != nil {
+		return time.Time{}, false
+	}
+
+	return time.Date(day.Year(), day.Month(), day.Day(), t.Hour(), t.Minute(), 0, 0, day.Location()), true
+}
+
+// ICS renders the events as an iCalendar file.
+func ICS(events []Event, now time.Time) string {
+	lines := []string{
+		"BEGIN:VCALENDAR",
+		"VERSION:2.0",
+		"PRODID:-//nb//nb//EN",
+		"CALSCALE:GREGORIAN",
+	}
+
+	for _, e := range events {
+		lines = append(lines,
+			"BEGIN:VEVENT",
+			"UID:"+e.UID,
+			"DTSTAMP:"+now.UTC().Format("20060102T150405Z"),
+		)
+
+		if e.AllDay {
+			lines = append(lines,
+				"DTSTART;VALUE=DATE:"+e.Start.Format("20060102"),
+				"DTEND;VALUE=DATE:"+e.End.Format("20060102"),
+			)
+		} else {
+			lines = append(lines,
+				"DTSTART:"+e.Start.UTC().Format("20060102T150405Z"),
+				"DTEND:"+e.End.UTC().Format("20060102T150405Z"),
+			)
+		}
+
+		lines = append(lines, "SUMMARY:"+escape(e.Summary), "END:VEVENT")
+	}
+
+	lines = append(lines, "END:VCALENDAR")
+
+	var b strings.Builder
+	for _, line := range lines {
+		b.WriteString(fold(line))
+	}
+
+	return b.String()
+}
+
+func escape(text string) string {
+	return strings.NewReplacer(`\`, `\\`, ";", `\;`, ",", `\,`, "\n", `\n`).Replace(text)
+}
+
+// fold splits a content line into lines of at most 75 bytes, without breaking
+// a UTF-8 character, each ending in CRLF.
+func fold(line string) string {
+	var b strings.Builder
+
+	width := 0
+	for _, r := range line {
+		size := len(string(r))
+		if width+size > 75 {
+			b.WriteString("\r\n ")
+			width = 1
+		}
+		b.WriteRune(r)
+		width += size
+	}
+	b.WriteString("\r\n")
+
+	return b.String()
+}
@@ -0,0 +1,81 @@
+package calendar
+
+import (
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestParseEvent(t *testing.T) {
+	day := time.Date(2022, 3, 4, 0, 0, 0, 0, time.Local)
+
+	cases := []struct {
+		given    string
+		expected Event
+	}{
+		{
+			given: "- event: 14:00-15:30 Dentist",
+			expected: Event{
+				Summary: "Dentist",
+				Start:   time.Date(2022, 3, 4, 14, 0, 0, 0, time.Local),
+				End:     time.Date(2022, 3, 4, 15, 30, 0, 0, time.Local),
+			},
+		},
+		{
+			given: "event: 2022-03-10 Conference",
+			expected: Event{
+				Summary: "Conference",
+				Start:   time.Date(2022, 3, 10, 0, 0, 0, 0, time.Local),
+				End:     time.Date(2022, 3, 11, 0, 0, 0, 0, time.Local),
+				AllDay:  true,
+			},
+		},
+	}
+
+	for _, c := range cases {
+		actual, ok := parseEvent(c.given, day)
+		if !ok || actual.Summary != c.expected.Summary || !actual.Start.Equal(c.expected.Start) || !actual.End.Equal(c.expected.End) || actual.AllDay != c.expected.AllDay {
+			t.Fatalf("given: %s\nexpected: %+v\nactual: %+v\n", c.given, c.expected, actual)
+		}
+	}
+
+	if _, ok := parseEvent("- [ ] plan the event: nothing", day); ok {
+		t.Fatalf("given: a task mentioning event:\nexpected: no event\nactual: an event\n")
+	}
+}
+
+func TestParseICS(t *testing.T) {
+	given := "BEGIN:VCALENDAR\r\n" +
+		"BEGIN:VEVENT\r\n" +
+		"DTSTART:20220301T090000\r\n" +
+		"DTEND:20220301T093000\r\n" +
+		"RRULE:FREQ=WEEKLY;BYDAY=TU,TH\r\n" +
+		"SUMMARY:Standup\\, da\r\n" +
+		" ily\r\n" +
+		"END:VEVENT\r\n" +
+		"BEGIN:VEVENT\r\n" +
+		"DTSTART;VALUE=DATE:20220303\r\n" +
+		"SUMMARY:Offsite\r\n" +
+		"END:VEVENT\r\n" +
+		"END:VCALENDAR\r\n"
+
+	events, err := ParseICS(strings.NewReader(given))
+	if err != nil {
+		t.Fatalf("given: %q\nexpected: no error\nactual: %v\n", given, err)
+	}
+
+	cases := map[string]string{
+		"2022-03-02": "",
+		"2022-03-03": "- All day: Offsite\n- 09:00-09:30 Standup, daily",
+		"2022-03-08": "- 09:00-09:30 Standup, daily",
+	}
+
+	for day, expected := range cases {
+		d, _ := time.ParseInLocation("2006-01-02", day, time.Local)
+
+		actual := Schedule(On(events, d))
+		if expected != actual {
+			t.Fatalf("given: %q on %s\nexpected: %q\nactual: %q\n", given, day, expected, actual)
+		}
+	}
+}
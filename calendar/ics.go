@@ -0,0 +1,289 @@
+package calendar
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/network"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/rfc"
+)
+
+// rule is the part of an RRULE that nb understands.
+type rule struct {
+	freq     string
+	interval int
+	until    time.Time
+	count    int
+	byDay    []time.Weekday
+}
+
+// Fetch downloads an iCalendar file, such as the secret address of a Google
+// calendar or the export of a CalDAV calendar, and parses its events.
+func Fetch(url, username, password string) ([]Event, error) {
+	req, err := http.NewRequest("GET", url, nil)
+	if err != nil {
+		return nil, err
+	}
+
+	if username != "" {
+		req.SetBasicAuth(username, password)
+	}
+
+	client, err := network.Client(10 * time.Second)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("unexpected status %s", resp.Status)
+	}
+
+	return ParseICS(resp.Body)
+}
+
+// ParseICS reads the events of an iCalendar file. Daily, weekly, monthly, and
+// yearly recurrences are kept so that On can expand them.
+func ParseICS(r io.Reader) ([]Event, error) {
+	lines, err := rfc.Unfold(r)
+	if err != nil {
+		return nil, err
+	}
+
+	events := []Event{}
+	var e *Event
+	for _, line := range lines {
+		name, params, value, _ := rfc.Property(line)
+
+		switch {
+		case name == "BEGIN" && value == "VEVENT":
+			e = &Event{exdates: map[string]bool{}}
+		case e == nil:
+			continue
+		case name == "END" && value == "VEVENT":
+			if !e.Start.IsZero() {
+				if e.End.IsZero() || !e.End.After(e.Start) {
+					e.End = e.Start
+					if e.AllDay {
+						e.End = e.Start.AddDate(0, 0, 1)
+					}
+				}
+				events = append(events, *e)
+			}
+			e = nil
+		case name == "UID":
+			e.UID = value
+		case name == "SUMMARY":
+			e.Summary = rfc.Unescape(value)
+		case name == "DTSTART":
+			e.Start, e.AllDay = parseTime(value, params)
+		case name == "DTEND":
+			e.End, _ = parseTime(value, params)
+		case name == "RRULE":
+			e.rule = parseRule(value)
+		case name == "EXDATE":
+			for _, v := range strings.Split(value, ",") {
+				t, _ := parseTime(v, params)
+				e.exdates[t.Format(note.DateFormat)] = true
+			}
+		}
+	}
+
+	return events, nil
+}
+
+// On returns the events that happen on the given day, with recurring events
+// moved to that day, ordered by start time with all day events first.
+func On(events []Event, day time.Time) []Event {
+	day = time.Date(day.Year(), day.Month(), day.Day(), 0, 0, 0, 0, time.Local)
+
+	on := []Event{}
+	for _, e := range events {
+		if occurrence, ok := e.on(day); ok {
+			on = append(on, occurrence)
+		}
+	}
+
+	sort.SliceStable(on, func(i, j int) bool {
+		if on[i].AllDay != on[j].AllDay {
+			return on[i].AllDay
+		}
+		return on[i].Start.Before(on[j].Start)
+	})
+
+	return on
+}
+
+// Schedule lists the events as markdown, one per line.
+func Schedule(events []Event) string {
+	lines := []string{}
+	for _, e := range events {
+		if e.AllDay {
+			lines = append(lines, "- All day: "+e.Summary)
+			continue
+		}
+
+		start, end := e.Start.In(time.Local), e.End.In(time.Local)
+		lines = append(lines, fmt.Sprintf("- %s-%s %s", start.Format("15:04"), end.Format("15:04"), e.Summary))
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func (e Event) on(day time.Time) (Event, bool) {
+	start := e.Start.In(time.Local)
+	first := time.Date(start.Year(), start.Month(), start.Day(), 0, 0, 0, 0, time.Local)
+
+	if e.rule == nil {
+		end := e.End.In(time.Local)
+		if e.AllDay {
+			// All day events end on the morning after their last day.
+			if !day.Before(first) && day.Before(end) {
+				return e, true
+			}
+			return Event{}, false
+		}
+		return e, day.Equal(first)
+	}
+
+	if day.Before(first) || e.exdates[day.Format(note.DateFormat)] {
+		return Event{}, false
+	}
+
+	n := 0
+	for d := first; !d.After(day); d = d.AddDate(0, 0, 1) {
+		if !e.rule.until.IsZero() && d.After(e.rule.until) {
+			return Event{}, false
+		}
+
+		if !e.rule.matches(first, d) {
+			continue
+		}
+
+		n++
+		if e.rule.count > 0 && n > e.rule.count {
+			return Event{}, false
+		}
+
+		if d.Equal(day) {
+			length := e.End.Sub(e.Start)
+			occurrence := e
+			occurrence.Start = time.Date(d.Year(), d.Month(), d.Day(), start.Hour(), start.Minute(), 0, 0, time.Local)
+			occurrence.End = occurrence.Start.Add(length)
+			return occurrence, true
+		}
+	}
+
+	return Event{}, false
+}
+
+func (r *rule) matches(first, d time.Time) bool {
+	days := int(d.Sub(first).Hours()+12) / 24
+
+	switch r.freq {
+	case "DAILY":
+		return days%r.interval == 0
+	case "WEEKLY":
+		weekdays := r.byDay
+		if len(weekdays) == 0 {
+			weekdays = []time.Weekday{first.Weekday()}
+		}
+
+		// Count weeks from the Monday of the first occurrence.
+		offset := (int(first.Weekday()) + 6) % 7
+		if (days+offset)/7%r.interval != 0 {
+			return false
+		}
+
+		for _, w := range weekdays {
+			if d.Weekday() == w {
+				return true
+			}
+		}
+		return false
+	case "MONTHLY":
+		months := (d.Year()-first.Year())*12 + int(d.Month()-first.Month())
+		return d.Day() == first.Day() && months%r.interval == 0
+	case "YEARLY":
+		return d.Month() == first.Month() && d.Day() == first.Day() && (d.Year()-first.Year())%r.interval == 0
+	}
+
+	return false
+}
+
+func parseTime(value string, params map[string]string) (time.Time, bool) {
+	if params["VALUE"] == "DATE" || len(value) == 8 {
+		t, err := time.ParseInLocation("20060102", value, time.Local)
+		if err != nil {
+			return time.Time{}, false
+		}
+		return t, true
+	}
+
+	if strings.HasSuffix(value, "Z") {
+		t, _ := time.Parse("20060102T150405Z", value)
+		return t, false
+	}
+
+	loc := time.Local
+	if tzid, ok := params["TZID"]; ok {
+		if l, err := time.LoadLocation(tzid); err == nil {
+			loc = l
+		}
+	}
+
+	t, _ := time.ParseInLocation("20060102T150405", value, loc)
+	return t, false
+}
+
+func parseRule(value string) *rule {
+	r := &rule{interval: 1}
+
+	for _, part := range strings.Split(value, ";") {
+		kv := strings.SplitN(part, "=", 2)
+		if len(kv) != 2 {
+			continue
+		}
+
+		switch strings.ToUpper(kv[0]) {
+		case "FREQ":
+			r.freq = strings.ToUpper(kv[1])
+		case "INTERVAL":
+			if n, err := strconv.Atoi(kv[1]); err == nil && n > 0 {
+				r.interval = n
+			}
+		case "COUNT":
+			r.count, _ = strconv.Atoi(kv[1])
+		case "UNTIL":
+			until, _ := parseTime(kv[1], map[string]string{})
+			r.until = until.In(time.Local)
+		case "BYDAY":
+			for _, d := range strings.Split(kv[1], ",") {
+				// Positions such as 1MO are not supported, only the weekday.
+				d = strings.TrimLeft(d, "+-0123456789")
+				for w, name := range []string{"SU", "MO", "TU", "WE", "TH", "FR", "SA"} {
+					if d == name {
+						r.byDay = append(r.byDay, time.Weekday(w))
+					}
+				}
+			}
+		}
+	}
+
+	if r.freq == "" {
+		return nil
+	}
+
+	return r
+}
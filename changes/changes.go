@@ -0,0 +1,125 @@
+package changes
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Between lists the notes under root changed within the window. When root is
+// in a git repository the commit history is used, along with uncommitted
+// files modified within the window. Otherwise modification times are
+// compared.
+func Between(root string, since, until time.Time) ([]string, error) {
+	if isGitRepo(root) {
+		return fromGit(root, since, until)
+	}
+
+	return fromModTime(root, since, until)
+}
+
+func isGitRepo(root string) bool {
+	out, err := exec.Command("git", "-C", root, "rev-parse", "--is-inside-work-tree").Output()
+	return err == nil && strings.TrimSpace(string(out)) == "true"
+}
+
+func fromGit(root string, since, until time.Time) ([]string, error) {
+	out, err := exec.Command(
+		"git", "-C", root, "log",
+		"--since="+since.Format(time.RFC3339),
+		"--until="+until.Format(time.RFC3339),
+		"--name-only", "--relative", "--pretty=format:",
+	).Output()
+	if err != nil {
+		return nil, err
+	}
+	names := strings.Split(string(out), "\n")
+
+	out, err = exec.Command("git", "-C", root, "ls-files", "--modified", "--others", "--exclude-standard").Output()
+	if err != nil {
+		return nil, err
+	}
+	for _, name := range strings.Split(string(out), "\n") {
+		info, err := os.Stat(filepath.Join(root, name))
+		if err == nil && inWindow(info.ModTime(), since, until) {
+			names = append(names, name)
+		}
+	}
+
+	seen := map[string]bool{}
+	paths := []string{}
+	for _, name := range names {
+		if filepath.Ext(name) != ".md" || seen[name] {
+			continue
+		}
+		seen[name] = true
+
+		path := filepath.Join(root, name)
+		if _, err := os.Stat(path); err != nil {
+			// Deleted since it was changed.
+			continue
+		}
+		paths = append(paths, path)
+	}
+
+	sort.Strings(paths)
+
+	return paths, nil
+}
+
+func fromModTime(root string, since, until time.Time) ([]string, error) {
+	all, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	paths := []string{}
+	for _, path := range all {
+		info, err := os.Stat(path)
+		if err != nil {
+			return nil, err
+		}
+
+		if inWindow(info.ModTime(), since, until) {
+			paths = append(paths, path)
+		}
+	}
+
+	return paths, nil
+}
+
+func inWindow(t, since, until time.Time) bool {
+	return !t.Before(since) && !t.After(until)
+}
+
+// Recent returns up to limit notes under root, most recently modified first.
+func Recent(root string, limit int) ([]string, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	modified := map[string]time.Time{}
+	for _, path := range paths {
+		info, err := os.Stat(path)
+		if err != nil {
+			return nil, err
+		}
+		modified[path] = info.ModTime()
+	}
+
+	sort.SliceStable(paths, func(i, j int) bool {
+		return modified[paths[i]].After(modified[paths[j]])
+	})
+
+	if limit > 0 && len(paths) > limit {
+		paths = paths[:limit]
+	}
+
+	return paths, nil
+}
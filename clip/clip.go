@@ -0,0 +1,44 @@
+package clip
+
+import (
+	neturl "net/url"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/web"
+)
+
+func Dir(root string) string {
+	return filepath.Join(root, "Clips")
+}
+
+// Save converts the page downloaded from url to markdown and stores it in the
+// Clips folder with its source and the date in the frontmatter. It returns the
+// path of the new note.
+func Save(root, url string, page []byte, now time.Time) (string, error) {
+	title := web.Title(page)
+	if title == "" {
+		title = url
+	}
+
+	if err := os.MkdirAll(Dir(root), 0755); err != nil {
+		return "", err
+	}
+
+	host := url
+	if u, err := neturl.Parse(url); err == nil && u.Host != "" {
+		host = u.Host
+	}
+
+	path := note.Available(filepath.Join(Dir(root), note.FileName(title, host)))
+
+	n := &note.Note{Path: path}
+	n.Frontmatter.Set("title", title)
+	n.Frontmatter.Set("source", url)
+	n.Frontmatter.Set("date", now.Format(note.DateFormat))
+	n.Body = "# " + title + "\n\n" + web.Markdown(page, url)
+
+	return path, n.Write()
+}
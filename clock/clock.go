@@ -0,0 +1,117 @@
+package clock
+
+import (
+	"errors"
+	"os"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+const Section = "Time"
+
+var entryLine = regexp.MustCompile(`^- (\d{2}:\d{2}) (in|out)(?: (.*))?$`)
+
+// Entry is a line such as "- 09:15 in Project Alpha" or "- 10:30 out" in the
+// Time section of a log.
+type Entry struct {
+	Time  time.Time
+	In    bool
+	Label string
+}
+
+var ErrNotClockedIn = errors.New("not clocked in")
+
+// Parse reads the entries from the Time section of the log for day.
+func Parse(content string, day time.Time) []Entry {
+	entries := []Entry{}
+
+	for _, line := range strings.Split(note.Section(content, Section), "\n") {
+		match := entryLine.FindStringSubmatch(strings.TrimSpace(line))
+		if match == nil {
+			continue
+		}
+
+		t, err := time.ParseInLocation("15:04", match[1], day.Location())
+		if err != nil {
+			continue
+		}
+
+		entries = append(entries, Entry{
+			Time:  time.Date(day.Year(), day.Month(), day.Day(), t.Hour(), t.Minute(), 0, 0, day.Location()),
+			In:    match[2] == "in",
+			Label: strings.TrimSpace(match[3]),
+		})
+	}
+
+	return entries
+}
+
+// Running returns the entry that was clocked in and not yet out, if any.
+func Running(entries []Entry) (Entry, bool) {
+	if len(entries) == 0 || !entries[len(entries)-1].In {
+		return Entry{}, false
+	}
+
+	return entries[len(entries)-1], true
+}
+
+// In clocks in to label, first clocking out of anything still running.
+func In(logPath, label string, now time.Time) error {
+	content, err := os.ReadFile(logPath)
+	if err != nil {
+		return err
+	}
+
+	updated := string(content)
+	if _, ok := Running(Parse(updated, now)); ok {
+		updated = note.AppendToSection(updated, Section, "- "+now.Format("15:04")+" out")
+	}
+	updated = note.AppendToSection(updated, Section, "- "+now.Format("15:04")+" in "+label)
+
+	return note.Save(logPath, []byte(updated))
+}
+
+// Out clocks out of whatever is running and returns its label.
+func Out(logPath string, now time.Time) (string, error) {
+	content, err := os.ReadFile(logPath)
+	if err != nil {
+		return "", err
+	}
+
+	running, ok := Running(Parse(string(content), now))
+	if !ok {
+		return "", ErrNotClockedIn
+	}
+
+	updated := note.AppendToSection(string(content), Section, "- "+now.Format("15:04")+" out")
+
+	return running.Label, note.Save(logPath, []byte(updated))
+}
+
+// Totals sums the time spent on each label. An entry still running counts up
+// to until, unless until is on a later day, in which case it is dropped.
+func Totals(entries []Entry, until time.Time) map[string]time.Duration {
+	totals := map[string]time.Duration{}
+
+	for i, e := range entries {
+		if !e.In {
+			continue
+		}
+
+		end := until
+		if i+1 < len(entries) {
+			end = entries[i+1].Time
+		} else if end.Format(note.DateFormat) != e.Time.Format(note.DateFormat) {
+			continue
+		}
+
+		if end.After(e.Time) {
+			totals[e.Label] += end.Sub(e.Time)
+		}
+	}
+
+	return totals
+}
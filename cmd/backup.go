@@ -0,0 +1,160 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/backup"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/remote"
+)
+
+// backupCmd represents the backup command
+var backupCmd = &cobra.Command{
+	Use:   "backup",
+	Short: "Make a copy of the whole notebook.",
+	Long: `Make a gzipped tarball of every file in the notebook, leaving out the .git
+folder and earlier backups. By default it is written to the current directory;
+use --output to pick another file.
+
+With --remote the backup is uploaded to the store configured under "remote"
+instead. "remote.type" is "s3" or "webdav":
+
+  remote:
+    type: s3
+    s3:
+      bucket: my-notes
+      region: eu-central-1
+      prefix: backups
+      endpoint: ""            # for S3 compatible services
+      access_key_id: ...      # or AWS_ACCESS_KEY_ID
+      secret_access_key: ...  # or AWS_SECRET_ACCESS_KEY, with AWS_SESSION_TOKEN
+
+  remote:
+    type: webdav
+    webdav:
+      url: https://cloud.example.com/remote.php/dav/files/me/Backups
+      username: me
+      password: ...`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		toRemote, _ := cmd.Flags().GetBool("remote")
+		output, _ := cmd.Flags().GetString("output")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		name := backup.Name(time.Now())
+
+		if !toRemote {
+			if output == "" {
+				output = name
+			}
+			runHook(hook.PreBackup, output)
+			writeBackup(root, output)
+			runHook(hook.PostBackup, output)
+			fmt.Println(output)
+			return
+		}
+
+		store, err := configuredRemote()
+		if err != nil {
+			log.Fatalf("could not set up the remote store: %v", err)
+		}
+
+		dir, err := os.MkdirTemp("", "nb-backup")
+		if err != nil {
+			log.Fatalf("could not create a temporary directory: %v", err)
+		}
+		defer os.RemoveAll(dir)
+
+		path := filepath.Join(dir, name)
+		runHook(hook.PreBackup, path)
+		writeBackup(root, path)
+
+		f, err := os.Open(path)
+		if err != nil {
+			log.Fatalf("could not read the backup: %v", err)
+		}
+		defer f.Close()
+
+		info, err := f.Stat()
+		if err != nil {
+			log.Fatalf("could not read the backup: %v", err)
+		}
+
+		if err := store.Put(name, f, info.Size()); err != nil {
+			log.Fatalf("could not upload the backup to %s: %v", store.Name(), err)
+		}
+		runHook(hook.PostBackup, path)
+		fmt.Printf("Uploaded %s to %s\n", name, store.Name())
+	},
+}
+
+func runHook(event, path string) {
+	if err := hook.Run(event, path); err != nil {
+		log.Fatalf("%v", err)
+	}
+}
+
+func writeBackup(root, path string) {
+	f, err := os.Create(path)
+	if err != nil {
+		log.Fatalf("could not create %s: %v", path, err)
+	}
+	defer f.Close()
+
+	if err := backup.Archive(root, f, path); err != nil {
+		log.Fatalf("could not back up the notebook: %v", err)
+	}
+}
+
+func configuredRemote() (remote.Store, error) {
+	switch kind := config.Remote("type"); kind {
+	case "s3":
+		s := &remote.S3{
+			Bucket:          config.Remote("s3.bucket"),
+			Region:          config.Remote("s3.region"),
+			Prefix:          config.Remote("s3.prefix"),
+			Endpoint:        config.Remote("s3.endpoint"),
+			AccessKeyID:     config.Remote("s3.access_key_id"),
+			SecretAccessKey: config.Remote("s3.secret_access_key"),
+		}
+		if s.AccessKeyID == "" {
+			s.AccessKeyID, s.SecretAccessKey = os.Getenv("AWS_ACCESS_KEY_ID"), os.Getenv("AWS_SECRET_ACCESS_KEY")
+			s.SessionToken = os.Getenv("AWS_SESSION_TOKEN")
+		}
+		if s.Bucket == "" {
+			return nil, fmt.Errorf("remote.s3.bucket is not set")
+		}
+		return s, nil
+	case "webdav":
+		w := &remote.WebDAV{
+			URL:      config.Remote("webdav.url"),
+			Username: config.Remote("webdav.username"),
+			Password: config.Remote("webdav.password"),
+		}
+		if w.URL == "" {
+			return nil, fmt.Errorf("remote.webdav.url is not set")
+		}
+		return w, nil
+	case "":
+		return nil, fmt.Errorf("remote.type is not set")
+	default:
+		return nil, fmt.Errorf("unknown remote type %q, expected s3 or webdav", kind)
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(backupCmd)
+	backupCmd.Flags().Bool("remote", false, "Upload the backup to the configured remote store")
+	backupCmd.Flags().StringP("output", "o", "", "File to write the backup to")
+}
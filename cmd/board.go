@@ -0,0 +1,75 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/board"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/task"
+)
+
+// boardCmd represents the board command
+var boardCmd = &cobra.Command{
+	Use:   "board",
+	Short: "Show the tasks of recent logs as a kanban board.",
+	Long: `Show the tasks of recent logs as a kanban board.
+
+Tasks are grouped by their checkbox: "- [ ]" is todo, "- [/]" is doing, and
+"- [x]" is done. A "status:<name>" token in the task text puts it in a column
+of that name instead. Each task is shown with its id for "nb board move".`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		tasks := loadBoard(cmd)
+
+		fmt.Print(board.Render(board.Columns(tasks), 32))
+	},
+}
+
+// boardMoveCmd represents the board move command
+var boardMoveCmd = &cobra.Command{
+	Use:   "move <id> <status>",
+	Short: "Move a task to another column.",
+	Long: `Move a task to another column by rewriting its line in the log. Moving
+to todo, doing, or done changes the checkbox; any other status is written as a
+"status:<name>" token.`,
+	Args: cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		tasks := loadBoard(cmd)
+
+		t, ok := task.Find(tasks, args[0])
+		if !ok {
+			log.Fatalf("no task on the board has the id %s", args[0])
+		}
+
+		if err := task.SetStatus(t, strings.ToLower(args[1])); err != nil {
+			log.Fatalf("could not move task: %v", err)
+		}
+	},
+}
+
+func loadBoard(cmd *cobra.Command) []task.Task {
+	days, _ := cmd.Flags().GetInt("days")
+
+	logDir, err := noteLog.Dir()
+	if err != nil {
+		log.Fatalf("could not get log directory: %v", err)
+	}
+
+	tasks, err := board.Load(logDir, time.Now(), days)
+	if err != nil {
+		log.Fatalf("could not read tasks: %v", err)
+	}
+
+	return tasks
+}
+
+func init() {
+	rootCmd.AddCommand(boardCmd)
+	boardCmd.AddCommand(boardMoveCmd)
+	boardCmd.PersistentFlags().Int("days", 7, "Number of days of logs to include")
+}
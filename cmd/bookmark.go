@@ -0,0 +1,88 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/bookmark"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/web"
+)
+
+// bookmarkCmd represents the bookmark command
+var bookmarkCmd = &cobra.Command{
+	Use:   "bookmark <url>",
+	Short: "Save a link to read later.",
+	Long: `Save a link to read later.
+
+The page title is fetched and the link is added as an unchecked item to
+Bookmarks.md, or to a note per month in the Bookmarks folder when
+"bookmarks.per_month" is set in the config. Check the box once it is read.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		url := args[0]
+		tags, _ := cmd.Flags().GetStringSlice("tags")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		title := url
+		if page, err := web.Fetch(url); err != nil {
+			fmt.Fprintf(os.Stderr, "could not fetch the title of %s: %v\n", url, err)
+		} else if t := web.Title(page); t != "" {
+			title = t
+		}
+
+		now := time.Now()
+		path := bookmark.Path(root, now, config.BookmarksPerMonth())
+		b := bookmark.Bookmark{Title: title, URL: url, Tags: tags, Added: now.Format(note.DateFormat)}
+
+		if err := bookmark.Add(path, b); err != nil {
+			log.Fatalf("could not add bookmark to %s: %v", path, err)
+		}
+
+		fmt.Println(title)
+	},
+}
+
+// bookmarkListCmd represents the bookmark list command
+var bookmarkListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List saved links.",
+	Long:  ``,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		unread, _ := cmd.Flags().GetBool("unread")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		bookmarks, err := bookmark.List(root)
+		if err != nil {
+			log.Fatalf("could not read bookmarks: %v", err)
+		}
+
+		for _, b := range bookmarks {
+			if unread && b.Read {
+				continue
+			}
+			fmt.Printf("%s  %s\n    %s\n", b.Added, b.Title, b.URL)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(bookmarkCmd)
+	bookmarkCmd.AddCommand(bookmarkListCmd)
+	bookmarkCmd.Flags().StringSlice("tags", []string{}, "Comma separated list of tags")
+	bookmarkListCmd.Flags().Bool("unread", false, "Only list links not yet read")
+}
@@ -0,0 +1,62 @@
+package cmd
+
+import (
+	"log"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/inbox"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+)
+
+// captureCmd represents the capture command
+var captureCmd = &cobra.Command{
+	Use:   "capture <text>",
+	Short: "Append a line of text to a note.",
+	Long: `Append a line of text to a note.
+
+By default the text goes at the end of today's log. Use --to to pick another
+note and section, for example --to "Projects/Alpha#Log". The note and the
+section are created if they do not exist. A target of only a section, such as
+--to "#Ideas", appends to that section of today's log.
+
+Use --inbox to put the text in Inbox.md at the root of the notebook, to be
+refiled later with "nb inbox process".`,
+	Args: cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		if toInbox, _ := cmd.Flags().GetBool("inbox"); toInbox {
+			if err := inbox.Add(root, strings.Join(args, " ")); err != nil {
+				log.Fatalf("could not capture to the inbox: %v", err)
+			}
+			return
+		}
+
+		to, _ := cmd.Flags().GetString("to")
+		path, section := note.Target(root, to)
+
+		if path == "" {
+			path, err = noteLog.Ensure(0)
+			if err != nil {
+				log.Fatalf("could not fetch today's log: %v", err)
+			}
+		}
+
+		if err := note.Capture(path, section, strings.Join(args, " ")); err != nil {
+			log.Fatalf("could not capture to %s: %v", path, err)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(captureCmd)
+	captureCmd.Flags().String("to", "", "Note and section to append to, as \"path#Section\"")
+	captureCmd.Flags().Bool("inbox", false, "Append to the inbox instead")
+}
@@ -0,0 +1,62 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/changes"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+)
+
+// changedCmd represents the changed command
+var changedCmd = &cobra.Command{
+	Use:   "changed",
+	Short: "List the notes changed in a window of time.",
+	Long: `List the notes changed in a window of time, one path per line.
+
+The window is set with --since and --until, which take dates (YYYY-MM-DD) or
+phrases such as "yesterday" and "2 days ago". When the notebook is a git
+repository its history is used, otherwise file modification times are.
+
+The output is meant to be passed to other commands, for example
+nb fmt $(nb changed --since yesterday).`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		now := time.Now()
+
+		sinceFlag, _ := cmd.Flags().GetString("since")
+		since, err := date.Parse(sinceFlag, now)
+		if err != nil {
+			log.Fatalf("could not read --since: %v", err)
+		}
+
+		untilFlag, _ := cmd.Flags().GetString("until")
+		until, err := date.Parse(untilFlag, now)
+		if err != nil {
+			log.Fatalf("could not read --until: %v", err)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		paths, err := changes.Between(root, since, until)
+		if err != nil {
+			log.Fatalf("could not list changes: %v", err)
+		}
+
+		for _, path := range paths {
+			fmt.Println(path)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(changedCmd)
+	changedCmd.Flags().String("since", "today", "Start of the window")
+	changedCmd.Flags().String("until", "now", "End of the window")
+}
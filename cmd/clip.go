@@ -0,0 +1,61 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/clip"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/link"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/web"
+)
+
+// clipCmd represents the clip command
+var clipCmd = &cobra.Command{
+	Use:   "clip <url>",
+	Short: "Save a copy of a web page as a note.",
+	Long: `Save a copy of a web page as a note.
+
+The main content of the page is converted to markdown and stored in the Clips
+folder, with the source URL and date in the frontmatter. The new note is linked
+from the Clips section of today's log.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		url := args[0]
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		page, err := web.Fetch(url)
+		if err != nil {
+			log.Fatalf("could not fetch %s: %v", url, err)
+		}
+
+		path, err := clip.Save(root, url, page, time.Now())
+		if err != nil {
+			log.Fatalf("could not save %s: %v", url, err)
+		}
+
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		if err := note.Capture(fileName, "Clips", "- "+link.To(path)); err != nil {
+			log.Fatalf("could not link clip from %s: %v", fileName, err)
+		}
+
+		fmt.Println(path)
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(clipCmd)
+}
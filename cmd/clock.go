@@ -0,0 +1,128 @@
+package cmd
+
+import (
+	"errors"
+	"fmt"
+	"log"
+	"os"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/clock"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+// clockCmd represents the clock command
+var clockCmd = &cobra.Command{
+	Use:   "clock",
+	Short: "Track time in the daily log.",
+	Long: `Track time in the Time section of the daily log.
+
+Entries are written as "- 09:15 in Project Alpha" and "- 10:30 out", so they
+can also be added or corrected by hand.`,
+}
+
+// clockInCmd represents the clock in command
+var clockInCmd = &cobra.Command{
+	Use:   "in <label>",
+	Short: "Start tracking time against a label.",
+	Long:  `Start tracking time against a label, clocking out of anything already running.`,
+	Args:  cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		if err := clock.In(fileName, strings.Join(args, " "), time.Now()); err != nil {
+			log.Fatalf("could not clock in: %v", err)
+		}
+	},
+}
+
+// clockOutCmd represents the clock out command
+var clockOutCmd = &cobra.Command{
+	Use:   "out",
+	Short: "Stop tracking time.",
+	Long:  ``,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		label, err := clock.Out(fileName, time.Now())
+		if errors.Is(err, clock.ErrNotClockedIn) {
+			fmt.Println("Not clocked in.")
+			return
+		} else if err != nil {
+			log.Fatalf("could not clock out: %v", err)
+		}
+
+		fmt.Printf("Clocked out of %s.\n", label)
+	},
+}
+
+// clockReportCmd represents the clock report command
+var clockReportCmd = &cobra.Command{
+	Use:   "report",
+	Short: "Sum the time tracked per label.",
+	Long:  `Sum the time tracked per label today, or this week with --week.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		now := time.Now()
+		from := date.StartOfDay(now)
+		if week, _ := cmd.Flags().GetBool("week"); week {
+			from = date.StartOfWeek(now, config.WeekStart())
+		}
+
+		totals := map[string]time.Duration{}
+		for day := from; !day.After(now); day = day.AddDate(0, 0, 1) {
+			content, err := os.ReadFile(logpath.For(logDir, day))
+			if os.IsNotExist(err) {
+				continue
+			} else if err != nil {
+				log.Fatalf("could not read log for %s: %v", day.Format(note.DateFormat), err)
+			}
+
+			for label, d := range clock.Totals(clock.Parse(string(content), day), now) {
+				totals[label] += d
+			}
+		}
+
+		labels := []string{}
+		for label := range totals {
+			labels = append(labels, label)
+		}
+		sort.Strings(labels)
+
+		var sum time.Duration
+		for _, label := range labels {
+			fmt.Printf("%-30s %s\n", label, totals[label].Truncate(time.Minute))
+			sum += totals[label]
+		}
+		fmt.Printf("%-30s %s\n", "Total", sum.Truncate(time.Minute))
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(clockCmd)
+	clockCmd.AddCommand(clockInCmd)
+	clockCmd.AddCommand(clockOutCmd)
+	clockCmd.AddCommand(clockReportCmd)
+	clockReportCmd.Flags().Bool("week", false, "Report on the current week")
+}
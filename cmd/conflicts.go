@@ -0,0 +1,169 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/conflict"
+	"github.com/t-eckert/nb/diff"
+	"github.com/t-eckert/nb/prompt"
+)
+
+// conflictsCmd represents the conflicts command
+var conflictsCmd = &cobra.Command{
+	Use:   "conflicts",
+	Short: "Find and resolve the conflict copies left by sync tools.",
+	Long: `Find and resolve the copies Syncthing, Dropbox, Nextcloud, and other sync tools
+keep when a note was changed in two places at once, such as
+"Note.sync-conflict-20250304-123456-ABCDEFG.md" or
+"Note (conflicted copy 2025-03-04).md".`,
+}
+
+// conflictsListCmd represents the conflicts list command
+var conflictsListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List the conflict copies in the notebook.",
+	Long:  ``,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, conflicts := findConflicts()
+
+		for _, c := range conflicts {
+			fmt.Printf("%s\n  copy of %s\n", relativeTo(root, c.Path), relativeTo(root, c.Original))
+		}
+	},
+}
+
+// conflictsResolveCmd represents the conflicts resolve command
+var conflictsResolveCmd = &cobra.Command{
+	Use:   "resolve [copy...]",
+	Short: "Resolve conflict copies by keeping one side or merging them.",
+	Long: `Resolve the given conflict copies, or all of them. For each, the differences
+from the original note are shown and you pick what to keep: the original, the
+copy, or both merged. A merge starts from the last version of the note in its
+history from before the copy was made: it keeps the lines either side added,
+drops the lines either side deleted, and keeps a task changed on both sides
+once, checked off if either side checked it off. Without such a version every
+line either side has is kept.
+
+Use --keep ours, theirs, or merge to resolve without asking. The conflict copy
+is moved to the trash afterwards.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		keep, _ := cmd.Flags().GetString("keep")
+
+		root, conflicts := findConflicts()
+		if len(args) > 0 {
+			conflicts = pickConflicts(root, conflicts, args)
+		}
+
+		for _, c := range conflicts {
+			ours, theirs, err := conflict.Read(c)
+			if err != nil {
+				log.Fatalf("could not read %s: %v", c.Path, err)
+			}
+
+			choice := keep
+			if choice == "" {
+				fmt.Print(diff.Unified(
+					relativeTo(root, c.Original),
+					relativeTo(root, c.Path),
+					strings.Split(ours, "\n"),
+					strings.Split(theirs, "\n"),
+					3,
+				))
+
+				answer, err := prompt.Ask("Keep [o]urs, [t]heirs, [m]erge, or [s]kip? ")
+				if err != nil {
+					return
+				}
+				choice = map[string]string{"o": "ours", "t": "theirs", "m": "merge"}[strings.ToLower(answer)]
+			}
+
+			content := ""
+			switch choice {
+			case "ours":
+				content = ours
+			case "theirs":
+				content = theirs
+			case "merge":
+				base, ok, err := conflict.Base(c)
+				if err != nil {
+					log.Fatalf("could not read the history of %s: %v", c.Original, err)
+				}
+				if !ok {
+					base = conflict.Common(ours, theirs)
+				}
+				content = conflict.Merge(base, ours, theirs)
+			case "":
+				continue
+			default:
+				log.Fatalf("unknown choice %q, expected ours, theirs, or merge", choice)
+			}
+
+			if err := conflict.Resolve(root, c, content, time.Now()); err != nil {
+				log.Fatalf("could not resolve %s: %v", c.Path, err)
+			}
+			fmt.Printf("Resolved %s\n", relativeTo(root, c.Original))
+		}
+	},
+}
+
+func findConflicts() (string, []conflict.Conflict) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		log.Fatalf("could not get root directory: %v", err)
+	}
+
+	conflicts, err := conflict.Find(root)
+	if err != nil {
+		log.Fatalf("could not read notebook: %v", err)
+	}
+
+	return root, conflicts
+}
+
+func pickConflicts(root string, conflicts []conflict.Conflict, args []string) []conflict.Conflict {
+	picked := []conflict.Conflict{}
+	for _, arg := range args {
+		path, err := filepath.Abs(arg)
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", arg, err)
+		}
+
+		found := false
+		for _, c := range conflicts {
+			if c.Path == path || c.Path == filepath.Join(root, arg) {
+				picked = append(picked, c)
+				found = true
+			}
+		}
+
+		if !found {
+			log.Fatalf("%s is not a conflict copy", arg)
+		}
+	}
+
+	return picked
+}
+
+func relativeTo(root, path string) string {
+	if rel, err := filepath.Rel(root, path); err == nil {
+		return filepath.ToSlash(rel)
+	}
+
+	return path
+}
+
+func init() {
+	rootCmd.AddCommand(conflictsCmd)
+	conflictsCmd.AddCommand(conflictsListCmd)
+	conflictsCmd.AddCommand(conflictsResolveCmd)
+	conflictsResolveCmd.Flags().String("keep", "", "Resolve without asking: ours, theirs, or merge")
+}
@@ -0,0 +1,109 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/notify"
+	"github.com/t-eckert/nb/remind"
+	"github.com/t-eckert/nb/watch"
+)
+
+// daemonCmd represents the daemon command
+var daemonCmd = &cobra.Command{
+	Use:   "daemon",
+	Short: "Run in the background and send notifications for due reminders.",
+	Long: `Run in the background and send notifications for due reminders.
+
+Reminders are read from "remind:" tokens on open tasks in the logs and from
+the "remind" date in the frontmatter of notes, and read again whenever a note
+changes. Due reminders are checked every interval. Each
+reminder is notified once while the daemon runs, including reminders that came
+due while it was stopped.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		interval, _ := cmd.Flags().GetDuration("interval")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		watcher, err := watch.New(root)
+		if err != nil {
+			log.Fatalf("could not watch %s: %v", root, err)
+		}
+		defer watcher.Close()
+
+		reminders, err := readReminders(root, logDir)
+		if err != nil {
+			log.Fatalf("could not read reminders: %v", err)
+		}
+
+		ticker := time.NewTicker(interval)
+		defer ticker.Stop()
+
+		fired := map[string]bool{}
+		for {
+			now := time.Now()
+			for _, r := range reminders {
+				key := r.ID() + r.At.String()
+				if fired[key] || r.At.After(now) {
+					continue
+				}
+
+				if err := notify.Send("nb reminder", r.Text); err != nil {
+					log.Printf("could not send notification: %v", err)
+					continue
+				}
+
+				fmt.Printf("%s  reminded: %s\n", now.Format("15:04"), r.Text)
+				fired[key] = true
+			}
+
+			select {
+			case <-ticker.C:
+			case <-watcher.Changes:
+				updated, err := readReminders(root, logDir)
+				if err != nil {
+					log.Printf("could not read reminders: %v", err)
+					continue
+				}
+				reminders = updated
+			case err := <-watcher.Errors:
+				log.Printf("could not watch %s: %v", root, err)
+			}
+		}
+	},
+}
+
+// readReminders reads the reminders on tasks in the logs and in the
+// frontmatter of notes.
+func readReminders(root, logDir string) ([]remind.Reminder, error) {
+	tasks, err := remind.Tasks(logDir)
+	if err != nil {
+		return nil, err
+	}
+
+	notes, err := remind.Notes(root)
+	if err != nil {
+		return nil, err
+	}
+
+	return append(tasks, notes...), nil
+}
+
+func init() {
+	rootCmd.AddCommand(daemonCmd)
+	daemonCmd.Flags().Duration("interval", time.Minute, "How often to check for due reminders")
+}
@@ -0,0 +1,91 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/diff"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+// diffCmd represents the diff command
+var diffCmd = &cobra.Command{
+	Use:   "diff <from> <to>",
+	Short: "Show the differences between two logs or notes.",
+	Long: `Show the differences between two logs or notes.
+
+Each argument is either a date (YYYY-MM-DD), naming the log for that day, or
+the path of a note. With --tasks only the changes to tasks are shown.`,
+	Args: cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		fromPath, fromContent := readNoteArg(args[0])
+		toPath, toContent := readNoteArg(args[1])
+
+		tasksOnly, _ := cmd.Flags().GetBool("tasks")
+		if tasksOnly {
+			changes := task.Diff(task.Parse(fromPath, fromContent), task.Parse(toPath, toContent))
+			for _, c := range changes {
+				fmt.Printf("%-9s %s\n", c.Kind, c.Text)
+			}
+			return
+		}
+
+		fmt.Print(diff.Unified(
+			fromPath,
+			toPath,
+			strings.Split(fromContent, "\n"),
+			strings.Split(toContent, "\n"),
+			3,
+		))
+	},
+}
+
+// readNoteArg resolves an argument naming a log by date or a note by path and
+// reads its content.
+func readNoteArg(arg string) (string, string) {
+	path, err := resolveNote(arg)
+	if err != nil {
+		log.Fatalf("could not resolve %s: %v", arg, err)
+	}
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		log.Fatalf("could not read %s: %v", path, err)
+	}
+
+	return path, string(content)
+}
+
+// resolveNote turns a date into the path of its log. Other arguments are taken
+// as paths, relative to the notebook when they do not exist from the working
+// directory.
+func resolveNote(arg string) (string, error) {
+	if date, err := time.ParseInLocation(note.DateFormat, arg, time.Local); err == nil {
+		return noteLog.Find(date)
+	}
+
+	if _, err := os.Stat(arg); err == nil {
+		return arg, nil
+	}
+
+	root, err := config.GetRootDir()
+	if err != nil {
+		return "", err
+	}
+
+	return filepath.Join(root, arg), nil
+}
+
+func init() {
+	rootCmd.AddCommand(diffCmd)
+	diffCmd.Flags().Bool("tasks", false, "Only show changes to tasks")
+}
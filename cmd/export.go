@@ -0,0 +1,197 @@
+package cmd
+
+import (
+	"encoding/csv"
+	"fmt"
+	"log"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/calendar"
+	"github.com/t-eckert/nb/config"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/pandoc"
+	"github.com/t-eckert/nb/serve"
+)
+
+// exportCmd represents the export command
+var exportCmd = &cobra.Command{
+	Use:   "export",
+	Short: "Export notes to other formats.",
+	Long: `Export notes to other formats.
+
+Formats nb does not write itself are one command away through pandoc:
+
+  nb export --via-pandoc --to docx "Project plan"
+
+writes "Project plan.docx", or the file given with --output. Any output format
+pandoc knows can be given with --to, such as docx, odt, or epub. The frontmatter
+of the note is passed to pandoc as metadata. Set "export.pandoc" to use a
+pandoc binary that is not on the PATH.`,
+	Args: cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		viaPandoc, _ := cmd.Flags().GetBool("via-pandoc")
+		to, _ := cmd.Flags().GetString("to")
+		output, _ := cmd.Flags().GetString("output")
+
+		if !viaPandoc {
+			cmd.Help()
+			return
+		}
+
+		if len(args) == 0 || to == "" {
+			log.Fatalf("could not export: give a note and a format with --to")
+		}
+
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		if note.IsPrivate(path) {
+			log.Fatalf("could not export %s: the note is private", path)
+		}
+
+		if output == "" {
+			output = strings.TrimSuffix(filepath.Base(path), ".md") + "." + to
+		}
+
+		if err := pandoc.Convert(path, to, output); err != nil {
+			log.Fatalf("could not export %s: %v", path, err)
+		}
+	},
+}
+
+// exportICSCmd represents the export ics command
+var exportICSCmd = &cobra.Command{
+	Use:   "ics",
+	Short: "Export events and due tasks as an iCalendar file.",
+	Long: `Export events and due tasks as an iCalendar file.
+
+Open tasks with a "due:YYYY-MM-DD" token become all day events on that date.
+Lines in a log starting with "event:" become events on the day of the log, for
+example:
+
+  - event: 14:00-15:00 Dentist
+  - event: 2025-03-04 Conference
+
+The same calendar is served at /calendar.ics by "nb serve".`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		output, _ := cmd.Flags().GetString("output")
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		events, err := calendar.FromLogs(logDir)
+		if err != nil {
+			log.Fatalf("could not read events: %v", err)
+		}
+
+		ics := calendar.ICS(events, time.Now())
+		if output == "" {
+			fmt.Print(ics)
+			return
+		}
+
+		if err := os.WriteFile(output, []byte(ics), 0644); err != nil {
+			log.Fatalf("could not write %s: %v", output, err)
+		}
+	},
+}
+
+// exportHTMLCmd represents the export html command
+var exportHTMLCmd = &cobra.Command{
+	Use:   "html <note>",
+	Short: "Export a note as a web page.",
+	Long: `Export a note as a web page that can be opened without "nb serve", styled
+with the theme set with "serve.theme". Mermaid diagrams are drawn by mermaid.js
+when the page is opened. The same page is served at /notes/<path> by
+"nb serve".`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		output, _ := cmd.Flags().GetString("output")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		if note.IsPrivate(path) {
+			log.Fatalf("could not export %s: the note is private", path)
+		}
+
+		page, err := serve.Standalone(root, path)
+		if err != nil {
+			log.Fatalf("could not render %s: %v", path, err)
+		}
+
+		if output == "" {
+			os.Stdout.Write(page)
+			return
+		}
+
+		if err := os.WriteFile(output, page, 0644); err != nil {
+			log.Fatalf("could not write %s: %v", output, err)
+		}
+	},
+}
+
+// privateFiles remembers which files are private, for exports that read many
+// rows from the same files.
+type privateFiles map[string]bool
+
+func (p privateFiles) has(path string) bool {
+	private, ok := p[path]
+	if !ok {
+		private = note.IsPrivate(path)
+		p[path] = private
+	}
+
+	return private
+}
+
+// writeCSV writes the rows as CSV to the file at output, or to stdout when
+// output is empty.
+func writeCSV(output string, rows [][]string) error {
+	if output == "" {
+		return csv.NewWriter(os.Stdout).WriteAll(rows)
+	}
+
+	f, err := os.Create(output)
+	if err != nil {
+		return err
+	}
+
+	if err := csv.NewWriter(f).WriteAll(rows); err != nil {
+		f.Close()
+		return err
+	}
+
+	return f.Close()
+}
+
+func init() {
+	rootCmd.AddCommand(exportCmd)
+	exportCmd.Flags().Bool("via-pandoc", false, "Convert the note with pandoc")
+	exportCmd.Flags().String("to", "", "Format for pandoc to write, such as docx, odt, or epub")
+	exportCmd.Flags().StringP("output", "o", "", "File to write, named after the note by default")
+	exportCmd.AddCommand(exportICSCmd)
+	exportCmd.AddCommand(exportHTMLCmd)
+	exportHTMLCmd.Flags().StringP("output", "o", "", "File to write the page to instead of stdout")
+	exportICSCmd.Flags().StringP("output", "o", "", "File to write the calendar to instead of stdout")
+}
@@ -0,0 +1,80 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/format"
+	"github.com/t-eckert/nb/note"
+)
+
+// fmtCmd represents the fmt command
+var fmtCmd = &cobra.Command{
+	Use:   "fmt [path]",
+	Short: "Normalize the formatting of notes.",
+	Long: `Normalize the formatting of notes.
+
+Headings get a blank line around them, blank lines are collapsed, trailing
+whitespace is removed, list markers and indentation are made consistent, and
+frontmatter keys are put in a standard order. Lines are wrapped at "fmt.width"
+when it is set in the config. Fenced code blocks are never changed.
+
+Pass the path of a note, or --all to format the whole notebook.`,
+	Args: cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		all, _ := cmd.Flags().GetBool("all")
+
+		var paths []string
+		switch {
+		case all:
+			root, err := config.GetRootDir()
+			if err != nil {
+				log.Fatalf("could not get root directory: %v", err)
+			}
+
+			paths, err = note.Paths(root)
+			if err != nil {
+				log.Fatalf("could not list notes: %v", err)
+			}
+		case len(args) == 1:
+			path, err := resolveNote(args[0])
+			if err != nil {
+				log.Fatalf("could not resolve %s: %v", args[0], err)
+			}
+			paths = []string{path}
+		default:
+			log.Fatalf("pass the path of a note or --all")
+		}
+
+		for _, path := range paths {
+			content, err := os.ReadFile(path)
+			if err != nil {
+				log.Fatalf("could not read %s: %v", path, err)
+			}
+
+			formatted, err := format.Format(string(content), config.FormatWidth())
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "skipping %s: %v\n", path, err)
+				continue
+			}
+
+			if formatted == string(content) {
+				continue
+			}
+
+			if err := note.Save(path, []byte(formatted)); err != nil {
+				log.Fatalf("could not write %s: %v", path, err)
+			}
+			fmt.Println(path)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(fmtCmd)
+	fmtCmd.Flags().Bool("all", false, "Format every note in the notebook")
+}
@@ -0,0 +1,97 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"path/filepath"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/graph"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/logpath"
+)
+
+// graphCmd represents the graph command
+var graphCmd = &cobra.Command{
+	Use:   "graph",
+	Short: "Look at how the notes link to each other.",
+	Long:  ``,
+}
+
+// graphOrphansCmd represents the graph orphans command
+var graphOrphansCmd = &cobra.Command{
+	Use:   "orphans",
+	Short: "List the notes that are not linked to or from any other note.",
+	Long: `List the notes with no wikilinks or relative markdown links to or from any
+other note. Daily logs are left out unless --logs is given.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		logs, _ := cmd.Flags().GetBool("logs")
+
+		root, g := buildGraph()
+		for _, path := range g.Orphans() {
+			printGraphNote(root, path, logs, "")
+		}
+	},
+}
+
+// graphStubsCmd represents the graph stubs command
+var graphStubsCmd = &cobra.Command{
+	Use:   "stubs",
+	Short: "List the notes with very little in them.",
+	Long: `List the notes with fewer words than --words in their body, leaving out the
+frontmatter, shortest first. Daily logs are left out unless --logs is given.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		logs, _ := cmd.Flags().GetBool("logs")
+		words, _ := cmd.Flags().GetInt("words")
+
+		root, g := buildGraph()
+		for _, path := range g.Stubs(words) {
+			printGraphNote(root, path, logs, fmt.Sprintf("%4d  ", g.Words[path]))
+		}
+	},
+}
+
+func buildGraph() (string, *graph.Graph) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		log.Fatalf("could not get root directory: %v", err)
+	}
+
+	g, err := graph.Build(root)
+	if err != nil {
+		log.Fatalf("could not read notebook: %v", err)
+	}
+
+	return root, g
+}
+
+func printGraphNote(root, path string, logs bool, prefix string) {
+	logDir, err := noteLog.Dir()
+	if err != nil {
+		log.Fatalf("could not get log directory: %v", err)
+	}
+
+	if _, ok := logpath.Date(logDir, path, time.Local); ok && !logs {
+		return
+	}
+
+	if rel, err := filepath.Rel(root, path); err == nil {
+		path = rel
+	}
+	fmt.Println(prefix + filepath.ToSlash(path))
+}
+
+func init() {
+	rootCmd.AddCommand(graphCmd)
+	graphCmd.AddCommand(graphOrphansCmd)
+	graphCmd.AddCommand(graphStubsCmd)
+	graphOrphansCmd.Flags().Bool("logs", false, "Include the daily logs")
+	graphStubsCmd.Flags().Bool("logs", false, "Include the daily logs")
+	graphStubsCmd.Flags().Int("words", 50, "Notes with fewer words than this are stubs")
+}
@@ -0,0 +1,129 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"strconv"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/habit"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+)
+
+// habitsCmd represents the habits command
+var habitsCmd = &cobra.Command{
+	Use:   "habits",
+	Short: "Track habits in the daily logs.",
+	Long: `Track habits in the daily logs.
+
+Habits listed under "habits" in the config are added as a checklist to the
+Habits section of each new log.`,
+}
+
+// habitsReportCmd represents the habits report command
+var habitsReportCmd = &cobra.Command{
+	Use:   "report",
+	Short: "Show how often each habit was checked off.",
+	Long:  ``,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		days, _ := cmd.Flags().GetInt("days")
+		if days < 1 {
+			log.Fatalf("--days must be at least 1")
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		reports, err := habit.Reports(logDir, config.Habits(), time.Now(), days)
+		if err != nil {
+			log.Fatalf("could not read habits: %v", err)
+		}
+
+		if len(reports) == 0 {
+			fmt.Println("No habits are being tracked.")
+			return
+		}
+
+		width := len("Habit")
+		for _, r := range reports {
+			if len(r.Habit) > width {
+				width = len(r.Habit)
+			}
+		}
+
+		fmt.Printf("%-*s  %7s  %6s  %4s\n", width, "Habit", "Done", "Streak", "Best")
+		for _, r := range reports {
+			done := fmt.Sprintf("%d/%d", r.Done, r.Days)
+			fmt.Printf("%-*s  %7s  %6d  %4d\n", width, r.Habit, done, r.Streak, r.Best)
+		}
+	},
+}
+
+// habitsExportCmd represents the habits export command
+var habitsExportCmd = &cobra.Command{
+	Use:   "export",
+	Short: "Export the habits of every log as a spreadsheet.",
+	Long: `Export whether each habit was checked off in the logs as a CSV file, with one
+row per habit and day: the date, the habit, whether it was done, and the log
+it was read from. Configured habits missing from a log count as not done, and
+private logs are left out.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		format, _ := cmd.Flags().GetString("format")
+		output, _ := cmd.Flags().GetString("output")
+
+		if format != "csv" {
+			log.Fatalf("unknown format %q, expected csv", format)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		entries, err := habit.Entries(logDir, config.Habits())
+		if err != nil {
+			log.Fatalf("could not read habits: %v", err)
+		}
+
+		rows := [][]string{{"date", "habit", "done", "file"}}
+		private := privateFiles{}
+		for _, e := range entries {
+			if private.has(e.Path) {
+				continue
+			}
+
+			rows = append(rows, []string{
+				e.Date.Format(note.DateFormat),
+				e.Habit,
+				strconv.FormatBool(e.Done),
+				relativeTo(root, e.Path),
+			})
+		}
+
+		if err := writeCSV(output, rows); err != nil {
+			log.Fatalf("could not write habits: %v", err)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(habitsCmd)
+	habitsCmd.AddCommand(habitsReportCmd)
+	habitsCmd.AddCommand(habitsExportCmd)
+	habitsExportCmd.Flags().String("format", "csv", "Output format, csv")
+	habitsExportCmd.Flags().StringP("output", "o", "", "File to write to instead of stdout")
+	habitsReportCmd.Flags().Int("days", 30, "Number of days to report on")
+}
@@ -0,0 +1,73 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/history"
+	"github.com/t-eckert/nb/note"
+)
+
+// historyCmd represents the history command
+var historyCmd = &cobra.Command{
+	Use:   "history <note>",
+	Short: "List the saved versions of a note.",
+	Long: `List the saved versions of a note, oldest first.
+
+A snapshot is taken every time nb changes a note, both before and after the
+change, so edits made outside of nb are kept too.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		versions, err := history.List(path)
+		if err != nil {
+			log.Fatalf("could not read the history of %s: %v", path, err)
+		}
+
+		if len(versions) == 0 {
+			fmt.Println("No saved versions.")
+			return
+		}
+
+		for _, v := range versions {
+			fmt.Printf("%s  %s\n", v.ID, v.Time.Format("2006-01-02 15:04:05"))
+		}
+	},
+}
+
+// historyRestoreCmd represents the history restore command
+var historyRestoreCmd = &cobra.Command{
+	Use:   "restore <note> <id>",
+	Short: "Restore a saved version of a note.",
+	Long:  `Restore a saved version of a note. The current content is saved first, so the restore can be undone.`,
+	Args:  cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		content, err := history.Content(path, args[1])
+		if err != nil {
+			log.Fatalf("could not read version %s: %v", args[1], err)
+		}
+
+		if err := note.Save(path, content); err != nil {
+			log.Fatalf("could not restore %s: %v", path, err)
+		}
+
+		fmt.Printf("Restored %s to %s.\n", path, args[1])
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(historyCmd)
+	historyCmd.AddCommand(historyRestoreCmd)
+}
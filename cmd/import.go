@@ -0,0 +1,149 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"regexp"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/importer"
+)
+
+// importCmd represents the import command
+var importCmd = &cobra.Command{
+	Use:   "import",
+	Short: "Import notes from other apps.",
+	Long:  ``,
+}
+
+// importKeepCmd represents the import keep command
+var importKeepCmd = &cobra.Command{
+	Use:   "keep <takeout.zip>",
+	Short: "Import notes from a Google Takeout export of Keep.",
+	Long: `Import the notes in a Google Takeout export of Keep into the Keep folder.
+
+Checklists become checkboxes, labels become tags, and pinned and archived notes
+get "pinned: true" and "archived: true" in their frontmatter. Notes in the trash
+are skipped. Untitled notes are named after their first line, and attached
+images are copied to Keep/attachments.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		paths, err := importer.Keep(root, args[0])
+		if err != nil {
+			log.Fatalf("could not import %s: %v", args[0], err)
+		}
+
+		fmt.Printf("Imported %d notes into %s\n", len(paths), importer.KeepFolder)
+	},
+}
+
+// importFolderCmd represents the import folder command
+var importFolderCmd = &cobra.Command{
+	Use:   "folder <path>",
+	Short: "Copy a folder of markdown files into the notebook.",
+	Long: `Copy the markdown files under a folder into the notebook, keeping their paths
+unless a --map rule moves them. A rule is a pattern over the paths in the
+folder and the notebook folder to move the matches to, for example
+
+  nb import folder ~/Journal --map "journal/*=Log/" --date-from filename
+
+moves journal/2021-01-02.md to Log/2021-01-02.md. The first rule to match wins,
+and a rule matching a folder also moves everything below it.
+
+With --date-from, each note gets a date from its file name, its frontmatter, or
+when it was last modified. Notes with a date are given "date" in their
+frontmatter, and notes moved into the log folder are put where the log of that
+day belongs. File names are searched with --date-pattern, a regular expression
+whose first three groups are the year, month, and day.
+
+--set adds frontmatter to every note that does not have the key already. Use
+--dry-run to see where each file would go without copying anything. Files that
+would overwrite a note are skipped.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		maps, _ := cmd.Flags().GetStringArray("map")
+		dateFrom, _ := cmd.Flags().GetString("date-from")
+		datePattern, _ := cmd.Flags().GetString("date-pattern")
+		set, _ := cmd.Flags().GetStringArray("set")
+		dryRun, _ := cmd.Flags().GetBool("dry-run")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		f := importer.Folder{DateFrom: dateFrom}
+		switch dateFrom {
+		case "", "filename", "frontmatter", "modified":
+		default:
+			log.Fatalf("unknown --date-from %q, expected filename, frontmatter, or modified", dateFrom)
+		}
+
+		for _, m := range maps {
+			mapping, err := importer.ParseMapping(m)
+			if err != nil {
+				log.Fatalf("could not read --map: %v", err)
+			}
+			f.Mappings = append(f.Mappings, mapping)
+		}
+
+		if f.DatePattern, err = regexp.Compile(datePattern); err != nil {
+			log.Fatalf("could not read --date-pattern: %v", err)
+		}
+
+		for _, kv := range set {
+			parts := strings.SplitN(kv, "=", 2)
+			if len(parts) != 2 {
+				log.Fatalf("%q should be written as key=value", kv)
+			}
+			f.Frontmatter.Set(parts[0], parts[1])
+		}
+
+		copies, err := f.Plan(args[0], root)
+		if err != nil {
+			log.Fatalf("could not read %s: %v", args[0], err)
+		}
+
+		imported := 0
+		for _, c := range copies {
+			if c.Skip != "" {
+				fmt.Printf("skip  %s (%s)\n", c.From, c.Skip)
+				continue
+			}
+			if dryRun {
+				fmt.Printf("copy  %s -> %s\n", c.From, relativeTo(root, c.To))
+			}
+			imported++
+		}
+
+		if dryRun {
+			return
+		}
+
+		if err := f.Apply(copies); err != nil {
+			log.Fatalf("could not import %s: %v", args[0], err)
+		}
+
+		fmt.Printf("Imported %d notes\n", imported)
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(importCmd)
+	importCmd.AddCommand(importKeepCmd)
+	importCmd.AddCommand(importFolderCmd)
+	importFolderCmd.Flags().StringArray("map", []string{}, "Move files matching a pattern into a folder, as pattern=folder")
+	importFolderCmd.Flags().String("date-from", "", "Where notes get their date: filename, frontmatter, or modified")
+	importFolderCmd.Flags().String("date-pattern", importer.DefaultDatePattern, "Regular expression finding the year, month, and day in file names")
+	importFolderCmd.Flags().StringArray("set", []string{}, "Frontmatter to add to each note, as key=value")
+	importFolderCmd.Flags().Bool("dry-run", false, "Show where each file would go without copying")
+}
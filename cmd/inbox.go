@@ -0,0 +1,139 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/inbox"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/prompt"
+)
+
+// inboxCmd represents the inbox command
+var inboxCmd = &cobra.Command{
+	Use:   "inbox",
+	Short: "List the items waiting in the inbox.",
+	Long: `List the items waiting in Inbox.md at the root of the notebook. Items are
+added with "nb capture --inbox" and refiled with "nb inbox process".`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		items, err := inbox.Items(root)
+		if err != nil {
+			log.Fatalf("could not read the inbox: %v", err)
+		}
+
+		for _, it := range items {
+			fmt.Println("- " + it.Text)
+		}
+	},
+}
+
+// inboxProcessCmd represents the inbox process command
+var inboxProcessCmd = &cobra.Command{
+	Use:   "process",
+	Short: "Refile each item in the inbox.",
+	Long: `Go through the items in the inbox one by one and send each to today's log,
+to a note, or to the tasks of today's log. Notes are given as "path#Section",
+like "nb capture --to". Refiled items are removed from the inbox.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		items, err := inbox.Items(root)
+		if err != nil {
+			log.Fatalf("could not read the inbox: %v", err)
+		}
+
+		removed := 0
+		for i, it := range items {
+			fmt.Printf("\n(%d/%d) %s\n", i+1, len(items), it.Text)
+
+			// Items below the ones already removed have moved up.
+			it.Line -= removed
+
+			refiled, quit := refile(root, it)
+			if refiled {
+				if err := inbox.Remove(root, []inbox.Item{it}); err != nil {
+					log.Fatalf("could not remove the item from the inbox: %v", err)
+				}
+				removed++
+			}
+			if quit {
+				return
+			}
+		}
+	},
+}
+
+// refile asks where the item should go and sends it there. It reports whether
+// the item was refiled or dropped, and whether to stop processing.
+func refile(root string, it inbox.Item) (bool, bool) {
+	for {
+		answer, err := prompt.Ask("[l]og, [n]ote, [t]ask, [d]elete, [s]kip, [q]uit? ")
+		if err != nil {
+			return false, true
+		}
+
+		switch strings.ToLower(answer) {
+		case "l", "log":
+			path, err := noteLog.Ensure(0)
+			if err != nil {
+				log.Fatalf("could not fetch today's log: %v", err)
+			}
+			if err := note.Capture(path, "", "- "+it.Text); err != nil {
+				log.Fatalf("could not capture to %s: %v", path, err)
+			}
+			return true, false
+		case "n", "note":
+			to, err := prompt.Ask("Note (path#Section): ")
+			if err != nil || to == "" {
+				continue
+			}
+
+			path, section := note.Target(root, to)
+			if path == "" {
+				if path, err = noteLog.Ensure(0); err != nil {
+					log.Fatalf("could not fetch today's log: %v", err)
+				}
+			}
+			if err := note.Capture(path, section, "- "+it.Text); err != nil {
+				log.Fatalf("could not capture to %s: %v", path, err)
+			}
+			return true, false
+		case "t", "task":
+			path, err := noteLog.Ensure(0)
+			if err != nil {
+				log.Fatalf("could not fetch today's log: %v", err)
+			}
+			if err := noteLog.AppendTask(path, it.Text); err != nil {
+				log.Fatalf("could not add task to %s: %v", path, err)
+			}
+			return true, false
+		case "d", "delete":
+			return true, false
+		case "s", "skip", "":
+			return false, false
+		case "q", "quit":
+			return false, true
+		}
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(inboxCmd)
+	inboxCmd.AddCommand(inboxProcessCmd)
+}
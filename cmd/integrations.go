@@ -0,0 +1,230 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/calendar"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/github"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+	"github.com/t-eckert/nb/tracker"
+)
+
+// integrationsCmd represents the integrations command
+var integrationsCmd = &cobra.Command{
+	Use:   "integrations",
+	Short: "Pull activity from other services into the daily log.",
+	Long:  ``,
+}
+
+// integrationsGitHubCmd represents the integrations github command
+var integrationsGitHubCmd = &cobra.Command{
+	Use:   "github",
+	Short: "Work with GitHub.",
+	Long:  ``,
+}
+
+// integrationsGitHubPullCmd represents the integrations github pull command
+var integrationsGitHubPullCmd = &cobra.Command{
+	Use:   "pull",
+	Short: "Write your GitHub issues, review requests, and merged PRs into today's log.",
+	Long: `Fetch the open issues assigned to you, the pull requests waiting on your
+review, and your pull requests merged today, and write them into the GitHub
+section of today's log, replacing what an earlier pull wrote there.
+
+The token is read from "integrations.github.token" in the config file, or the
+GITHUB_TOKEN environment variable. Set "integrations.github.api_url" to use
+GitHub Enterprise.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		token := config.GitHubToken()
+		if token == "" {
+			log.Fatalf("no GitHub token, set integrations.github.token in the config file")
+		}
+
+		client := &github.Client{Token: token, API: config.GitHubAPI()}
+
+		activity, err := client.Pull(time.Now())
+		if err != nil {
+			log.Fatalf("could not pull from GitHub: %v", err)
+		}
+
+		replaceLogSection("GitHub", activity.Markdown())
+	},
+}
+
+// integrationsCalendarCmd represents the integrations calendar command
+var integrationsCalendarCmd = &cobra.Command{
+	Use:   "calendar",
+	Short: "Work with your calendar.",
+	Long:  ``,
+}
+
+// integrationsCalendarPullCmd represents the integrations calendar pull command
+var integrationsCalendarPullCmd = &cobra.Command{
+	Use:   "pull",
+	Short: "Write today's events into the Schedule section of today's log.",
+	Long: `Read today's events from an iCalendar address and write them into the
+Schedule section of today's log, replacing what an earlier pull wrote there.
+New logs get their Schedule section filled in the same way when they are
+created.
+
+Set "integrations.calendar.url" in the config file to the secret iCal address
+of a Google calendar, or the export address of a CalDAV calendar. For
+calendars behind a login, also set "integrations.calendar.username" and
+"integrations.calendar.password".`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		url := config.CalendarURL()
+		if url == "" {
+			log.Fatalf("no calendar, set integrations.calendar.url in the config file")
+		}
+
+		username, password := config.CalendarCredentials()
+		events, err := calendar.Fetch(url, username, password)
+		if err != nil {
+			log.Fatalf("could not fetch calendar: %v", err)
+		}
+
+		today := calendar.On(events, time.Now())
+		if len(today) == 0 {
+			fmt.Println("No events today.")
+			return
+		}
+
+		replaceLogSection(noteLog.ScheduleSection, calendar.Schedule(today))
+	},
+}
+
+// integrationsTrackerCmd represents the integrations tracker command
+var integrationsTrackerCmd = &cobra.Command{
+	Use:   "tracker",
+	Short: "Work with issue trackers.",
+	Long:  ``,
+}
+
+// integrationsTrackerPullCmd represents the integrations tracker pull command
+var integrationsTrackerPullCmd = &cobra.Command{
+	Use:   "pull",
+	Short: "Add your open tickets to the Work section of today's log.",
+	Long: `Add the open tickets assigned to you in Jira and Linear to the Work section
+of today's log, as tasks linking to each ticket. Tickets already in today's log
+are skipped.
+
+Jira is configured with "integrations.jira.url", "integrations.jira.email",
+and "integrations.jira.token". Leave out the email to use a personal access
+token on Jira Server. Linear is configured with "integrations.linear.token".
+
+When the task for a ticket is completed with "nb task done", nb can update the
+ticket as well. Set "integrations.jira.on_done" to "comment", or to
+"transition" along with "integrations.jira.transition" naming the transition
+to take. Set "integrations.linear.on_done" to "comment" or "complete".`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		trackers := configuredTrackers()
+		if len(trackers) == 0 {
+			log.Fatalf("no issue tracker, set integrations.jira or integrations.linear in the config file")
+		}
+
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		content, err := os.ReadFile(fileName)
+		if err != nil {
+			log.Fatalf("could not read %s: %v", fileName, err)
+		}
+
+		existing := map[string]bool{}
+		for _, t := range task.Parse(fileName, string(content)) {
+			if ticket, ok := tracker.FromText(t.Text); ok {
+				existing[ticket.Key] = true
+			}
+		}
+
+		added := 0
+		for _, tr := range trackers {
+			tickets, err := tr.Open()
+			if err != nil {
+				log.Fatalf("could not pull from %s: %v", tr.Name(), err)
+			}
+
+			for _, ticket := range tickets {
+				if existing[ticket.Key] {
+					continue
+				}
+
+				if err := note.Capture(fileName, tracker.Section, "- [ ] "+ticket.Line()); err != nil {
+					log.Fatalf("could not add %s to %s: %v", ticket.Key, fileName, err)
+				}
+				existing[ticket.Key] = true
+				added++
+			}
+		}
+
+		fmt.Printf("Added %d tickets to today's log.\n", added)
+	},
+}
+
+// configuredTrackers are the issue trackers set up in the config file.
+func configuredTrackers() []tracker.Tracker {
+	trackers := []tracker.Tracker{}
+
+	if url := config.Integration("jira", "url"); url != "" {
+		trackers = append(trackers, &tracker.Jira{
+			URL:        url,
+			Email:      config.Integration("jira", "email"),
+			Token:      config.Integration("jira", "token"),
+			OnDone:     config.Integration("jira", "on_done"),
+			Transition: config.Integration("jira", "transition"),
+		})
+	}
+
+	if token := config.Integration("linear", "token"); token != "" {
+		trackers = append(trackers, &tracker.Linear{
+			Token:  token,
+			OnDone: config.Integration("linear", "on_done"),
+		})
+	}
+
+	return trackers
+}
+
+// replaceLogSection writes body into a section of today's log.
+func replaceLogSection(section, body string) {
+	fileName, err := noteLog.Ensure(0)
+	if err != nil {
+		log.Fatalf("could not fetch today's log: %v", err)
+	}
+
+	content, err := os.ReadFile(fileName)
+	if err != nil {
+		log.Fatalf("could not read %s: %v", fileName, err)
+	}
+
+	if err := note.Save(fileName, []byte(note.ReplaceSection(string(content), section, body))); err != nil {
+		log.Fatalf("could not write %s: %v", fileName, err)
+	}
+
+	fmt.Printf("Updated the %s section of %s\n", section, fileName)
+}
+
+func init() {
+	rootCmd.AddCommand(integrationsCmd)
+	integrationsCmd.AddCommand(integrationsGitHubCmd)
+	integrationsGitHubCmd.AddCommand(integrationsGitHubPullCmd)
+	integrationsCmd.AddCommand(integrationsCalendarCmd)
+	integrationsCalendarCmd.AddCommand(integrationsCalendarPullCmd)
+	integrationsCmd.AddCommand(integrationsTrackerCmd)
+	integrationsTrackerCmd.AddCommand(integrationsTrackerPullCmd)
+}
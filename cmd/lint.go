@@ -0,0 +1,163 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"log"
+	"os"
+	"strconv"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/lint"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/prompt"
+)
+
+// lintCmd represents the lint command
+var lintCmd = &cobra.Command{
+	Use:   "lint",
+	Short: "Check the notebook for common problems.",
+	Long: `Check the notebook for common problems.
+
+The rules are:
+
+  broken-wikilink        [[links]] that do not match any note
+  broken-link            relative [markdown](links) to files that do not exist
+  duplicate-title        notes sharing the same title
+  malformed-frontmatter  frontmatter that is not valid YAML
+  template-placeholder   {{placeholders}} left over from a template
+  log-filename           files in the Log folder not named YYYY-MM-DD.md
+
+Rules can be turned off by listing them under "lint.disable" in the config.
+The command exits with status 1 when any problem is found.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		nb, err := lint.Load(root, logDir)
+		if err != nil {
+			log.Fatalf("could not read notebook: %v", err)
+		}
+
+		problems := lint.Run(nb, config.LintDisabled())
+
+		format, _ := cmd.Flags().GetString("format")
+		switch format {
+		case "json":
+			out, err := json.MarshalIndent(problems, "", "  ")
+			if err != nil {
+				log.Fatalf("could not encode problems: %v", err)
+			}
+			fmt.Println(string(out))
+		case "text":
+			for _, p := range problems {
+				fmt.Println(p)
+			}
+		default:
+			log.Fatalf("unknown format %q, expected text or json", format)
+		}
+
+		if len(problems) > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+// lintLinksCmd represents the lint links command
+var lintLinksCmd = &cobra.Command{
+	Use:   "links",
+	Short: "Check every link in the notebook.",
+	Long: `Resolve every wikilink and relative markdown link in the notebook and list
+the ones whose target does not exist.
+
+With --fix, each dead link is shown with the notes whose names are most like
+its target, and the one picked replaces the target of the link. Headings and
+aliases are kept. The command exits with status 1 when a dead link is left.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		fix, _ := cmd.Flags().GetBool("fix")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		nb, err := lint.Load(root, logDir)
+		if err != nil {
+			log.Fatalf("could not read notebook: %v", err)
+		}
+
+		left := 0
+		for _, d := range nb.DeadLinks() {
+			fmt.Printf("%s:%d: %s\n", d.Path, d.Line+1, d.Target)
+
+			if !fix || !fixLink(nb, d) {
+				left++
+			}
+		}
+
+		if left > 0 {
+			os.Exit(1)
+		}
+	},
+}
+
+// fixLink offers replacements for a dead link and rewrites it with the one
+// picked. It reports whether the link was fixed.
+func fixLink(nb *lint.Notebook, d lint.DeadLink) bool {
+	suggestions := nb.Suggest(d, 5)
+	if len(suggestions) == 0 {
+		fmt.Println("  no similar notes")
+		return false
+	}
+
+	for i, s := range suggestions {
+		fmt.Printf("  %d. %s\n", i+1, s)
+	}
+
+	answer, err := prompt.Ask("Replace with [1-" + strconv.Itoa(len(suggestions)) + "], or skip: ")
+	if err != nil {
+		return false
+	}
+
+	n, err := strconv.Atoi(answer)
+	if err != nil || n < 1 || n > len(suggestions) {
+		return false
+	}
+
+	content, err := os.ReadFile(d.Path)
+	if err != nil {
+		log.Fatalf("could not read %s: %v", d.Path, err)
+	}
+
+	updated := link.Replace(string(content), d.Line, d.Target, suggestions[n-1])
+	if err := note.Save(d.Path, []byte(updated)); err != nil {
+		log.Fatalf("could not save %s: %v", d.Path, err)
+	}
+
+	return true
+}
+
+func init() {
+	rootCmd.AddCommand(lintCmd)
+	lintCmd.AddCommand(lintLinksCmd)
+	lintCmd.Flags().String("format", "text", "Output format, text or json")
+	lintLinksCmd.Flags().Bool("fix", false, "Pick replacements for dead links interactively")
+}
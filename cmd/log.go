@@ -1,11 +1,19 @@
 package cmd
 
 import (
+	"fmt"
 	"log"
+	"os"
+	"strings"
+	"time"
 
 	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/date"
 	"github.com/t-eckert/nb/editor"
 	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/render"
+	"github.com/t-eckert/nb/terminal"
+	"github.com/t-eckert/nb/ui"
 )
 
 // logCmd represents the log command
@@ -15,27 +23,175 @@ var logCmd = &cobra.Command{
 	Long:  ``,
 
 	Run: func(cmd *cobra.Command, args []string) {
-		fileName, err := noteLog.LogPath(0)
+		fileName, err := noteLog.Ensure(0)
 		if err != nil {
 			log.Fatalf("could not fetch today's log: %v", err)
 		}
 
-		logExists, err := noteLog.DoesLogExist(fileName)
+		if err = editor.Open(fileName); err != nil {
+			log.Fatalf("could not open %s: %v", fileName, err)
+		}
+	},
+}
+
+// logArchiveCmd represents the log archive command
+var logArchiveCmd = &cobra.Command{
+	Use:   "archive",
+	Short: "Move old logs into the archive.",
+	Long: `Move every log dated before --before into Log/Archive/YYYY/.
+
+Archived logs can still be opened by date. They are left out of "nb stats"
+unless --archived is given.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		value, _ := cmd.Flags().GetString("before")
+
+		before, err := date.Parse(value, time.Now())
 		if err != nil {
-			log.Fatalf("could not check if log exists: %v", err)
+			log.Fatalf("could not parse --before: %v", err)
 		}
 
-		if !logExists {
-			noteLog.GenerateNew(fileName, 0)
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
 		}
 
-		if err = editor.Open(fileName); err != nil {
-			log.Fatalf("could not open %s: %v", fileName, err)
+		moved, err := noteLog.Archive(logDir, before)
+		fmt.Printf("Archived %d logs.\n", len(moved))
+		if err != nil {
+			log.Fatalf("could not archive logs: %v", err)
+		}
+	},
+}
+
+// logOnThisDayCmd represents the log onthisday command
+var logOnThisDayCmd = &cobra.Command{
+	Use:   "onthisday",
+	Short: "Show the logs from this date in earlier years.",
+	Long: `Print the logs written on today's date in earlier years, most recent first.
+With --months, the logs from one, three, and six months ago follow.
+
+Set "log.on_this_day: true" in the config file to link to them from the On This
+Day section of each new log.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		months, _ := cmd.Flags().GetBool("months")
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		paths, err := noteLog.OnThisDay(logDir, time.Now(), months)
+		if err != nil {
+			log.Fatalf("could not find earlier logs: %v", err)
+		}
+
+		if len(paths) == 0 {
+			fmt.Println("Nothing logged on this day before.")
+			return
+		}
+
+		for i, path := range paths {
+			content, err := os.ReadFile(path)
+			if err != nil {
+				log.Fatalf("could not read %s: %v", path, err)
+			}
+
+			if i > 0 {
+				fmt.Println()
+			}
+			fmt.Println(strings.TrimRight(string(content), "\n"))
+		}
+	},
+}
+
+// logRolloverCmd represents the log rollover command
+var logRolloverCmd = &cobra.Command{
+	Use:   "rollover",
+	Short: "Carry open tasks over to today's log.",
+	Long: `Copy the open tasks in the Tasks section of the most recent earlier log, with
+their open subtasks, into today's log, most urgent first. Each carried task
+gets a rolled:N token counting how many times it has been carried, which nb
+task report uses to find the ones that keep slipping. Tasks already in today's
+log are left out.
+
+Set "log.auto_rollover: true" in the config file to do this whenever today's
+log is first made.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		carried, err := noteLog.Rollover(logDir, fileName, time.Now())
+		if err != nil {
+			log.Fatalf("could not carry over tasks: %v", err)
+		}
+
+		fmt.Printf("Carried over %d tasks.\n", carried)
+	},
+}
+
+// logViewCmd represents the log view command
+var logViewCmd = &cobra.Command{
+	Use:   "view [date]",
+	Short: "Show a daily log in the terminal.",
+	Long: `Show a daily log in the terminal, today's by default. Headings, checkboxes,
+and code blocks are styled, and long logs are shown in $PAGER. Use --raw to
+print the markdown as it is.`,
+	Args: cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		raw, _ := cmd.Flags().GetBool("raw")
+
+		day := time.Now()
+		if len(args) == 1 {
+			var err error
+			if day, err = date.Parse(args[0], time.Now()); err != nil {
+				log.Fatalf("could not parse %s: %v", args[0], err)
+			}
+		}
+
+		path, err := noteLog.Find(day)
+		if err != nil {
+			log.Fatalf("could not find the log for %s: %v", day.Format("2006-01-02"), err)
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			log.Fatalf("could not read %s: %v", path, err)
+		}
+
+		if raw {
+			fmt.Print(string(content))
+			return
+		}
+
+		if err := terminal.Page(render.Markdown(string(content), ui.Style())); err != nil {
+			log.Fatalf("could not show %s: %v", path, err)
 		}
 	},
 }
 
 func init() {
 	rootCmd.AddCommand(logCmd)
+	logCmd.AddCommand(logArchiveCmd)
+	logCmd.AddCommand(logOnThisDayCmd)
+	logCmd.AddCommand(logRolloverCmd)
+	logCmd.AddCommand(logViewCmd)
 	logCmd.PersistentFlags().Int("Offset", 0, "Offset")
+	logArchiveCmd.Flags().String("before", "", "Archive logs dated before this date, e.g. 2022-01-01 or \"90 days ago\"")
+	logArchiveCmd.MarkFlagRequired("before")
+	logViewCmd.Flags().Bool("raw", false, "Print the markdown without rendering it")
+	logOnThisDayCmd.Flags().Bool("months", false, "Also show the logs from 1, 3, and 6 months ago")
 }
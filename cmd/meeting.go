@@ -0,0 +1,115 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/link"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/meeting"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+// meetingCmd represents the meeting command
+var meetingCmd = &cobra.Command{
+	Use:   "meeting",
+	Short: "Take meeting notes.",
+	Long:  ``,
+}
+
+// meetingNewCmd represents the meeting new command
+var meetingNewCmd = &cobra.Command{
+	Use:   "new <title>",
+	Short: "Create a meeting note and link it from today's log.",
+	Long: `Create a note in the Meetings folder with the date, time, and attendees in
+its frontmatter, link it from the Meetings section of today's log, and open it.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		attendees, _ := cmd.Flags().GetStringSlice("attendees")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		path, err := meeting.New(root, args[0], attendees, time.Now())
+		if err != nil {
+			log.Fatalf("could not create meeting note: %v", err)
+		}
+
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		if err := note.Capture(fileName, "Meetings", "- "+link.To(path)); err != nil {
+			log.Fatalf("could not link meeting from %s: %v", fileName, err)
+		}
+
+		if err := editor.Open(path); err != nil {
+			log.Fatalf("could not open %s: %v", path, err)
+		}
+	},
+}
+
+// meetingActionsCmd represents the meeting actions command
+var meetingActionsCmd = &cobra.Command{
+	Use:   "actions <note>",
+	Short: "Copy the open action items of a meeting into today's log.",
+	Long: `Copy every open "- [ ]" item in a meeting note into the Tasks section of
+today's log, with a link back to the meeting. Items already in today's log are
+skipped.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		actions, err := meeting.Actions(path)
+		if err != nil {
+			log.Fatalf("could not read %s: %v", path, err)
+		}
+
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		content, err := os.ReadFile(fileName)
+		if err != nil {
+			log.Fatalf("could not read %s: %v", fileName, err)
+		}
+		existing := task.Parse(fileName, string(content))
+
+		added := 0
+		for _, a := range actions {
+			text := a.Text + " (" + link.To(path) + ")"
+			if len(task.Duplicates(text, existing)) > 0 {
+				continue
+			}
+
+			if err := noteLog.AppendTask(fileName, text); err != nil {
+				log.Fatalf("could not add task to %s: %v", fileName, err)
+			}
+			added++
+		}
+
+		fmt.Printf("Added %d of %d action items to today's log.\n", added, len(actions))
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(meetingCmd)
+	meetingCmd.AddCommand(meetingNewCmd)
+	meetingCmd.AddCommand(meetingActionsCmd)
+	meetingNewCmd.Flags().StringSlice("attendees", []string{}, "Comma separated list of attendees")
+}
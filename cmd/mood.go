@@ -0,0 +1,109 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"strconv"
+	"time"
+
+	"github.com/spf13/cobra"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/mood"
+	"github.com/t-eckert/nb/prompt"
+)
+
+// logMoodCmd represents the log mood command
+var logMoodCmd = &cobra.Command{
+	Use:   "mood [1-5]",
+	Short: "Record today's mood.",
+	Long:  `Record today's mood from 1 to 5. You are asked for it when no rating is given.`,
+	Args:  cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		recordRating(mood.Mood, args)
+	},
+}
+
+// logEnergyCmd represents the log energy command
+var logEnergyCmd = &cobra.Command{
+	Use:   "energy [1-5]",
+	Short: "Record today's energy.",
+	Long:  `Record today's energy from 1 to 5. You are asked for it when no rating is given.`,
+	Args:  cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		recordRating(mood.Energy, args)
+	},
+}
+
+func recordRating(key string, args []string) {
+	answer := ""
+	if len(args) == 1 {
+		answer = args[0]
+	} else {
+		var err error
+		answer, err = prompt.Ask(fmt.Sprintf("How is your %s today (1-5)? ", key))
+		if err != nil {
+			log.Fatalf("could not read %s: %v", key, err)
+		}
+	}
+
+	value, err := strconv.Atoi(answer)
+	if err != nil {
+		log.Fatalf("%q is not a number from 1 to 5", answer)
+	}
+
+	fileName, err := noteLog.Ensure(0)
+	if err != nil {
+		log.Fatalf("could not fetch today's log: %v", err)
+	}
+
+	if err := mood.Set(fileName, key, value); err != nil {
+		log.Fatalf("could not record %s: %v", key, err)
+	}
+}
+
+// moodCmd represents the mood command
+var moodCmd = &cobra.Command{
+	Use:   "mood",
+	Short: "Look back at mood and energy.",
+	Long:  ``,
+}
+
+// moodReportCmd represents the mood report command
+var moodReportCmd = &cobra.Command{
+	Use:   "report",
+	Short: "Chart mood and energy over time.",
+	Long: `Chart the mood and energy recorded in the daily logs as sparklines, one
+character per day with the most recent day last.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		days, _ := cmd.Flags().GetInt("days")
+		if days < 1 {
+			log.Fatalf("--days must be at least 1")
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		for _, key := range []string{mood.Mood, mood.Energy} {
+			series, err := mood.Series(logDir, key, time.Now(), days)
+			if err != nil {
+				log.Fatalf("could not read %s: %v", key, err)
+			}
+
+			fmt.Printf("%-7s %s  avg %.1f\n", key, mood.Sparkline(series), mood.Average(series))
+		}
+	},
+}
+
+func init() {
+	logCmd.AddCommand(logMoodCmd)
+	logCmd.AddCommand(logEnergyCmd)
+
+	rootCmd.AddCommand(moodCmd)
+	moodCmd.AddCommand(moodReportCmd)
+	moodReportCmd.Flags().Int("days", 30, "Number of days to chart")
+}
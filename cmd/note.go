@@ -0,0 +1,181 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/move"
+	"github.com/t-eckert/nb/trash"
+)
+
+// noteCmd represents the note command
+var noteCmd = &cobra.Command{
+	Use:   "note",
+	Short: "Manage notes.",
+	Long:  ``,
+}
+
+// noteRenameCmd represents the note rename command
+var noteRenameCmd = &cobra.Command{
+	Use:   "rename <old> <new>",
+	Short: "Rename a note and update the links to it.",
+	Long: `Rename a note and update every wikilink and relative markdown link to it
+across the notebook.
+
+A new name without a folder keeps the note in its folder. Otherwise the new
+path is taken from the notebook root.`,
+	Args: cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		from, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		from, err = filepath.Abs(from)
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		to := args[1]
+		if !strings.HasSuffix(to, ".md") {
+			to += ".md"
+		}
+
+		if filepath.Base(to) != to {
+			root, err := config.GetRootDir()
+			if err != nil {
+				log.Fatalf("could not get root directory: %v", err)
+			}
+			to = filepath.Join(root, to)
+		} else {
+			to = filepath.Join(filepath.Dir(from), to)
+		}
+
+		moveNote(from, to, false)
+	},
+}
+
+// noteMoveCmd represents the note move command
+var noteMoveCmd = &cobra.Command{
+	Use:   "move <path> <dest-folder>",
+	Short: "Move a note to another folder and update the links to it.",
+	Long: `Move a note to another folder, keeping its name and frontmatter. Every
+wikilink and relative markdown link to it across the notebook is updated, as
+are the relative links inside it. The destination folder is taken from the
+notebook root and created if needed.`,
+	Args: cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		force, _ := cmd.Flags().GetBool("force")
+
+		from, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		from, err = filepath.Abs(from)
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		moveNote(from, filepath.Join(root, args[1], filepath.Base(from)), force)
+	},
+}
+
+// noteRmCmd represents the note rm command
+var noteRmCmd = &cobra.Command{
+	Use:   "rm <path>",
+	Short: "Move a note to the trash.",
+	Long: `Move a note to the trash. It can be brought back with "nb trash restore".`,
+	Args:  cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		path, err = filepath.Abs(path)
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		item, err := trash.Remove(root, path, time.Now())
+		if err != nil {
+			log.Fatalf("could not remove %s: %v", path, err)
+		}
+
+		fmt.Printf("Moved %s to the trash as %s.\n", item.Path, item.ID)
+	},
+}
+
+// moveNote moves a note and reports the links that were updated.
+func moveNote(from, to string, force bool) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		log.Fatalf("could not get root directory: %v", err)
+	}
+
+	result, err := move.Note(root, from, to, force)
+	if err != nil {
+		log.Fatalf("could not move %s: %v", from, err)
+	}
+
+	fmt.Printf("Moved %s to %s\n", from, to)
+	fmt.Printf("Updated %d links in %d notes.\n", result.Links, result.Notes)
+}
+
+// noteRelatedCmd represents the note related command
+var noteRelatedCmd = &cobra.Command{
+	Use:   "related <path>",
+	Short: "List the notes most related to a note.",
+	Long: `List the notes most related to a note, best first. Notes are related when
+they link to each other, link to or are linked from the same notes, or share
+tags in their frontmatter.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		count, _ := cmd.Flags().GetInt("count")
+
+		path, err := resolveNote(args[0])
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		path, err = filepath.Abs(path)
+		if err != nil {
+			log.Fatalf("could not resolve %s: %v", args[0], err)
+		}
+
+		root, g := buildGraph()
+		for _, r := range g.Related(path, count) {
+			fmt.Printf("%3d  %s\n", r.Score, relativeTo(root, r.Path))
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(noteCmd)
+	noteCmd.AddCommand(noteRenameCmd)
+	noteCmd.AddCommand(noteMoveCmd)
+	noteCmd.AddCommand(noteRmCmd)
+	noteCmd.AddCommand(noteRelatedCmd)
+	noteMoveCmd.Flags().Bool("force", false, "Overwrite a note with the same name in the destination")
+	noteRelatedCmd.Flags().IntP("count", "n", 10, "Number of notes to list")
+}
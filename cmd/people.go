@@ -0,0 +1,148 @@
+package cmd
+
+import (
+	"fmt"
+	"io"
+	"log"
+	"net/http"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/network"
+	"github.com/t-eckert/nb/people"
+	"github.com/t-eckert/nb/vcard"
+)
+
+// peopleCmd represents the people command
+var peopleCmd = &cobra.Command{
+	Use:     "people",
+	Aliases: []string{"person"},
+	Short:   "Manage the People pages of the notebook.",
+	Long:    ``,
+}
+
+// peopleSyncCmd represents the people sync command
+var peopleSyncCmd = &cobra.Command{
+	Use:   "sync",
+	Short: "Create and update People pages from contacts.",
+	Long: `Create and update People pages from a vCard file.
+
+The --vcf flag takes either a path to a .vcf file or an http(s) URL that serves
+one, such as the export URL of a CardDAV address book. The email, org, phone,
+and birthday of each contact are written to the frontmatter of its page.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		source, _ := cmd.Flags().GetString("vcf")
+		if source == "" {
+			log.Fatalf("a contacts file is required, pass one with --vcf")
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		contacts, err := openContacts(source)
+		if err != nil {
+			log.Fatalf("could not open %s: %v", source, err)
+		}
+		defer contacts.Close()
+
+		cards, err := vcard.Parse(contacts)
+		if err != nil {
+			log.Fatalf("could not read contacts: %v", err)
+		}
+
+		created, updated, err := people.Sync(root, cards)
+		if err != nil {
+			log.Fatalf("could not sync contacts: %v", err)
+		}
+
+		fmt.Printf("Created %d and updated %d People pages.\n", created, updated)
+	},
+}
+
+// peopleNewCmd represents the people new command
+var peopleNewCmd = &cobra.Command{
+	Use:   "new <name>",
+	Short: "Create a People page and open it.",
+	Long:  ``,
+	Args:  cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		path, err := people.New(root, strings.Join(args, " "))
+		if err != nil {
+			log.Fatalf("could not create People page: %v", err)
+		}
+
+		if err := editor.Open(path); err != nil {
+			log.Fatalf("could not open %s: %v", path, err)
+		}
+	},
+}
+
+// peopleShowCmd represents the people show command
+var peopleShowCmd = &cobra.Command{
+	Use:   "show <name>",
+	Short: "List every note that mentions a person.",
+	Long: `List every line in the notebook that mentions a person, either as an
+@mention or as a [[wikilink]] to their page. For "Sam Smith" the mentions
+@SamSmith, @sam_smith, and @Sam all match.`,
+	Args: cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		mentions, err := people.Mentions(root, strings.Join(args, " "))
+		if err != nil {
+			log.Fatalf("could not search for mentions: %v", err)
+		}
+
+		for _, m := range mentions {
+			fmt.Printf("%s:%d: %s\n", m.Path, m.Line+1, m.Text)
+		}
+	},
+}
+
+func openContacts(source string) (io.ReadCloser, error) {
+	if !strings.HasPrefix(source, "http://") && !strings.HasPrefix(source, "https://") {
+		return os.Open(source)
+	}
+
+	client, err := network.Client(30 * time.Second)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := client.Get(source)
+	if err != nil {
+		return nil, err
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		resp.Body.Close()
+		return nil, fmt.Errorf("unexpected status %s", resp.Status)
+	}
+
+	return resp.Body, nil
+}
+
+func init() {
+	rootCmd.AddCommand(peopleCmd)
+	peopleCmd.AddCommand(peopleSyncCmd)
+	peopleCmd.AddCommand(peopleNewCmd)
+	peopleCmd.AddCommand(peopleShowCmd)
+	peopleSyncCmd.Flags().String("vcf", "", "Path or URL of a vCard file")
+}
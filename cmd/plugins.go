@@ -0,0 +1,54 @@
+package cmd
+
+import (
+	"errors"
+	"fmt"
+	"log"
+	"os"
+	"os/exec"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/plugin"
+)
+
+// pluginsCmd represents the plugins command
+var pluginsCmd = &cobra.Command{
+	Use:   "plugins",
+	Short: "List the plugins on the PATH.",
+	Long: `List the nb-<name> executables on the PATH. Running "nb <name>" runs the
+plugin with the rest of the arguments, as git and cargo do, unless nb has a
+command of that name.
+
+Plugins get the environment of nb along with NB_NOTEBOOK, NB_LOG_DIR,
+NB_STATE_DIR, NB_CONFIG, and NB_EDITOR.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		names := plugin.List()
+		if len(names) == 0 {
+			fmt.Println("No plugins found.")
+			return
+		}
+
+		for _, name := range names {
+			fmt.Println(name)
+		}
+	},
+}
+
+// runPlugin runs a plugin in place of nb, exiting with its exit code.
+func runPlugin(path string, args []string) {
+	initConfig()
+
+	if err := plugin.Run(path, args); err != nil {
+		var exit *exec.ExitError
+		if errors.As(err, &exit) {
+			os.Exit(exit.ExitCode())
+		}
+		log.Fatalf("could not run %s: %v", path, err)
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(pluginsCmd)
+}
@@ -0,0 +1,140 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"log"
+	"os"
+	"strings"
+	"text/tabwriter"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/query"
+)
+
+// queryCmd represents the query command
+var queryCmd = &cobra.Command{
+	Use:   "query <query>",
+	Short: "List notes by their frontmatter.",
+	Long: `List notes by their frontmatter, for example:
+
+  nb query "from Projects where status = 'active' and due < 2025-06-01 sort by due"
+
+A query has the form
+
+  [table <field>, ...] [from <folder>] [where <condition>] [sort by <field> [asc|desc]] [limit <n>]
+
+Conditions compare a field with =, !=, <, <=, >, >=, or contains, and can be
+combined with and, or, not, and parentheses. Besides frontmatter keys, the
+fields file.name, file.path, and file.folder are available.`,
+	Args: cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		format, _ := cmd.Flags().GetString("format")
+
+		q, err := query.Parse(strings.Join(args, " "))
+		if err != nil {
+			log.Fatalf("could not parse query: %v", err)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		rows, err := q.Run(root)
+		if err != nil {
+			log.Fatalf("could not run query: %v", err)
+		}
+
+		printRows(q.Columns(), rows, format)
+	},
+}
+
+// queryRunCmd represents the query run command
+var queryRunCmd = &cobra.Command{
+	Use:   "run <name>",
+	Short: "Run a saved query.",
+	Long: `Run a saved query by name.
+
+Queries are saved under "queries" in the config file, or as notes in the
++Queries folder with the query in their "query" frontmatter key. The note's
+file name is the name of the query. "nb serve" shows each saved query at
+/queries/<name>.`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		format, _ := cmd.Flags().GetString("format")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		saved, err := query.Saved(root, config.Queries())
+		if err != nil {
+			log.Fatalf("could not read saved queries: %v", err)
+		}
+
+		text, ok := saved[args[0]]
+		if !ok {
+			log.Fatalf("no saved query named %q", args[0])
+		}
+
+		q, err := query.Parse(text)
+		if err != nil {
+			log.Fatalf("could not parse query %s: %v", args[0], err)
+		}
+
+		rows, err := q.Run(root)
+		if err != nil {
+			log.Fatalf("could not run query %s: %v", args[0], err)
+		}
+
+		printRows(q.Columns(), rows, format)
+	},
+}
+
+func printRows(columns []string, rows []query.Row, format string) {
+	switch format {
+	case "json":
+		out := []map[string]interface{}{}
+		for _, row := range rows {
+			record := map[string]interface{}{"path": row.Path}
+			for i, column := range columns {
+				record[column] = query.Text(row.Values[i])
+			}
+			out = append(out, record)
+		}
+
+		encoded, err := json.MarshalIndent(out, "", "  ")
+		if err != nil {
+			log.Fatalf("could not encode results: %v", err)
+		}
+		fmt.Println(string(encoded))
+	case "text":
+		w := tabwriter.NewWriter(os.Stdout, 0, 0, 2, ' ', 0)
+		fmt.Fprintln(w, strings.ToUpper(strings.Join(columns, "\t")))
+		for _, row := range rows {
+			values := []string{}
+			for _, value := range row.Values {
+				text := query.Text(value)
+				if text == "" {
+					text = "-"
+				}
+				values = append(values, text)
+			}
+			fmt.Fprintln(w, strings.Join(values, "\t"))
+		}
+		w.Flush()
+	default:
+		log.Fatalf("unknown format %q, expected text or json", format)
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(queryCmd)
+	queryCmd.AddCommand(queryRunCmd)
+	queryCmd.PersistentFlags().String("format", "text", "Output format, text or json")
+}
@@ -0,0 +1,53 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"math/rand"
+	"path/filepath"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/note"
+)
+
+// randomCmd represents the random command
+var randomCmd = &cobra.Command{
+	Use:   "random",
+	Short: "Open a random note.",
+	Long:  `Open a random note, to come across old ideas again.`,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		folder, _ := cmd.Flags().GetString("folder")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		paths, err := note.Paths(filepath.Join(root, folder))
+		if err != nil {
+			log.Fatalf("could not list notes: %v", err)
+		}
+
+		if len(paths) == 0 {
+			fmt.Println("No notes to pick from.")
+			return
+		}
+
+		rand.Seed(time.Now().UnixNano())
+		path := paths[rand.Intn(len(paths))]
+
+		if err := editor.Open(path); err != nil {
+			log.Fatalf("could not open %s: %v", path, err)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(randomCmd)
+	randomCmd.Flags().String("folder", "", "Only pick from this folder of the notebook")
+}
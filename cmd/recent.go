@@ -0,0 +1,74 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"path/filepath"
+	"strconv"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/changes"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/prompt"
+)
+
+// recentCmd represents the recent command
+var recentCmd = &cobra.Command{
+	Use:   "recent",
+	Short: "List recently modified notes and pick one to open.",
+	Long: `List the notes in the notebook that were changed most recently, newest
+first, by when their files were last modified, whether by nb, an editor, or a
+sync tool. Notes in hidden folders and notes matched by the ignore file are
+left out.
+
+Pick a note by its number to open it in the editor, or press enter to quit.
+--limit sets how many notes are listed, 10 by default; 0 lists them all.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		limit, _ := cmd.Flags().GetInt("limit")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		paths, err := changes.Recent(root, limit)
+		if err != nil {
+			log.Fatalf("could not list notes: %v", err)
+		}
+
+		if len(paths) == 0 {
+			fmt.Println("No notes yet.")
+			return
+		}
+
+		for i, path := range paths {
+			rel, err := filepath.Rel(root, path)
+			if err != nil {
+				rel = path
+			}
+			fmt.Printf("%3d  %s\n", i+1, rel)
+		}
+
+		answer, err := prompt.Ask("Open which note? ")
+		if err != nil || answer == "" {
+			return
+		}
+
+		choice, err := strconv.Atoi(answer)
+		if err != nil || choice < 1 || choice > len(paths) {
+			log.Fatalf("%q is not one of the notes listed", answer)
+		}
+
+		if err := editor.Open(paths[choice-1]); err != nil {
+			log.Fatalf("could not open %s: %v", paths[choice-1], err)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(recentCmd)
+	recentCmd.Flags().Int("limit", 10, "How many notes to list")
+}
@@ -0,0 +1,63 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/remind"
+	"github.com/t-eckert/nb/ui"
+)
+
+// remindCmd represents the remind command
+var remindCmd = &cobra.Command{
+	Use:   "remind",
+	Short: "Inspect reminders on tasks.",
+	Long: `Inspect reminders on tasks.
+
+Add a "remind: YYYY-MM-DD HH:MM" token to a task to be reminded about it. The
+time is optional. Reminders are delivered as desktop notifications while
+"nb daemon" is running.`,
+}
+
+// remindListCmd represents the remind list command
+var remindListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List pending reminders on open tasks.",
+	Long:  ``,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		reminders, err := remind.Tasks(logDir)
+		if err != nil {
+			log.Fatalf("could not read reminders: %v", err)
+		}
+
+		if len(reminders) == 0 {
+			fmt.Println("No pending reminders.")
+			return
+		}
+
+		now := time.Now()
+		for _, r := range reminders {
+			overdue := ""
+			if r.At.Before(now) {
+				overdue = " (overdue)"
+			}
+
+			fmt.Printf("%s  %s  %s%s\n", ui.Dim(r.ID()), ui.Accent(ui.Date(r.At)+r.At.Format(" 15:04")), r.Text, overdue)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(remindCmd)
+	remindCmd.AddCommand(remindListCmd)
+}
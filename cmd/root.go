@@ -4,12 +4,18 @@ import (
 	"fmt"
 	"log"
 	"os"
+	"strings"
+	"time"
 
 	"github.com/spf13/cobra"
 
 	"github.com/spf13/viper"
 	"github.com/t-eckert/nb/config"
 	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/plugin"
+	"github.com/t-eckert/nb/prompt"
+	"github.com/t-eckert/nb/ui"
+	"github.com/t-eckert/nb/verbose"
 )
 
 var cfgFile string
@@ -18,7 +24,33 @@ var cfgFile string
 var rootCmd = &cobra.Command{
 	Use:   "nb",
 	Short: "NotaBene is a command line application for taking notes",
-	Long:  `NotaBene is a command line application for taking notes`,
+	Long: `NotaBene is a command line application for taking notes.
+
+Settings are read from .nb.yaml in the home directory, and then from .nb.toml
+at the root of the notebook, if there is one, so that a shared notebook can
+carry its own conventions. The notebook file may only set conventions such as
+log.*, fmt.*, habits, queries, and template variables: hooks, the editor,
+integrations, remotes, and other settings that run commands or hold
+credentials are only read there once the file is trusted, which nb asks about
+the first time it sees them, or with nb trust. Any change to a trusted file
+has to be trusted again.
+
+Any setting can be overridden with an environment variable named after its key
+in capitals, with dots as underscores and an NB_ prefix, such as
+NB_NOTEBOOK_PATH, NB_EDITOR, NB_SERVE_PORT, or NB_INTEGRATIONS_GITHUB_TOKEN.
+
+Shell commands set under "hooks" run at pre_log_create, post_log_create,
+post_note_create, pre_edit, post_edit, post_rollover, pre_backup, and
+post_backup, with NB_EVENT, NB_FILE, NB_DATE, NB_COMMAND, and NB_NOTEBOOK in
+their environment. A failing pre_ hook stops what it runs before.
+
+With --offline, or NB_OFFLINE=1, nb does not use the network: clipping,
+integrations, and backups to a remote fail straight away, and new logs are made
+without the calendar schedule.
+
+With -v, nb says on stderr which files it reads and writes, which hooks, plugins,
+and editors it runs, and which requests it makes, with credentials left out.
+With -vv it says more. NB_VERBOSE=debug or NB_VERBOSE=trace does the same.`,
 	Run: func(cmd *cobra.Command, args []string) {
 		root, err := config.GetRootDir()
 		if err != nil {
@@ -32,7 +64,19 @@ var rootCmd = &cobra.Command{
 
 // Execute adds all child commands to the root command and sets flags appropriately.
 // This is called by main.main(). It only needs to happen once to the rootCmd.
+//
+// Subcommands nb does not know are run as plugins, so that `nb foo` runs an
+// nb-foo executable on the PATH when there is one.
 func Execute() {
+	if len(os.Args) > 1 && !strings.HasPrefix(os.Args[1], "-") {
+		if _, _, err := rootCmd.Find(os.Args[1:]); err != nil {
+			if path, err := plugin.Find(os.Args[1]); err == nil {
+				runPlugin(path, os.Args[2:])
+				return
+			}
+		}
+	}
+
 	cobra.CheckErr(rootCmd.Execute())
 }
 
@@ -43,7 +87,10 @@ func init() {
 	// Cobra supports persistent flags, which, if defined here,
 	// will be global for your application.
 
-	rootCmd.PersistentFlags().StringVar(&cfgFile, "config", "", "config file (default is $HOME/.nb.yaml)")
+	rootCmd.PersistentFlags().StringVar(&cfgFile, "config", "", "config file (default is .nb.yaml in the home directory)")
+	rootCmd.PersistentFlags().Bool("offline", false, "Do not use the network")
+	viper.BindPFlag("offline", rootCmd.PersistentFlags().Lookup("offline"))
+	rootCmd.PersistentFlags().CountP("verbose", "v", "Say what nb is doing on stderr, -vv for more")
 
 	// Cobra also supports local flags, which will only run
 	// when this action is called directly.
@@ -52,6 +99,14 @@ func init() {
 
 // initConfig reads in config file and ENV variables if set.
 func initConfig() {
+	// The level is read before the config so that reading the config can be
+	// traced. It is not NB_LOG, which viper reads as the log section.
+	level := verbose.FromEnv()
+	if count, _ := rootCmd.PersistentFlags().GetCount("verbose"); count > level {
+		level = count
+	}
+	verbose.SetLevel(level)
+
 	if cfgFile != "" {
 		// Use config file from the flag.
 		viper.SetConfigFile(cfgFile)
@@ -66,10 +121,66 @@ func initConfig() {
 		viper.SetConfigName(".nb")
 	}
 
-	viper.AutomaticEnv() // read in environment variables that match
+	// Every setting can be overridden with an environment variable named
+	// after its key, such as NB_NOTEBOOK_PATH or NB_SERVE_PORT.
+	viper.SetEnvPrefix("nb")
+	viper.SetEnvKeyReplacer(strings.NewReplacer(".", "_"))
+	viper.AutomaticEnv()
 
 	// If a config file is found, read it in.
 	if err := viper.ReadInConfig(); err == nil {
 		fmt.Fprintln(os.Stderr, "Using config file:", viper.ConfigFileUsed())
 	}
+
+	if path, ignored, err := config.MergeLocal(); err != nil {
+		fmt.Fprintf(os.Stderr, "Could not read the notebook config file: %v\n", err)
+	} else if path != "" {
+		fmt.Fprintln(os.Stderr, "Using notebook config file:", path)
+		if len(ignored) > 0 {
+			trustLocal(path, ignored)
+		}
+	}
+
+	// Days start and end in the configured timezone rather than the one
+	// of the system.
+	if loc, err := config.Timezone(); err != nil {
+		fmt.Fprintf(os.Stderr, "Could not load the timezone: %v\n", err)
+	} else {
+		time.Local = loc
+	}
+
+	if problems := config.Validate(); len(problems) == 1 {
+		fmt.Fprintln(os.Stderr, "1 problem in the config, see nb config validate")
+	} else if len(problems) > 1 {
+		fmt.Fprintf(os.Stderr, "%d problems in the config, see nb config validate\n", len(problems))
+	}
+
+	log.SetFlags(0)
+	log.SetPrefix(ui.Error("error: "))
+}
+
+// trustLocal asks whether to trust a notebook config file that sets keys a
+// notebook may not set on its own, such as hooks, and reads them when the
+// answer is yes. Without a terminal to ask on, the keys are ignored.
+func trustLocal(path string, ignored []string) {
+	fmt.Fprintf(os.Stderr, "%s sets %s, which can run commands or send credentials elsewhere.\n", path, strings.Join(ignored, ", "))
+
+	if info, err := os.Stdin.Stat(); err != nil || info.Mode()&os.ModeCharDevice == 0 {
+		fmt.Fprintln(os.Stderr, "Ignoring them. Run nb trust to allow them.")
+		return
+	}
+
+	answer, err := prompt.Ask("Trust this notebook config file? [y/N] ")
+	if err != nil || (answer != "y" && answer != "yes") {
+		fmt.Fprintln(os.Stderr, "Ignoring them. Run nb trust to allow them later.")
+		return
+	}
+
+	if err := config.Trust(path); err != nil {
+		fmt.Fprintf(os.Stderr, "Could not trust %s: %v\n", path, err)
+		return
+	}
+	if _, _, err := config.MergeLocal(); err != nil {
+		fmt.Fprintf(os.Stderr, "Could not read the notebook config file: %v\n", err)
+	}
 }
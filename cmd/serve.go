@@ -8,13 +8,42 @@ import (
 // serveCmd represents the serve command
 var serveCmd = &cobra.Command{
 	Use:   "serve",
-	Short: "",
-	Long:  ``,
+	Short: "Serve the notebook over HTTP.",
+	Long: `Serve the notebook over HTTP, along with a feed of the latest logs at
+/feed.xml, a calendar at /calendar.ics, and saved queries at /queries/. Notes
+are rendered as web pages at /notes/<path>, with mermaid diagrams drawn in the
+browser.
+
+The notebook is only served to this machine unless --host says otherwise, such
+as --host 0.0.0.0 for every network it is on. Nothing is authenticated, so
+whoever can reach the address can read the notebook.
+
+With --edit, notes can also be edited at /edit/<path>, and /today opens
+today's log in the editor. Saves are refused when the note has changed on disk
+since it was opened. Only turn on editing where everyone who can reach the
+address may change the notebook.
+
+Private notes are not served, listed, or put in the feed and calendar. A note
+is private when its frontmatter has "private: true" or it is in one of the
+folders listed under "notes.private".
+
+Set "serve.math" to typeset the TeX between $ and $$ with KaTeX, and
+"serve.related" to list the notes related to each note below it, as
+"nb note related" does.
+
+Pages use the theme set with "serve.theme": light, dark, sepia, or auto, which
+follows the browser. "serve.css" can point at a CSS file to add after it.`,
+
 	Run: func(cmd *cobra.Command, args []string) {
-		serve.Serve()
+		host, _ := cmd.Flags().GetString("host")
+		edit, _ := cmd.Flags().GetBool("edit")
+
+		serve.Serve(host, edit)
 	},
 }
 
 func init() {
 	rootCmd.AddCommand(serveCmd)
+	serveCmd.Flags().String("host", "127.0.0.1", "The address to listen on")
+	serveCmd.Flags().Bool("edit", false, "Let notes be edited in the browser")
 }
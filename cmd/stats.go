@@ -0,0 +1,82 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/stats"
+)
+
+// statsCmd represents the stats command
+var statsCmd = &cobra.Command{
+	Use:   "stats",
+	Short: "Show writing statistics from the daily logs.",
+	Long: `Show writing statistics from the daily logs: words written, tasks
+completed, and logging streaks. Use --format json to get every day and week for
+graphing elsewhere.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		logDir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		archived, _ := cmd.Flags().GetBool("archived")
+
+		s, err := stats.Compute(root, logDir, time.Now(), archived)
+		if err != nil {
+			log.Fatalf("could not compute statistics: %v", err)
+		}
+
+		format, _ := cmd.Flags().GetString("format")
+		switch format {
+		case "json":
+			out, err := json.MarshalIndent(s, "", "  ")
+			if err != nil {
+				log.Fatalf("could not encode statistics: %v", err)
+			}
+			fmt.Println(string(out))
+		case "text":
+			printStats(s)
+		default:
+			log.Fatalf("unknown format %q, expected text or json", format)
+		}
+	},
+}
+
+func printStats(s *stats.Stats) {
+	fmt.Printf("Logs:             %d\n", s.Logs)
+	fmt.Printf("Other notes:      %d\n", s.Notes)
+	fmt.Printf("Words in logs:    %d\n", s.Words)
+	fmt.Printf("Tasks completed:  %d/%d (%.0f%%)\n", s.TasksDone, s.TasksTotal, s.CompletionRate*100)
+	fmt.Printf("Longest streak:   %d days\n", s.LongestStreak)
+	fmt.Printf("Current streak:   %d days\n", s.CurrentStreak)
+
+	weeks := s.Weeks
+	if len(weeks) > 8 {
+		weeks = weeks[len(weeks)-8:]
+	}
+
+	if len(weeks) > 0 {
+		fmt.Println("\nWords per week")
+	}
+	for _, w := range weeks {
+		fmt.Printf("  %s  %d\n", w.Week, w.Words)
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(statsCmd)
+	statsCmd.Flags().String("format", "text", "Output format, text or json")
+	statsCmd.Flags().Bool("archived", false, "Include archived logs")
+}
@@ -0,0 +1,468 @@
+package cmd
+
+import (
+	"encoding/json"
+	"fmt"
+	"log"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/prompt"
+	"github.com/t-eckert/nb/stats"
+	"github.com/t-eckert/nb/task"
+	"github.com/t-eckert/nb/tracker"
+	"github.com/t-eckert/nb/ui"
+)
+
+// taskCmd represents the task command
+var taskCmd = &cobra.Command{
+	Use:     "task",
+	Aliases: []string{"todo"},
+	Short:   "Manage tasks in the daily logs.",
+	Long:    ``,
+}
+
+// taskAddCmd represents the task add command
+var taskAddCmd = &cobra.Command{
+	Use:   "add <text>",
+	Short: "Add a task to today's log.",
+	Long: `Add a task to the Tasks section of today's log.
+
+Before adding, open tasks in the logs are checked for near-duplicates. If one
+is found you are asked whether to add the task anyway. Use --force to skip
+the check.`,
+	Args: cobra.MinimumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		text := strings.Join(args, " ")
+
+		force, _ := cmd.Flags().GetBool("force")
+		if !force && !confirmNotDuplicate(text) {
+			return
+		}
+
+		fileName, err := noteLog.Ensure(0)
+		if err != nil {
+			log.Fatalf("could not fetch today's log: %v", err)
+		}
+
+		if err := noteLog.AppendTask(fileName, text); err != nil {
+			log.Fatalf("could not add task to %s: %v", fileName, err)
+		}
+	},
+}
+
+// taskDoneCmd represents the task done command
+var taskDoneCmd = &cobra.Command{
+	Use:   "done <id>",
+	Short: "Check off a task in the logs.",
+	Long: `Check off the open task with the given id in the logs. The id is shown by
+"nb board".
+
+When the task links to a Jira or Linear ticket pulled in with
+"nb integrations tracker pull", the ticket is updated as configured with
+"integrations.<tracker>.on_done".`,
+	Args: cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		dir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		tasks, err := task.FromLogs(dir)
+		if err != nil {
+			log.Fatalf("could not read tasks: %v", err)
+		}
+
+		t, ok := task.Find(task.Open(tasks), args[0])
+		if !ok {
+			log.Fatalf("no open task has the id %s", args[0])
+		}
+
+		if err := task.SetStatus(t, task.Done); err != nil {
+			log.Fatalf("could not complete task: %v", err)
+		}
+
+		ticket, ok := tracker.FromText(t.Text)
+		if !ok {
+			return
+		}
+
+		if tr, ok := tracker.For(configuredTrackers(), ticket); ok {
+			if err := tr.Done(ticket); err != nil {
+				log.Fatalf("could not update %s in %s: %v", ticket.Key, tr.Name(), err)
+			}
+		}
+	},
+}
+
+// taskDeferCmd represents the task defer command
+var taskDeferCmd = &cobra.Command{
+	Use:   "defer <id> <date>",
+	Short: "Move a task to the log of a later day.",
+	Long: `Move the open task with the given id out of its log and into the log for a
+later day, generating that log if it does not exist yet. The date can be a
+YYYY-MM-DD date, "tomorrow", or an offset such as "+3d" or "+1w". The task is
+marked with a "deferred:" token for the day it was moved from. Its open
+subtasks move with it.`,
+	Args: cobra.ExactArgs(2),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		dir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		tasks, err := task.FromLogs(dir)
+		if err != nil {
+			log.Fatalf("could not read tasks: %v", err)
+		}
+
+		t, ok := task.Find(task.Open(tasks), args[0])
+		if !ok {
+			log.Fatalf("no open task has the id %s", args[0])
+		}
+
+		now := time.Now()
+		target, err := date.Parse(args[1], now)
+		if err != nil {
+			log.Fatalf("could not parse date: %v", err)
+		}
+
+		today := date.StartOfDay(now)
+		if !target.After(today) {
+			log.Fatalf("tasks can only be deferred to a later day than today")
+		}
+
+		from, ok := logpath.Date(dir, t.Path, time.Local)
+		if !ok {
+			from = today
+		}
+
+		fileName, err := noteLog.Ensure(int(target.Sub(today).Hours()+12) / 24)
+		if err != nil {
+			log.Fatalf("could not fetch the log for %s: %v", target.Format(note.DateFormat), err)
+		}
+
+		if filepath.Clean(fileName) == filepath.Clean(t.Path) {
+			log.Fatalf("the task is already in the log for %s", target.Format(note.DateFormat))
+		}
+
+		subtasks := task.Subtasks(tasks, t)
+		if err := noteLog.AppendTasks(fileName, task.Lines(task.Deferred(t, from), t, subtasks)); err != nil {
+			log.Fatalf("could not add task to %s: %v", fileName, err)
+		}
+
+		if err := task.Remove(append([]task.Task{t}, subtasks...)...); err != nil {
+			log.Fatalf("could not remove task from %s: %v", t.Path, err)
+		}
+	},
+}
+
+// taskListCmd represents the task list command
+var taskListCmd = &cobra.Command{
+	Use:   "list [@context...] [+project...]",
+	Short: "List the open tasks in the notebook.",
+	Long: `List the open tasks in every note with their ids and where they are: the
+date for tasks in the daily logs, and the path of any other note. A task carried
+over to later logs is listed once. Notes matched by .nbignore are skipped.
+Subtasks are listed indented under their parent task.
+
+With --sort priority, tasks are ordered by their priority marker: a todo.txt
+letter such as "(A)" at the start of the task, a word such as "!high", or one
+of the emoji 🔺 ⏫ 🔼 🔽 ⏬. Tasks without a marker sort between medium and low.
+
+Tasks can be narrowed down to those with todo.txt style "@context" and
+"+project" tokens, for example "nb task list @errands" or
+"nb task list --project nb".`,
+	Args: cobra.ArbitraryArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		all, _ := cmd.Flags().GetBool("all")
+		logsOnly, _ := cmd.Flags().GetBool("logs")
+		sortBy, _ := cmd.Flags().GetString("sort")
+		projects, _ := cmd.Flags().GetStringSlice("project")
+
+		contexts := []string{}
+		for _, arg := range args {
+			switch {
+			case strings.HasPrefix(arg, "@"):
+				contexts = append(contexts, arg[1:])
+			case strings.HasPrefix(arg, "+"):
+				projects = append(projects, arg[1:])
+			default:
+				log.Fatalf("%q is not a @context or +project", arg)
+			}
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		dir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		var tasks []task.Task
+		if logsOnly {
+			tasks, err = task.FromLogs(dir)
+		} else {
+			tasks, err = task.FromNotebook(root)
+		}
+		if err != nil {
+			log.Fatalf("could not read tasks: %v", err)
+		}
+
+		tasks = task.Filter(task.Latest(tasks), contexts, projects)
+		switch sortBy {
+		case "priority":
+			task.ByPriority(tasks)
+		case "file":
+		default:
+			log.Fatalf("unknown sort %q, expected file or priority", sortBy)
+		}
+
+		shown := []task.Task{}
+		for _, t := range tasks {
+			if !t.Done || all {
+				shown = append(shown, t)
+			}
+		}
+
+		printTasks(root, dir, task.Tree(shown), "")
+	},
+}
+
+// taskReportCmd represents the task report command
+var taskReportCmd = &cobra.Command{
+	Use:   "report",
+	Short: "Show how tasks are created and completed over time.",
+	Long: `Show the number of tasks created and completed in the daily logs each week,
+the average age of the open tasks, and the open tasks that keep being carried
+over from log to log. A task is created on the day of the first log it appears
+in.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		weeks, _ := cmd.Flags().GetInt("weeks")
+		carried, _ := cmd.Flags().GetInt("carried")
+		format, _ := cmd.Flags().GetString("format")
+
+		if weeks < 1 {
+			log.Fatalf("--weeks should be at least 1")
+		}
+
+		dir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		report, err := stats.Tasks(dir, date.StartOfDay(time.Now()), weeks, carried)
+		if err != nil {
+			log.Fatalf("could not read tasks: %v", err)
+		}
+
+		switch format {
+		case "json":
+			out, err := json.MarshalIndent(report, "", "  ")
+			if err != nil {
+				log.Fatalf("could not encode report: %v", err)
+			}
+			fmt.Println(string(out))
+		case "text":
+			printTaskReport(report, carried)
+		default:
+			log.Fatalf("unknown format %q, expected text or json", format)
+		}
+	},
+}
+
+// taskExportCmd represents the task export command
+var taskExportCmd = &cobra.Command{
+	Use:   "export",
+	Short: "Export every task as a spreadsheet.",
+	Long: `Export the tasks in the notebook as a CSV file with one row per task: its id,
+the date of its log, the file and line it is on, the section it is under, its
+status, priority, due and completion dates, and its text. A task carried over
+to later logs is exported once, as it is in the latest log. Tasks in private
+notes are left out.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		format, _ := cmd.Flags().GetString("format")
+		output, _ := cmd.Flags().GetString("output")
+		logsOnly, _ := cmd.Flags().GetBool("logs")
+
+		if format != "csv" {
+			log.Fatalf("unknown format %q, expected csv", format)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		dir, err := noteLog.Dir()
+		if err != nil {
+			log.Fatalf("could not get log directory: %v", err)
+		}
+
+		var tasks []task.Task
+		if logsOnly {
+			tasks, err = task.FromLogs(dir)
+		} else {
+			tasks, err = task.FromNotebook(root)
+		}
+		if err != nil {
+			log.Fatalf("could not read tasks: %v", err)
+		}
+
+		rows := [][]string{{"id", "date", "file", "line", "section", "status", "priority", "due", "completed", "text"}}
+		private := privateFiles{}
+		for _, t := range task.Latest(tasks) {
+			if private.has(t.Path) {
+				continue
+			}
+
+			day := ""
+			if d, ok := logpath.Date(dir, t.Path, time.Local); ok {
+				day = d.Format(note.DateFormat)
+			}
+
+			due := ""
+			if d, ok := t.Due(); ok {
+				due = d.Format(note.DateFormat)
+			}
+
+			completed := ""
+			if d, ok := t.Completed(); ok {
+				completed = d.Format(note.DateFormat)
+			}
+
+			rows = append(rows, []string{
+				t.ID(),
+				day,
+				relativeTo(root, t.Path),
+				strconv.Itoa(t.Line + 1),
+				t.Section,
+				t.Status,
+				task.PriorityName(t.Priority),
+				due,
+				completed,
+				t.Text,
+			})
+		}
+
+		if err := writeCSV(output, rows); err != nil {
+			log.Fatalf("could not write tasks: %v", err)
+		}
+	},
+}
+
+func printTaskReport(r *stats.TaskReport, carried int) {
+	fmt.Println("Week        Created  Completed")
+	for _, w := range r.Weeks {
+		fmt.Printf("%s  %7d  %9d\n", w.Week, w.Created, w.Completed)
+	}
+
+	fmt.Printf("\nOpen tasks:       %d\n", r.Open)
+	fmt.Printf("Average age:      %.1f days\n", r.AverageOpenAge)
+
+	if len(r.Carried) == 0 {
+		return
+	}
+
+	fmt.Printf("\nCarried over more than %d times\n", carried)
+	for _, c := range r.Carried {
+		fmt.Printf("  %s  %3dx  %s\n", ui.Dim(c.ID), c.Times, c.Text)
+	}
+}
+
+// printTasks lists the tasks with their subtasks indented under them.
+func printTasks(root, logDir string, nodes []*task.Node, indent string) {
+	for _, n := range nodes {
+		mark := "[ ]"
+		if n.Done {
+			mark = "[x]"
+		}
+
+		fmt.Printf("%s  %s  %s%s %s\n", ui.Dim(n.ID()), ui.Accent(location(root, logDir, n.Path)), indent, mark, n.Text)
+		printTasks(root, logDir, n.Children, indent+"  ")
+	}
+}
+
+// location is the date of a log, or the path of any other note from the root.
+func location(root, logDir, path string) string {
+	if d, ok := logpath.Date(logDir, path, time.Local); ok {
+		return ui.Date(d)
+	}
+
+	if rel, err := filepath.Rel(root, path); err == nil {
+		return filepath.ToSlash(rel)
+	}
+
+	return path
+}
+
+// confirmNotDuplicate warns about open tasks similar to the text and reports
+// whether the new task should still be added.
+func confirmNotDuplicate(text string) bool {
+	dir, err := noteLog.Dir()
+	if err != nil {
+		log.Fatalf("could not get log directory: %v", err)
+	}
+
+	tasks, err := task.FromLogs(dir)
+	if err != nil {
+		log.Fatalf("could not read tasks: %v", err)
+	}
+
+	duplicates := task.Duplicates(text, task.Open(tasks))
+	if len(duplicates) == 0 {
+		return true
+	}
+
+	fmt.Println("Similar open tasks already exist:")
+	for _, t := range duplicates {
+		fmt.Printf("  - [ ] %s (%s:%d)\n", t.Text, t.Path, t.Line+1)
+	}
+
+	answer, err := prompt.Ask("Add it anyway? [y/N] ")
+	if err != nil {
+		return false
+	}
+
+	return answer == "y" || answer == "yes"
+}
+
+func init() {
+	rootCmd.AddCommand(taskCmd)
+	taskCmd.AddCommand(taskAddCmd)
+	taskCmd.AddCommand(taskDoneCmd)
+	taskCmd.AddCommand(taskDeferCmd)
+	taskCmd.AddCommand(taskListCmd)
+	taskCmd.AddCommand(taskReportCmd)
+	taskCmd.AddCommand(taskExportCmd)
+	taskAddCmd.Flags().BoolP("force", "f", false, "Add the task without checking for duplicates")
+	taskListCmd.Flags().Bool("all", false, "Include completed tasks")
+	taskListCmd.Flags().Bool("logs", false, "Only list tasks in the daily logs")
+	taskListCmd.Flags().String("sort", "file", "Order of the tasks, file or priority")
+	taskListCmd.Flags().StringSlice("project", []string{}, "Only list tasks with this +project")
+	taskReportCmd.Flags().Int("weeks", 4, "Number of weeks to report on")
+	taskReportCmd.Flags().Int("carried", 3, "List open tasks carried over more than this many times")
+	taskReportCmd.Flags().String("format", "text", "Output format, text or json")
+	taskExportCmd.Flags().String("format", "csv", "Output format, csv")
+	taskExportCmd.Flags().StringP("output", "o", "", "File to write to instead of stdout")
+	taskExportCmd.Flags().Bool("logs", false, "Only export tasks in the daily logs")
+}
@@ -0,0 +1,94 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/prompt"
+	"github.com/t-eckert/nb/recurring"
+	"github.com/t-eckert/nb/remind"
+)
+
+// todayCmd represents the today command
+var todayCmd = &cobra.Command{
+	Use:   "today",
+	Short: "Show what needs attention today.",
+	Long: `Show what needs attention today.
+
+Upcoming birthdays from the People pages and dates listed under
+"recurring_dates" in any note are shown first, starting "recurring.lead_days"
+days ahead (7 by default). Then notes with a "remind: YYYY-MM-DD" frontmatter
+key are listed once that date arrives. Each reminder can be cleared,
+rescheduled, or kept.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		events, err := recurring.Upcoming(root, time.Now(), config.RecurringLeadDays())
+		if err != nil {
+			log.Fatalf("could not check recurring dates: %v", err)
+		}
+
+		for _, e := range events {
+			fmt.Println(e)
+		}
+
+		due, err := remind.Due(root, time.Now())
+		if err != nil {
+			log.Fatalf("could not check reminders: %v", err)
+		}
+
+		if len(due) == 0 && len(events) == 0 {
+			fmt.Println("Nothing to revisit today.")
+			return
+		}
+
+		for _, n := range due {
+			fmt.Printf("You asked to revisit '%s' today\n", n.Title())
+
+			if err := resolveReminder(n); err != nil {
+				log.Fatalf("could not update reminder for %s: %v", n.Path, err)
+			}
+		}
+	},
+}
+
+func resolveReminder(n *note.Note) error {
+	for {
+		answer, err := prompt.Ask("  [c]lear, [r]eschedule, or [k]eep? ")
+		if err != nil {
+			return err
+		}
+
+		switch answer {
+		case "c", "clear":
+			return remind.Clear(n)
+		case "r", "reschedule":
+			answer, err := prompt.Ask("  New date (YYYY-MM-DD): ")
+			if err != nil {
+				return err
+			}
+
+			date, err := time.ParseInLocation(note.DateFormat, answer, time.Local)
+			if err != nil {
+				fmt.Printf("  %q is not a date\n", answer)
+				continue
+			}
+
+			return remind.Reschedule(n, date)
+		case "", "k", "keep":
+			return nil
+		}
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(todayCmd)
+}
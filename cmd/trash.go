@@ -0,0 +1,107 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	"github.com/t-eckert/nb/trash"
+)
+
+// trashCmd represents the trash command
+var trashCmd = &cobra.Command{
+	Use:   "trash",
+	Short: "List, restore, and empty deleted notes.",
+	Long:  ``,
+}
+
+// trashListCmd represents the trash list command
+var trashListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List the notes in the trash.",
+	Long:  ``,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		items, err := trash.List(root)
+		if err != nil {
+			log.Fatalf("could not read the trash: %v", err)
+		}
+
+		if len(items) == 0 {
+			fmt.Println("The trash is empty.")
+			return
+		}
+
+		for _, item := range items {
+			fmt.Printf("%s  %s  %s\n", item.ID, item.DeletedAt.Format("2006-01-02 15:04"), item.Path)
+		}
+	},
+}
+
+// trashRestoreCmd represents the trash restore command
+var trashRestoreCmd = &cobra.Command{
+	Use:   "restore <id>",
+	Short: "Move a note from the trash back to where it was.",
+	Long:  ``,
+	Args:  cobra.ExactArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		item, err := trash.Restore(root, args[0])
+		if err != nil {
+			log.Fatalf("could not restore %s: %v", args[0], err)
+		}
+
+		fmt.Printf("Restored %s.\n", item.Path)
+	},
+}
+
+// trashEmptyCmd represents the trash empty command
+var trashEmptyCmd = &cobra.Command{
+	Use:   "empty",
+	Short: "Permanently delete notes from the trash.",
+	Long:  `Permanently delete the notes that have been in the trash longer than --older-than.`,
+	Args:  cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		value, _ := cmd.Flags().GetString("older-than")
+
+		age, err := date.Duration(value)
+		if err != nil {
+			log.Fatalf("could not parse --older-than: %v", err)
+		}
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		removed, err := trash.Empty(root, time.Now().Add(-age))
+		if err != nil {
+			log.Fatalf("could not empty the trash: %v", err)
+		}
+
+		fmt.Printf("Deleted %d notes.\n", removed)
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(trashCmd)
+	trashCmd.AddCommand(trashListCmd)
+	trashCmd.AddCommand(trashRestoreCmd)
+	trashCmd.AddCommand(trashEmptyCmd)
+	trashEmptyCmd.Flags().String("older-than", "30d", "Only delete notes trashed longer ago than this, e.g. 30d or 2w")
+}
@@ -0,0 +1,108 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"path/filepath"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+)
+
+// trustCmd represents the trust command
+var trustCmd = &cobra.Command{
+	Use:   "trust",
+	Short: "Let the notebook's .nb.toml set hooks and other commands.",
+	Long: `Trust the .nb.toml at the root of the notebook, as it is now, so that it can
+set any setting, including hooks, the editor, integrations, and remotes, which
+run commands or send credentials elsewhere. Without trust, a notebook config
+file may only set conventions such as log.*, fmt.*, and queries, so cloning
+someone else's notebook cannot run code.
+
+The trust is for the content of the file: when it changes, nb asks again.
+Trusted files are listed in the nb folder of the user's config directory.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path := localConfig()
+
+		if err := config.Trust(path); err != nil {
+			log.Fatalf("could not trust %s: %v", path, err)
+		}
+
+		fmt.Println("Trusted", path)
+	},
+}
+
+// trustListCmd represents the trust list command
+var trustListCmd = &cobra.Command{
+	Use:   "list",
+	Short: "List the trusted notebook config files.",
+	Long: `List the notebook config files that have been trusted with nb trust. Files
+that have changed since they were trusted are marked, as they are no longer
+trusted until nb trust is run again.`,
+	Args: cobra.NoArgs,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		trusted, err := config.TrustedFiles()
+		if err != nil {
+			log.Fatalf("could not read the trusted files: %v", err)
+		}
+
+		if len(trusted) == 0 {
+			fmt.Println("No notebook config files are trusted.")
+			return
+		}
+
+		for _, t := range trusted {
+			if config.IsTrusted(t.Path) {
+				fmt.Println(t.Path)
+			} else {
+				fmt.Println(t.Path, "(changed since it was trusted)")
+			}
+		}
+	},
+}
+
+// trustRevokeCmd represents the trust revoke command
+var trustRevokeCmd = &cobra.Command{
+	Use:   "revoke [path]",
+	Short: "Stop trusting a notebook config file.",
+	Long: `Stop trusting a notebook config file, by default the .nb.toml of the
+notebook, so that it can only set conventions again.`,
+	Args: cobra.MaximumNArgs(1),
+
+	Run: func(cmd *cobra.Command, args []string) {
+		path := localConfig()
+		if len(args) == 1 {
+			path = args[0]
+		}
+
+		revoked, err := config.Revoke(path)
+		if err != nil {
+			log.Fatalf("could not revoke trust in %s: %v", path, err)
+		}
+
+		if !revoked {
+			fmt.Println(path, "was not trusted")
+			return
+		}
+		fmt.Println("No longer trusting", path)
+	},
+}
+
+// localConfig is the path of the notebook config file.
+func localConfig() string {
+	root, err := config.GetRootDir()
+	if err != nil {
+		log.Fatalf("could not get root directory: %v", err)
+	}
+
+	return filepath.Join(root, config.LocalFile)
+}
+
+func init() {
+	rootCmd.AddCommand(trustCmd)
+	trustCmd.AddCommand(trustListCmd)
+	trustCmd.AddCommand(trustRevokeCmd)
+}
@@ -0,0 +1,54 @@
+package cmd
+
+import (
+	"fmt"
+	"log"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/editor"
+	"github.com/t-eckert/nb/zk"
+)
+
+// zkCmd represents the zk command
+var zkCmd = &cobra.Command{
+	Use:   "zk",
+	Short: "Keep notes with Zettelkasten ids.",
+	Long:  ``,
+}
+
+// zkNewCmd represents the zk new command
+var zkNewCmd = &cobra.Command{
+	Use:   "new [title]",
+	Short: "Create a note with a timestamp id.",
+	Long: `Create a note with an id such as 202503041231, the minute it was made, in its
+frontmatter, and open it. Wikilinks can point at the note by its id, as in
+[[202503041231]], and keep working when the note is renamed or moved.`,
+
+	Run: func(cmd *cobra.Command, args []string) {
+		folder, _ := cmd.Flags().GetString("folder")
+
+		root, err := config.GetRootDir()
+		if err != nil {
+			log.Fatalf("could not get root directory: %v", err)
+		}
+
+		path, err := zk.New(root, folder, strings.Join(args, " "), time.Now())
+		if err != nil {
+			log.Fatalf("could not create note: %v", err)
+		}
+		fmt.Println(path)
+
+		if err := editor.Open(path); err != nil {
+			log.Fatalf("could not open %s: %v", path, err)
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(zkCmd)
+	zkCmd.AddCommand(zkNewCmd)
+	zkNewCmd.Flags().String("folder", "", "Folder to create the note in")
+}
@@ -1,8 +1,16 @@
 package config
 
 import (
-	"fmt"
+	"bytes"
+	"crypto/sha1"
+	"encoding/hex"
 	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"time"
+
+	"github.com/spf13/viper"
 )
 
 const (
@@ -10,11 +18,477 @@ const (
 	Port   = 3000
 )
 
+// GetRootDir is the notebook folder, set with `notebook_path`. It defaults to
+// the Notebook folder in the home directory, which is %USERPROFILE% on
+// Windows. A leading ~ stands for the home directory.
 func GetRootDir() (string, error) {
 	home, err := os.UserHomeDir()
 	if err != nil {
-		return "", nil
+		return "", err
+	}
+
+	if path := viper.GetString("notebook_path"); path != "" {
+		if path == "~" || strings.HasPrefix(path, "~/") || strings.HasPrefix(path, `~\`) {
+			path = filepath.Join(home, path[1:])
+		}
+		return filepath.Abs(path)
+	}
+
+	return filepath.Join(home, "Notebook"), nil
+}
+
+// DataDir is where nb keeps state derived from the notebook at root, such as
+// the history of its notes, so that it stays out of the notebook and can be
+// deleted safely. It is set with `data_dir`, and otherwise is a folder per
+// notebook in %LOCALAPPDATA%\nb on Windows, ~/Library/Application Support/nb
+// on macOS, and $XDG_DATA_HOME/nb or ~/.local/share/nb elsewhere.
+func DataDir(root string) string {
+	base := viper.GetString("data_dir")
+	if base == "" {
+		base = defaultDataDir()
+	}
+	if base == "" {
+		return filepath.Join(root, ".nb")
+	}
+
+	abs, err := filepath.Abs(root)
+	if err != nil {
+		abs = root
+	}
+	sum := sha1.Sum([]byte(abs))
+
+	return filepath.Join(base, "notebooks", filepath.Base(abs)+"-"+hex.EncodeToString(sum[:])[:8])
+}
+
+func defaultDataDir() string {
+	switch runtime.GOOS {
+	case "windows":
+		if dir := os.Getenv("LOCALAPPDATA"); dir != "" {
+			return filepath.Join(dir, "nb")
+		}
+		return ""
+	case "darwin":
+		if home, err := os.UserHomeDir(); err == nil {
+			return filepath.Join(home, "Library", "Application Support", "nb")
+		}
+		return ""
+	}
+
+	if dir := os.Getenv("XDG_DATA_HOME"); dir != "" {
+		return filepath.Join(dir, "nb")
+	}
+	if home, err := os.UserHomeDir(); err == nil {
+		return filepath.Join(home, ".local", "share", "nb")
+	}
+
+	return ""
+}
+
+// EditorCommand is the editor notes are opened in, set with `editor` or the
+// VISUAL or EDITOR environment variables. It defaults to Editor, or Notepad
+// on Windows.
+func EditorCommand() string {
+	if viper.IsSet("editor") {
+		return viper.GetString("editor")
+	}
+
+	for _, env := range []string{"VISUAL", "EDITOR"} {
+		if editor := os.Getenv(env); editor != "" {
+			return editor
+		}
+	}
+
+	if runtime.GOOS == "windows" {
+		return "notepad"
+	}
+
+	return Editor
+}
+
+// LocalFile is the name of the config file a notebook can keep at its root.
+// Its settings override the ones in the home directory.
+const LocalFile = ".nb.toml"
+
+// localKeys are the settings a notebook's LocalFile may set, by key or by the
+// start of their keys. Settings that run commands, name files or hosts, or
+// hold credentials are left out, so that a notebook cloned from someone else
+// cannot run code or send tokens elsewhere, unless the user trusts the file
+// with `nb trust`.
+var localKeys = []string{
+	"bookmarks.",
+	"fmt.",
+	"habits",
+	"lint.",
+	"log.auto_rollover",
+	"log.folder",
+	"log.on_this_day",
+	"log.path_format",
+	"log.scheduled_elsewhere",
+	"log.template",
+	"notes.",
+	"queries.",
+	"recurring.",
+	"template.vars.",
+	"ui.date_format",
+	"ui.week_start",
+}
+
+// MergeLocal reads the LocalFile of the notebook, if it has one, over the
+// settings read so far, and returns its path along with the keys it ignored
+// because a notebook may not set them. A trusted file may set any key.
+func MergeLocal() (string, []string, error) {
+	root, err := GetRootDir()
+	if err != nil {
+		return "", nil, err
+	}
+
+	path := filepath.Join(root, LocalFile)
+	content, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return "", nil, nil
+	} else if err != nil {
+		return "", nil, err
+	}
+
+	local := viper.New()
+	local.SetConfigType("toml")
+	if err := local.ReadConfig(bytes.NewReader(content)); err != nil {
+		return "", nil, err
+	}
+	trusted := isTrusted(path, content)
+
+	allowed := viper.New()
+	ignored := []string{}
+	for _, key := range local.AllKeys() {
+		if !trusted && !isLocalKey(key) {
+			ignored = append(ignored, key)
+			continue
+		}
+		allowed.Set(key, local.Get(key))
+	}
+
+	return path, ignored, viper.MergeConfigMap(allowed.AllSettings())
+}
+
+func isLocalKey(key string) bool {
+	for _, k := range localKeys {
+		if key == k || strings.HasSuffix(k, ".") && strings.HasPrefix(key, k) {
+			return true
+		}
+	}
+
+	return false
+}
+
+// LogFolder is the name of the folder under the notebook root that holds the
+// daily logs, set with `log.folder`. With `log.path_format`, it is the part of
+// the format before the first placeholder.
+func LogFolder() string {
+	if format := viper.GetString("log.path_format"); format != "" {
+		parts := strings.Split(filepath.ToSlash(format), "/")
+
+		folder := []string{}
+		for _, part := range parts[:len(parts)-1] {
+			if strings.Contains(part, "%") {
+				break
+			}
+			folder = append(folder, part)
+		}
+
+		return filepath.Join(folder...)
+	}
+
+	if viper.IsSet("log.folder") {
+		return viper.GetString("log.folder")
+	}
+
+	return "Log"
+}
+
+// LogPathFormat is where daily logs are kept under the notebook root, set
+// with `log.path_format`, as in "Log/%Y/%m/%Y-%m-%d.md". It defaults to a
+// YYYY-MM-DD.md file per day in LogFolder.
+func LogPathFormat() string {
+	if format := viper.GetString("log.path_format"); format != "" {
+		return filepath.ToSlash(format)
+	}
+
+	return filepath.ToSlash(filepath.Join(LogFolder(), "%Y-%m-%d.md"))
+}
+
+// LogTemplate is the path of a note to use as the template of new daily logs
+// instead of the built in one, set with `log.template`. Relative paths are
+// from the notebook root.
+func LogTemplate() string {
+	return viper.GetString("log.template")
+}
+
+// RecurringLeadDays is how many days ahead birthdays and anniversaries are
+// shown, set with `recurring.lead_days`.
+func RecurringLeadDays() int {
+	if viper.IsSet("recurring.lead_days") {
+		return viper.GetInt("recurring.lead_days")
+	}
+
+	return 7
+}
+
+// LintDisabled lists the lint rules turned off with `lint.disable`.
+func LintDisabled() []string {
+	return viper.GetStringSlice("lint.disable")
+}
+
+// FormatWidth is the width `nb fmt` wraps lines at, set with `fmt.width`. Zero
+// leaves long lines alone.
+func FormatWidth() int {
+	return viper.GetInt("fmt.width")
+}
+
+// Habits are checked off in the Habits section of each daily log, set with
+// `habits`.
+func Habits() []string {
+	return viper.GetStringSlice("habits")
+}
+
+// BookmarksPerMonth files bookmarks in a note per month rather than a single
+// Bookmarks.md, set with `bookmarks.per_month`.
+func BookmarksPerMonth() bool {
+	return viper.GetBool("bookmarks.per_month")
+}
+
+// ServePort is the port `nb serve` listens on, set with `serve.port`.
+func ServePort() int {
+	if viper.IsSet("serve.port") {
+		return viper.GetInt("serve.port")
+	}
+
+	return Port
+}
+
+// ServeTheme is the built in theme of `nb serve`, set with `serve.theme` to
+// light, dark, sepia, or auto, which follows the browser.
+func ServeTheme() string {
+	if viper.IsSet("serve.theme") {
+		return viper.GetString("serve.theme")
 	}
 
-	return fmt.Sprintf("%s/Notebook", home), nil
+	return "auto"
+}
+
+// ServeCSS is the path of a CSS file `nb serve` adds after its theme, set with
+// `serve.css`. Relative paths are from the notebook root.
+func ServeCSS() string {
+	return viper.GetString("serve.css")
+}
+
+// ServeMath typesets the TeX between $ and $$ in notes with KaTeX when they are
+// served or exported as web pages, set with `serve.math`.
+func ServeMath() bool {
+	return viper.GetBool("serve.math")
+}
+
+// Pandoc is the pandoc binary `nb export --via-pandoc` runs, set with
+// `export.pandoc`. It defaults to pandoc on the PATH.
+func Pandoc() string {
+	if viper.IsSet("export.pandoc") {
+		return viper.GetString("export.pandoc")
+	}
+
+	return "pandoc"
+}
+
+// ServeRelated lists the notes related to each note served by `nb serve`
+// beside it, set with `serve.related`.
+func ServeRelated() bool {
+	return viper.GetBool("serve.related")
+}
+
+// FeedSize is how many of the latest logs `nb serve` puts in its feed, set
+// with `serve.feed_size`.
+func FeedSize() int {
+	if viper.IsSet("serve.feed_size") {
+		return viper.GetInt("serve.feed_size")
+	}
+
+	return 10
+}
+
+// Queries are saved queries by name, set with `queries`.
+func Queries() map[string]string {
+	return viper.GetStringMapString("queries")
+}
+
+// TemplateVars are placeholders to fill in templates by name, set with
+// `template.vars`.
+func TemplateVars() map[string]string {
+	return viper.GetStringMapString("template.vars")
+}
+
+// LogOnThisDay adds links to the logs from the same date in earlier years to
+// each new log, set with `log.on_this_day`.
+func LogOnThisDay() bool {
+	return viper.GetBool("log.on_this_day")
+}
+
+// Offline keeps nb off the network, set with `offline`, the NB_OFFLINE
+// environment variable, or --offline.
+func Offline() bool {
+	return viper.GetBool("offline")
+}
+
+// NetworkTimeout is how long a request to another machine can take before nb
+// gives up, set with `network.timeout` as a duration such as "30s".
+func NetworkTimeout() time.Duration {
+	return viper.GetDuration("network.timeout")
+}
+
+// NetworkConnectTimeout is how long connecting to another machine can take
+// before nb gives up, set with `network.connect_timeout`.
+func NetworkConnectTimeout() time.Duration {
+	return viper.GetDuration("network.connect_timeout")
+}
+
+// NetworkProxy is the URL of the proxy to send requests through, set with
+// `network.proxy`. Without it the proxy environment variables are used.
+func NetworkProxy() string {
+	return viper.GetString("network.proxy")
+}
+
+// NetworkCABundle is the path of a PEM file of certificate authorities to
+// trust as well as the system's, set with `network.ca_bundle`.
+func NetworkCABundle() string {
+	return viper.GetString("network.ca_bundle")
+}
+
+// GitHubToken is the personal access token used by the GitHub integration, set
+// with `integrations.github.token` or the GITHUB_TOKEN environment variable.
+func GitHubToken() string {
+	if viper.IsSet("integrations.github.token") {
+		return viper.GetString("integrations.github.token")
+	}
+
+	return os.Getenv("GITHUB_TOKEN")
+}
+
+// GitHubAPI is the base URL of the GitHub API, set with
+// `integrations.github.api_url` for GitHub Enterprise.
+func GitHubAPI() string {
+	if viper.IsSet("integrations.github.api_url") {
+		return viper.GetString("integrations.github.api_url")
+	}
+
+	return "https://api.github.com"
+}
+
+// CalendarURL is the iCalendar address that the Schedule section of new logs
+// is filled from, set with `integrations.calendar.url`.
+func CalendarURL() string {
+	return viper.GetString("integrations.calendar.url")
+}
+
+// CalendarCredentials are the username and password for the calendar, set
+// with `integrations.calendar.username` and `integrations.calendar.password`.
+func CalendarCredentials() (string, string) {
+	return viper.GetString("integrations.calendar.username"), viper.GetString("integrations.calendar.password")
+}
+
+// Integration is a setting of an integration, set with
+// `integrations.<name>.<key>`.
+func Integration(name, key string) string {
+	return viper.GetString("integrations." + name + "." + key)
+}
+
+// UIColor is when output is colored, set with `ui.color` to "on", "off", or
+// "auto", the default, which colors output to a terminal.
+func UIColor() string {
+	if viper.IsSet("ui.color") {
+		return viper.GetString("ui.color")
+	}
+
+	return "auto"
+}
+
+// UIAccent is the color of headings and dates, set with `ui.accent`.
+func UIAccent() string {
+	if viper.IsSet("ui.accent") {
+		return viper.GetString("ui.accent")
+	}
+
+	return "cyan"
+}
+
+// UIDateFormat is the Go layout dates are printed with, set with
+// `ui.date_format`.
+func UIDateFormat() string {
+	if viper.IsSet("ui.date_format") {
+		return viper.GetString("ui.date_format")
+	}
+
+	return "2006-01-02"
+}
+
+// Timezone is where "today" is for nb, set with `timezone` to a name such as
+// "Europe/Berlin" or "UTC". It defaults to the timezone of the system.
+func Timezone() (*time.Location, error) {
+	name := viper.GetString("timezone")
+	if name == "" {
+		return time.Local, nil
+	}
+
+	return time.LoadLocation(name)
+}
+
+// Locale is the language month and weekday names are written in, set with
+// `ui.locale` to a locale such as "de" or "fr_FR". It defaults to English.
+func Locale() string {
+	return viper.GetString("ui.locale")
+}
+
+// WeekStart is the first day of the week, set with `ui.week_start`. It
+// defaults to Monday.
+func WeekStart() time.Weekday {
+	name := strings.ToLower(viper.GetString("ui.week_start"))
+	for d := time.Sunday; d <= time.Saturday; d++ {
+		if strings.ToLower(d.String()) == name {
+			return d
+		}
+	}
+
+	return time.Monday
+}
+
+// LogAutoRollover carries the open tasks of the previous log over to today's
+// log when it is first made, set with `log.auto_rollover`.
+func LogAutoRollover() bool {
+	return viper.GetBool("log.auto_rollover")
+}
+
+// Hook is the shell command to run at event, set with `hooks.<event>`.
+func Hook(event string) string {
+	return viper.GetString("hooks." + event)
+}
+
+// PrivateFolders are the folders, from the notebook root, whose notes are kept
+// out of what nb shares, set with `notes.private`.
+func PrivateFolders() []string {
+	return viper.GetStringSlice("notes.private")
+}
+
+// LogScheduledElsewhere adds the tasks due in notes other than the logs to each
+// new log, set with `log.scheduled_elsewhere`.
+func LogScheduledElsewhere() bool {
+	return viper.GetBool("log.scheduled_elsewhere")
+}
+
+// NoteMetadata lists the frontmatter fields nb keeps up to date whenever it
+// writes a note, out of `id`, `created`, and `modified`, set with
+// `notes.metadata`.
+func NoteMetadata() []string {
+	return viper.GetStringSlice("notes.metadata")
+}
+
+// Remote is a setting of the remote store that backups are uploaded to, set
+// with `remote.<key>`, such as `remote.type` or `remote.s3.bucket`.
+func Remote(key string) string {
+	return viper.GetString("remote." + key)
 }
@@ -0,0 +1,62 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/spf13/viper"
+)
+
+func TestMergeLocal(t *testing.T) {
+	viper.Reset()
+	defer viper.Reset()
+
+	root := t.TempDir()
+	local := "[log]\nfolder = \"Journal\"\n\n[hooks]\npost_edit = \"curl evil.example\"\n\n[integrations.github]\napi_url = \"https://evil.example\"\n"
+	if err := os.WriteFile(filepath.Join(root, LocalFile), []byte(local), 0644); err != nil {
+		t.Fatal(err)
+	}
+	viper.Set("notebook_path", root)
+
+	_, ignored, err := MergeLocal()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if LogFolder() != "Journal" {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", local, "Journal", LogFolder())
+	}
+	if Hook("post_edit") != "" || len(ignored) != 2 {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %v\n", local, "hooks and integrations ignored", ignored)
+	}
+}
+
+func TestTrust(t *testing.T) {
+	viper.Reset()
+	defer viper.Reset()
+	t.Setenv("XDG_CONFIG_HOME", t.TempDir())
+	t.Setenv("HOME", t.TempDir())
+
+	root := t.TempDir()
+	path := filepath.Join(root, LocalFile)
+	local := "[hooks]\npost_edit = \"make\"\n"
+	if err := os.WriteFile(path, []byte(local), 0644); err != nil {
+		t.Fatal(err)
+	}
+	viper.Set("notebook_path", root)
+
+	if err := Trust(path); err != nil {
+		t.Fatal(err)
+	}
+	if _, ignored, err := MergeLocal(); err != nil || len(ignored) != 0 || Hook("post_edit") != "make" {
+		t.Fatalf("given: %s, trusted\nexpected: %s\nactual: %v %v\n", local, "hooks read", ignored, err)
+	}
+
+	if err := os.WriteFile(path, []byte(local+"pre_edit = \"rm -rf ~\"\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if IsTrusted(path) {
+		t.Fatalf("given: %s, changed after it was trusted\nexpected: not trusted\nactual: trusted\n", local)
+	}
+}
@@ -0,0 +1,166 @@
+package config
+
+import (
+	"bufio"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// Trusted is a notebook config file the user has allowed to set any setting,
+// including hooks and other settings that run commands. The trust only holds
+// while the file has the content it had when it was trusted.
+type Trusted struct {
+	Path string
+	Hash string
+}
+
+// TrustFile is where the trusted notebook config files are listed. It is kept
+// with the user's own config, out of reach of any notebook.
+func TrustFile() (string, error) {
+	dir, err := os.UserConfigDir()
+	if err != nil {
+		return "", err
+	}
+
+	return filepath.Join(dir, "nb", "trusted"), nil
+}
+
+// TrustedFiles lists the trusted notebook config files, by path.
+func TrustedFiles() ([]Trusted, error) {
+	file, err := TrustFile()
+	if err != nil {
+		return nil, err
+	}
+
+	f, err := os.Open(file)
+	if os.IsNotExist(err) {
+		return []Trusted{}, nil
+	} else if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+
+	trusted := []Trusted{}
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		fields := strings.SplitN(scanner.Text(), "\t", 2)
+		if len(fields) == 2 {
+			trusted = append(trusted, Trusted{Path: fields[1], Hash: fields[0]})
+		}
+	}
+
+	return trusted, scanner.Err()
+}
+
+// Trust allows the notebook config file at path, as it is now, to set any
+// setting.
+func Trust(path string) error {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return err
+	}
+
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return err
+	}
+
+	trusted, err := TrustedFiles()
+	if err != nil {
+		return err
+	}
+
+	kept := []Trusted{{Path: abs, Hash: hash(content)}}
+	for _, t := range trusted {
+		if t.Path != abs {
+			kept = append(kept, t)
+		}
+	}
+
+	return writeTrusted(kept)
+}
+
+// Revoke stops trusting the notebook config file at path. It returns false
+// when the file was not trusted.
+func Revoke(path string) (bool, error) {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return false, err
+	}
+
+	trusted, err := TrustedFiles()
+	if err != nil {
+		return false, err
+	}
+
+	kept := []Trusted{}
+	for _, t := range trusted {
+		if t.Path != abs {
+			kept = append(kept, t)
+		}
+	}
+	if len(kept) == len(trusted) {
+		return false, nil
+	}
+
+	return true, writeTrusted(kept)
+}
+
+// IsTrusted is whether the notebook config file at path is trusted as it is
+// now.
+func IsTrusted(path string) bool {
+	content, err := os.ReadFile(path)
+	return err == nil && isTrusted(path, content)
+}
+
+// isTrusted is whether the notebook config file at path is trusted with
+// exactly this content.
+func isTrusted(path string, content []byte) bool {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return false
+	}
+
+	trusted, err := TrustedFiles()
+	if err != nil {
+		return false
+	}
+
+	for _, t := range trusted {
+		if t.Path == abs && t.Hash == hash(content) {
+			return true
+		}
+	}
+
+	return false
+}
+
+func writeTrusted(trusted []Trusted) error {
+	file, err := TrustFile()
+	if err != nil {
+		return err
+	}
+
+	if err := os.MkdirAll(filepath.Dir(file), 0700); err != nil {
+		return err
+	}
+
+	sort.Slice(trusted, func(i, j int) bool { return trusted[i].Path < trusted[j].Path })
+
+	var b strings.Builder
+	for _, t := range trusted {
+		fmt.Fprintf(&b, "%s\t%s\n", t.Hash, t.Path)
+	}
+
+	return os.WriteFile(file, []byte(b.String()), 0600)
+}
+
+func hash(content []byte) string {
+	sum := sha256.Sum256(content)
+	return hex.EncodeToString(sum[:])
+}
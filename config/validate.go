@@ -0,0 +1,357 @@
+package config
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/spf13/cast"
+	"github.com/spf13/viper"
+)
+
+// Problem is a setting that nb does not know or cannot use.
+type Problem struct {
+	Key     string
+	Message string
+}
+
+func (p Problem) String() string {
+	return p.Key + ": " + p.Message
+}
+
+type check func(value interface{}) error
+
+// settings are the keys nb reads and what their values should be. Keys ending
+// in ".*" take any key below them.
+var settings = map[string]check{
+	"bookmarks.per_month": boolean,
+	"data_dir":            text,
+	"editor":              text,
+	"export.pandoc":       text,
+	"fmt.width":           atLeast(0),
+	"habits":              list(nil),
+	"notebook_path":       text,
+	"offline":             boolean,
+	"timezone":            timezone,
+
+	"hooks.pre_log_create":   text,
+	"hooks.post_log_create":  text,
+	"hooks.post_note_create": text,
+	"hooks.pre_edit":         text,
+	"hooks.post_edit":        text,
+	"hooks.post_rollover":    text,
+	"hooks.pre_backup":       text,
+	"hooks.post_backup":      text,
+
+	"integrations.calendar.url":      text,
+	"integrations.calendar.username": text,
+	"integrations.calendar.password": text,
+	"integrations.github.token":      text,
+	"integrations.github.api_url":    text,
+	"integrations.jira.url":          text,
+	"integrations.jira.email":        text,
+	"integrations.jira.token":        text,
+	"integrations.jira.on_done":      oneOf("", "comment", "transition"),
+	"integrations.jira.transition":   text,
+	"integrations.linear.token":      text,
+	"integrations.linear.on_done":    oneOf("", "comment", "complete"),
+
+	"lint.disable":            list(nil),
+	"log.auto_rollover":       boolean,
+	"log.folder":              text,
+	"log.path_format":         pathFormat,
+	"log.template":            text,
+	"log.on_this_day":         boolean,
+	"log.scheduled_elsewhere": boolean,
+	"network.ca_bundle":       text,
+	"network.connect_timeout": duration,
+	"network.proxy":           text,
+	"network.timeout":         duration,
+	"notes.metadata":          list([]string{"id", "created", "modified"}),
+	"notes.private":           list(nil),
+	"queries.*":               text,
+	"recurring.lead_days":     atLeast(0),
+
+	"remote.type":                 oneOf("s3", "webdav"),
+	"remote.s3.bucket":            text,
+	"remote.s3.region":            text,
+	"remote.s3.prefix":            text,
+	"remote.s3.endpoint":          text,
+	"remote.s3.access_key_id":     text,
+	"remote.s3.secret_access_key": text,
+	"remote.webdav.url":           text,
+	"remote.webdav.username":      text,
+	"remote.webdav.password":      text,
+
+	"serve.css":       text,
+	"serve.feed_size": atLeast(1),
+	"serve.math":      boolean,
+	"serve.port":      between(1, 65535),
+	"serve.related":   boolean,
+	"serve.theme":     oneOf("auto", "light", "dark", "sepia"),
+	"template.vars.*": text,
+	"ui.accent":       oneOf("black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"),
+	"ui.color":        oneOf("auto", "on", "off"),
+	"ui.date_format":  text,
+	"ui.locale":       text,
+	"ui.week_start":   oneOf("monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"),
+}
+
+// Validate checks every setting in the config file, reporting unknown keys,
+// with the closest known key when there is one, and values nb cannot use.
+func Validate() []Problem {
+	problems := []Problem{}
+
+	values := map[string]interface{}{}
+	flatten("", viper.AllSettings(), values)
+
+	keys := []string{}
+	for key := range values {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	for _, key := range keys {
+		c, ok := lookup(key)
+		if !ok {
+			message := "unknown setting"
+			if suggestion := closest(key); suggestion != "" {
+				message += fmt.Sprintf(", did you mean %q?", suggestion)
+			}
+			problems = append(problems, Problem{key, message})
+			continue
+		}
+
+		if err := c(values[key]); err != nil {
+			problems = append(problems, Problem{key, err.Error()})
+		}
+	}
+
+	return problems
+}
+
+func flatten(prefix string, m map[string]interface{}, values map[string]interface{}) {
+	for key, value := range m {
+		key = prefix + strings.ToLower(key)
+
+		if _, ok := settings[key]; !ok {
+			if child, ok := value.(map[string]interface{}); ok {
+				flatten(key+".", child, values)
+				continue
+			}
+		}
+
+		values[key] = value
+	}
+}
+
+func lookup(key string) (check, bool) {
+	if c, ok := settings[key]; ok {
+		return c, true
+	}
+
+	for pattern, c := range settings {
+		if strings.HasSuffix(pattern, ".*") && strings.HasPrefix(key, strings.TrimSuffix(pattern, "*")) {
+			return c, true
+		}
+	}
+
+	return nil, false
+}
+
+// closest is the known key with the fewest edits from key, if it is close
+// enough to be a likely misspelling.
+func closest(key string) string {
+	known := []string{}
+	for k := range settings {
+		known = append(known, strings.TrimSuffix(k, ".*"))
+	}
+	sort.Strings(known)
+
+	best, bestDistance := "", len(key)/3+1
+	for _, k := range known {
+		if d := distance(key, k); d < bestDistance {
+			best, bestDistance = k, d
+		}
+	}
+
+	return best
+}
+
+// distance is the Levenshtein distance between a and b.
+func distance(a, b string) int {
+	previous := make([]int, len(b)+1)
+	for j := range previous {
+		previous[j] = j
+	}
+
+	for i := 1; i <= len(a); i++ {
+		current := make([]int, len(b)+1)
+		current[0] = i
+		for j := 1; j <= len(b); j++ {
+			cost := 1
+			if a[i-1] == b[j-1] {
+				cost = 0
+			}
+			current[j] = min(previous[j]+1, current[j-1]+1, previous[j-1]+cost)
+		}
+		previous = current
+	}
+
+	return previous[len(b)]
+}
+
+func min(values ...int) int {
+	m := values[0]
+	for _, v := range values[1:] {
+		if v < m {
+			m = v
+		}
+	}
+
+	return m
+}
+
+func text(value interface{}) error {
+	if _, ok := value.(string); !ok {
+		return fmt.Errorf("should be text, not %v", value)
+	}
+
+	return nil
+}
+
+func pathFormat(value interface{}) error {
+	format, ok := value.(string)
+	if !ok {
+		return fmt.Errorf("should be text, not %v", value)
+	}
+	if !strings.Contains(format, "%d") && !strings.Contains(format, "%j") {
+		return fmt.Errorf("should have the day in it, as in \"Log/%%Y/%%m/%%Y-%%m-%%d.md\"")
+	}
+
+	return nil
+}
+
+func timezone(value interface{}) error {
+	name, ok := value.(string)
+	if !ok {
+		return fmt.Errorf("should be text, not %v", value)
+	}
+	if _, err := time.LoadLocation(name); err != nil {
+		return fmt.Errorf("should be a timezone such as \"Europe/Berlin\", not %s", name)
+	}
+
+	return nil
+}
+
+func duration(value interface{}) error {
+	s, ok := value.(string)
+	if !ok {
+		return fmt.Errorf("should be a duration such as \"30s\", not %v", value)
+	}
+	if _, err := time.ParseDuration(s); err != nil {
+		return fmt.Errorf("should be a duration such as \"30s\", not %s", s)
+	}
+
+	return nil
+}
+
+func boolean(value interface{}) error {
+	if _, err := cast.ToBoolE(value); err != nil {
+		return fmt.Errorf("should be true or false, not %v", value)
+	}
+
+	return nil
+}
+
+func between(low, high int) check {
+	return func(value interface{}) error {
+		i, err := wholeNumber(value)
+		if err != nil {
+			return err
+		}
+		if i < low || i > high {
+			return fmt.Errorf("should be from %d to %d, not %d", low, high, i)
+		}
+
+		return nil
+	}
+}
+
+func atLeast(n int) check {
+	return func(value interface{}) error {
+		i, err := wholeNumber(value)
+		if err != nil {
+			return err
+		}
+		if i < n {
+			return fmt.Errorf("should be at least %d, not %d", n, i)
+		}
+
+		return nil
+	}
+}
+
+// wholeNumber reads an integer of any size, as TOML gives them, or one written
+// as text, as environment variables give them.
+func wholeNumber(value interface{}) (int, error) {
+	switch value.(type) {
+	case bool, float32, float64:
+		return 0, fmt.Errorf("should be a whole number, not %v", value)
+	}
+
+	i, err := cast.ToIntE(value)
+	if err != nil {
+		return 0, fmt.Errorf("should be a whole number, not %v", value)
+	}
+
+	return i, nil
+}
+
+func oneOf(allowed ...string) check {
+	return func(value interface{}) error {
+		s, ok := value.(string)
+		if ok {
+			for _, a := range allowed {
+				if strings.EqualFold(s, a) {
+					return nil
+				}
+			}
+		}
+
+		return fmt.Errorf("should be one of %s, not %v", strings.Join(quoted(allowed), ", "), value)
+	}
+}
+
+func list(allowed []string) check {
+	return func(value interface{}) error {
+		items, ok := value.([]interface{})
+		if !ok {
+			return fmt.Errorf("should be a list, not %v", value)
+		}
+
+		if allowed == nil {
+			return nil
+		}
+
+		for _, item := range items {
+			if err := oneOf(allowed...)(item); err != nil {
+				return fmt.Errorf("has %v, which should be one of %s", item, strings.Join(quoted(allowed), ", "))
+			}
+		}
+
+		return nil
+	}
+}
+
+func quoted(values []string) []string {
+	q := []string{}
+	for _, v := range values {
+		if v != "" {
+			q = append(q, fmt.Sprintf("%q", v))
+		}
+	}
+
+	return q
+}
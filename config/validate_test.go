@@ -0,0 +1,44 @@
+package config
+
+import (
+	"testing"
+
+	"github.com/spf13/viper"
+)
+
+func TestValidate(t *testing.T) {
+	viper.Reset()
+	defer viper.Reset()
+
+	viper.Set("habtis", []string{"Read"})
+	viper.Set("ui.color", "sometimes")
+	viper.Set("queries.reading", "from Books")
+	expected := []string{
+		`habtis: unknown setting, did you mean "habits"?`,
+		`ui.color: should be one of "auto", "on", "off", not sometimes`,
+	}
+
+	actual := Validate()
+
+	if len(actual) != len(expected) {
+		t.Fatalf("given: habtis, ui.color, and queries.reading\nexpected: %v\nactual: %v\n", expected, actual)
+	}
+	for i := range expected {
+		if expected[i] != actual[i].String() {
+			t.Fatalf("given: habtis, ui.color, and queries.reading\nexpected: %s\nactual: %s\n", expected[i], actual[i])
+		}
+	}
+}
+
+func TestValidateNumbers(t *testing.T) {
+	viper.Reset()
+	defer viper.Reset()
+
+	viper.Set("serve.port", int64(8080))
+	viper.Set("fmt.width", "80")
+	viper.Set("serve.math", "true")
+
+	if actual := Validate(); len(actual) != 0 {
+		t.Fatalf("given: serve.port int64(8080), fmt.width \"80\", serve.math \"true\"\nexpected: no problems\nactual: %v\n", actual)
+	}
+}
@@ -0,0 +1,226 @@
+package conflict
+
+import (
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/diff"
+	"github.com/t-eckert/nb/history"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+	"github.com/t-eckert/nb/trash"
+)
+
+// The names sync tools give the copy they keep when a file was changed in two
+// places at once.
+var patterns = []*regexp.Regexp{
+	// Syncthing: Note.sync-conflict-20250304-123456-ABCDEFG.md
+	regexp.MustCompile(`^(.+)\.sync-conflict-\d{8}-\d{6}-[A-Z0-9]+\.md$`),
+	// Dropbox and Nextcloud: Note (Sam's conflicted copy 2025-03-04).md
+	regexp.MustCompile(`^(.+) \([^)]*conflicted copy[^)]*\)\.md$`),
+	// Others: Note (conflict).md, Note (conflict 2).md
+	regexp.MustCompile(`^(.+) \(conflict[^)]*\)\.md$`),
+}
+
+// Conflict is a copy of a note left by a sync tool, and the note it is a copy
+// of.
+type Conflict struct {
+	Path     string
+	Original string
+}
+
+// Find lists the conflict copies in the notebook at root.
+func Find(root string) ([]Conflict, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	conflicts := []Conflict{}
+	for _, path := range paths {
+		if original, ok := Original(path); ok {
+			conflicts = append(conflicts, Conflict{Path: path, Original: original})
+		}
+	}
+
+	return conflicts, nil
+}
+
+// Original is the path of the note that the file at path is a conflict copy
+// of, if it is one.
+func Original(path string) (string, bool) {
+	for _, pattern := range patterns {
+		if match := pattern.FindStringSubmatch(filepath.Base(path)); match != nil {
+			return filepath.Join(filepath.Dir(path), match[1]+".md"), true
+		}
+	}
+
+	return "", false
+}
+
+// Merge combines two versions of a note that were both changed from base.
+// Lines either side added are all kept, ours first, so nothing written on
+// either side is lost, and lines either side deleted are dropped. A task
+// changed on both sides is kept once, and checked off when either side checked
+// it off.
+func Merge(base, ours, theirs string) string {
+	baseLines := strings.Split(base, "\n")
+	oursKept, oursAdded := changes(baseLines, strings.Split(ours, "\n"))
+	theirsKept, theirsAdded := changes(baseLines, strings.Split(theirs, "\n"))
+
+	merged := []string{}
+	for i := 0; i <= len(baseLines); i++ {
+		merged = append(merged, mergeHunk(oursAdded[i], theirsAdded[i])...)
+		if i < len(baseLines) && oursKept[i] && theirsKept[i] {
+			merged = append(merged, baseLines[i])
+		}
+	}
+
+	return strings.Join(merged, "\n")
+}
+
+// Common is the lines ours and theirs share, in order. It stands in for the
+// base of a merge when the version both were changed from is not known, so
+// that every line either side has is kept.
+func Common(ours, theirs string) string {
+	common := []string{}
+	for _, line := range diff.Lines(strings.Split(ours, "\n"), strings.Split(theirs, "\n")) {
+		if line.Op == diff.Equal {
+			common = append(common, line.Text)
+		}
+	}
+
+	return strings.Join(common, "\n")
+}
+
+// changes compares a side of a merge to its base. kept tells which lines of
+// base the side still has, and added holds the lines the side added before
+// each line of base, with the lines added at the end last.
+func changes(base, side []string) ([]bool, [][]string) {
+	kept := make([]bool, len(base))
+	added := make([][]string, len(base)+1)
+
+	i := 0
+	for _, line := range diff.Lines(base, side) {
+		switch line.Op {
+		case diff.Equal:
+			kept[i] = true
+			i++
+		case diff.Delete:
+			i++
+		case diff.Insert:
+			added[i] = append(added[i], line.Text)
+		}
+	}
+
+	return kept, added
+}
+
+func mergeHunk(mine, other []string) []string {
+	merged := append([]string{}, mine...)
+
+	for _, line := range other {
+		if i, ok := sameTask(merged, line); ok {
+			if isDone(line) && !isDone(merged[i]) {
+				merged[i] = line
+			}
+			continue
+		}
+
+		if !contains(merged, line) {
+			merged = append(merged, line)
+		}
+	}
+
+	return merged
+}
+
+// sameTask finds the line in lines that holds the same task as line.
+func sameTask(lines []string, line string) (int, bool) {
+	tasks := task.Parse("", line)
+	if len(tasks) == 0 {
+		return 0, false
+	}
+
+	for i, l := range lines {
+		if other := task.Parse("", l); len(other) > 0 && other[0].ID() == tasks[0].ID() {
+			return i, true
+		}
+	}
+
+	return 0, false
+}
+
+func isDone(line string) bool {
+	tasks := task.Parse("", line)
+	return len(tasks) > 0 && tasks[0].Done
+}
+
+func contains(lines []string, line string) bool {
+	for _, l := range lines {
+		if l == line {
+			return true
+		}
+	}
+
+	return false
+}
+
+// Resolve replaces the original note with content and moves the conflict copy
+// to the trash of the notebook at root.
+func Resolve(root string, c Conflict, content string, now time.Time) error {
+	if err := note.Save(c.Original, []byte(content)); err != nil {
+		return err
+	}
+
+	_, err := trash.Remove(root, c.Path, now)
+	return err
+}
+
+// Base is the version of the original note that both it and the conflict copy
+// were changed from: the last snapshot of the original taken before the copy
+// was last changed. It is false when the history has no such snapshot.
+func Base(c Conflict) (string, bool, error) {
+	info, err := os.Stat(c.Path)
+	if err != nil {
+		return "", false, err
+	}
+
+	versions, err := history.List(c.Original)
+	if err != nil {
+		return "", false, err
+	}
+
+	for i := len(versions) - 1; i >= 0; i-- {
+		if versions[i].Time.After(info.ModTime()) {
+			continue
+		}
+
+		content, err := history.Content(c.Original, versions[i].ID)
+		if err != nil {
+			return "", false, err
+		}
+		return string(content), true, nil
+	}
+
+	return "", false, nil
+}
+
+// Read returns the contents of the original note and the conflict copy. A
+// missing original reads as empty.
+func Read(c Conflict) (string, string, error) {
+	ours, err := os.ReadFile(c.Original)
+	if err != nil && !os.IsNotExist(err) {
+		return "", "", err
+	}
+
+	theirs, err := os.ReadFile(c.Path)
+	if err != nil {
+		return "", "", err
+	}
+
+	return string(ours), string(theirs), nil
+}
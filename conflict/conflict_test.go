@@ -0,0 +1,50 @@
+package conflict
+
+import (
+	"testing"
+)
+
+func TestOriginal(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"/nb/Log/2025-03-04.sync-conflict-20250304-123456-ABCDEFG.md", "/nb/Log/2025-03-04.md"},
+		{"/nb/Ideas (Sam's conflicted copy 2025-03-04).md", "/nb/Ideas.md"},
+		{"/nb/Ideas (conflict).md", "/nb/Ideas.md"},
+		{"/nb/Ideas.md", ""},
+	}
+
+	for _, c := range cases {
+		actual, _ := Original(c.given)
+
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestMerge(t *testing.T) {
+	ours := "# Tasks\n\n- [x] Call Sam\n- [ ] Buy milk\n- [ ] Ours only"
+	theirs := "# Tasks\n\n- [ ] Call Sam\n- [x] Buy milk\n- [ ] Theirs only"
+	expected := "# Tasks\n\n- [x] Call Sam\n- [x] Buy milk\n- [ ] Ours only\n- [ ] Theirs only"
+
+	actual := Merge(Common(ours, theirs), ours, theirs)
+
+	if expected != actual {
+		t.Fatalf("given: %q and %q\nexpected: %q\nactual: %q\n", ours, theirs, expected, actual)
+	}
+}
+
+func TestMergeFromBase(t *testing.T) {
+	base := "- [ ] Call Sam\n- [ ] Buy milk\n- [ ] Old idea\nNotes"
+	ours := "- [x] Call Sam\n- [ ] Buy milk\n- [ ] Old idea\nNotes\nMore notes"
+	theirs := "- [ ] Call Sam\n- [ ] Buy milk\nNotes\n- [ ] Theirs only"
+	expected := "- [x] Call Sam\n- [ ] Buy milk\nNotes\nMore notes\n- [ ] Theirs only"
+
+	actual := Merge(base, ours, theirs)
+
+	if expected != actual {
+		t.Fatalf("given: %q, %q, and %q\nexpected: %q\nactual: %q\n", base, ours, theirs, expected, actual)
+	}
+}
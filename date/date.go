@@ -0,0 +1,108 @@
+package date
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+)
+
+var (
+	ago   = regexp.MustCompile(`^(\d+)\s*(minute|hour|day|week|month|year)s?\s+ago$`)
+	days  = regexp.MustCompile(`^(\d+)\s*(d|w)$`)
+	ahead = regexp.MustCompile(`^\+(\d+)\s*(d|w)$`)
+)
+
+// Parse understands absolute dates (YYYY-MM-DD or RFC 3339) as well as
+// "now", "today", "yesterday", "tomorrow", phrases like "2 days ago", and
+// offsets into the future like "+3d" or "+1w", all relative to now. Days are
+// taken from their start.
+func Parse(s string, now time.Time) (time.Time, error) {
+	s = strings.ToLower(strings.TrimSpace(s))
+
+	switch s {
+	case "now":
+		return now, nil
+	case "today":
+		return StartOfDay(now), nil
+	case "yesterday":
+		return StartOfDay(now).AddDate(0, 0, -1), nil
+	case "tomorrow":
+		return StartOfDay(now).AddDate(0, 0, 1), nil
+	}
+
+	if match := ahead.FindStringSubmatch(s); match != nil {
+		n, _ := strconv.Atoi(match[1])
+		if match[2] == "w" {
+			n *= 7
+		}
+		return StartOfDay(now).AddDate(0, 0, n), nil
+	}
+
+	if t, err := time.ParseInLocation("2006-01-02", s, now.Location()); err == nil {
+		return t, nil
+	}
+
+	if t, err := time.Parse(time.RFC3339, strings.ToUpper(s)); err == nil {
+		return t, nil
+	}
+
+	if match := ago.FindStringSubmatch(s); match != nil {
+		n, _ := strconv.Atoi(match[1])
+		switch match[2] {
+		case "minute":
+			return now.Add(-time.Duration(n) * time.Minute), nil
+		case "hour":
+			return now.Add(-time.Duration(n) * time.Hour), nil
+		case "day":
+			return now.AddDate(0, 0, -n), nil
+		case "week":
+			return now.AddDate(0, 0, -7*n), nil
+		case "month":
+			return now.AddDate(0, -n, 0), nil
+		case "year":
+			return now.AddDate(-n, 0, 0), nil
+		}
+	}
+
+	return time.Time{}, fmt.Errorf("could not understand the date %q", s)
+}
+
+// Duration understands day and week lengths such as "30d" or "2w", as well as
+// anything time.ParseDuration does.
+func Duration(s string) (time.Duration, error) {
+	s = strings.ToLower(strings.TrimSpace(s))
+
+	if match := days.FindStringSubmatch(s); match != nil {
+		n, _ := strconv.Atoi(match[1])
+		if match[2] == "w" {
+			n *= 7
+		}
+		return time.Duration(n) * 24 * time.Hour, nil
+	}
+
+	d, err := time.ParseDuration(s)
+	if err != nil {
+		return 0, fmt.Errorf("could not understand the duration %q", s)
+	}
+
+	return d, nil
+}
+
+func StartOfDay(t time.Time) time.Time {
+	return time.Date(t.Year(), t.Month(), t.Day(), 0, 0, 0, 0, t.Location())
+}
+
+// StartOfWeek is the start of the first day of the week t is in, for weeks
+// starting on start.
+func StartOfWeek(t time.Time, start time.Weekday) time.Time {
+	return StartOfDay(t).AddDate(0, 0, -((int(t.Weekday()) - int(start) + 7) % 7))
+}
+
+// Week names the week t is in, as in 2025-W10, for weeks starting on start.
+// Weeks are numbered as ISO weeks are, moved to begin on start.
+func Week(t time.Time, start time.Weekday) string {
+	year, week := t.AddDate(0, 0, (int(time.Monday)-int(start)+7)%7).ISOWeek()
+	return fmt.Sprintf("%d-W%02d", year, week)
+}
@@ -0,0 +1,59 @@
+package date
+
+import (
+	"testing"
+	"time"
+)
+
+func TestFormat(t *testing.T) {
+	given := time.Date(2025, 3, 4, 0, 0, 0, 0, time.UTC)
+
+	cases := map[string]string{
+		"en":          "Tuesday 4 March 2025 (Tue, Mar)",
+		"de_DE.UTF-8": "Dienstag 4 März 2025 (Di, Mär)",
+		"fr":          "mardi 4 mars 2025 (mar., mars)",
+	}
+
+	for locale, expected := range cases {
+		actual := Format(given, "Monday 2 January 2006 (Mon, Jan)", locale)
+		if expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", locale, expected, actual)
+		}
+	}
+}
+
+func TestWeek(t *testing.T) {
+	// A Sunday, which ends ISO week 9 but starts week 10 when weeks start on
+	// Sunday.
+	given := time.Date(2025, 3, 2, 0, 0, 0, 0, time.UTC)
+
+	cases := map[time.Weekday]string{
+		time.Monday: "2025-W09",
+		time.Sunday: "2025-W10",
+	}
+
+	for start, expected := range cases {
+		actual := Week(given, start)
+		if expected != actual {
+			t.Fatalf("given: %s starting on %s\nexpected: %s\nactual: %s\n", given.Format("2006-01-02"), start, expected, actual)
+		}
+	}
+}
+
+func TestStartOfWeek(t *testing.T) {
+	// A Sunday afternoon.
+	given := time.Date(2025, 3, 2, 15, 30, 0, 0, time.UTC)
+
+	cases := map[time.Weekday]string{
+		time.Monday:   "2025-02-24 00:00",
+		time.Saturday: "2025-03-01 00:00",
+		time.Sunday:   "2025-03-02 00:00",
+	}
+
+	for start, expected := range cases {
+		actual := StartOfWeek(given, start).Format("2006-01-02 15:04")
+		if expected != actual {
+			t.Fatalf("given: %s starting on %s\nexpected: %s\nactual: %s\n", given.Format("2006-01-02 15:04"), start, expected, actual)
+		}
+	}
+}
@@ -0,0 +1,149 @@
+package diff
+
+import (
+	"fmt"
+	"strings"
+)
+
+type Op int
+
+const (
+	Equal Op = iota
+	Insert
+	Delete
+)
+
+type Line struct {
+	Op   Op
+	Text string
+}
+
+// Lines computes the edits that turn a into b from their longest common
+// subsequence.
+func Lines(a, b []string) []Line {
+	lcs := make([][]int, len(a)+1)
+	for i := range lcs {
+		lcs[i] = make([]int, len(b)+1)
+	}
+
+	for i := len(a) - 1; i >= 0; i-- {
+		for j := len(b) - 1; j >= 0; j-- {
+			if a[i] == b[j] {
+				lcs[i][j] = lcs[i+1][j+1] + 1
+			} else if lcs[i+1][j] >= lcs[i][j+1] {
+				lcs[i][j] = lcs[i+1][j]
+			} else {
+				lcs[i][j] = lcs[i][j+1]
+			}
+		}
+	}
+
+	edits := []Line{}
+	i, j := 0, 0
+	for i < len(a) && j < len(b) {
+		switch {
+		case a[i] == b[j]:
+			edits = append(edits, Line{Equal, a[i]})
+			i++
+			j++
+		case lcs[i+1][j] >= lcs[i][j+1]:
+			edits = append(edits, Line{Delete, a[i]})
+			i++
+		default:
+			edits = append(edits, Line{Insert, b[j]})
+			j++
+		}
+	}
+	for ; i < len(a); i++ {
+		edits = append(edits, Line{Delete, a[i]})
+	}
+	for ; j < len(b); j++ {
+		edits = append(edits, Line{Insert, b[j]})
+	}
+
+	return edits
+}
+
+// Unified renders the differences between a and b as a unified diff with the
+// given number of context lines around each change. It is empty when a and b
+// are the same.
+func Unified(fromName, toName string, a, b []string, context int) string {
+	edits := Lines(a, b)
+
+	// The position in a and b at which each edit starts.
+	type position struct{ a, b int }
+	starts := make([]position, len(edits)+1)
+	ai, bi := 0, 0
+	for k, e := range edits {
+		starts[k] = position{ai, bi}
+		if e.Op != Insert {
+			ai++
+		}
+		if e.Op != Delete {
+			bi++
+		}
+	}
+	starts[len(edits)] = position{ai, bi}
+
+	var out strings.Builder
+	k := 0
+	for {
+		for k < len(edits) && edits[k].Op == Equal {
+			k++
+		}
+		if k == len(edits) {
+			break
+		}
+
+		if out.Len() == 0 {
+			fmt.Fprintf(&out, "--- %s\n+++ %s\n", fromName, toName)
+		}
+
+		start := k - context
+		if start < 0 {
+			start = 0
+		}
+
+		// Grow the hunk until the next change is far enough away that the
+		// context between them would not overlap.
+		end := k
+		for end < len(edits) {
+			if edits[end].Op != Equal {
+				end++
+				continue
+			}
+
+			run := end
+			for run < len(edits) && edits[run].Op == Equal {
+				run++
+			}
+
+			if run == len(edits) || run-end > 2*context {
+				end += context
+				if end > len(edits) {
+					end = len(edits)
+				}
+				break
+			}
+
+			end = run
+		}
+
+		from, to := starts[start], starts[end]
+		fmt.Fprintf(&out, "@@ -%d,%d +%d,%d @@\n", from.a+1, to.a-from.a, from.b+1, to.b-from.b)
+		for _, e := range edits[start:end] {
+			switch e.Op {
+			case Equal:
+				out.WriteString(" " + e.Text + "\n")
+			case Insert:
+				out.WriteString("+" + e.Text + "\n")
+			case Delete:
+				out.WriteString("-" + e.Text + "\n")
+			}
+		}
+
+		k = end
+	}
+
+	return out.String()
+}
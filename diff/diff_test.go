@@ -0,0 +1,19 @@
+package diff
+
+import (
+	"testing"
+)
+
+func TestUnified(t *testing.T) {
+	given := [][]string{
+		{"# Log", "", "## Tasks", "", "- [ ] Write report", "- [ ] Call Sam"},
+		{"# Log", "", "## Tasks", "", "- [x] Write report", "- [ ] Call Sam"},
+	}
+	expected := "--- a\n+++ b\n@@ -4,3 +4,3 @@\n \n-- [ ] Write report\n+- [x] Write report\n - [ ] Call Sam\n"
+
+	actual := Unified("a", "b", given[0], given[1], 1)
+
+	if expected != actual {
+		t.Fatalf("given: %v\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
@@ -3,14 +3,73 @@ package editor
 import (
 	"os"
 	"os/exec"
+	"strings"
+	"unicode"
 
 	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/verbose"
 )
 
+// Open edits the file in the configured editor, running the pre_edit and
+// post_edit hooks around it.
 func Open(filePath string) error {
-	cmd := exec.Command(config.Editor, filePath)
+	if err := hook.Run(hook.PreEdit, filePath); err != nil {
+		return err
+	}
+
+	cmd := Command(config.EditorCommand(), filePath)
+	verbose.Debugf("running editor: %s", strings.Join(cmd.Args, " "))
 	cmd.Stdin = os.Stdin
 	cmd.Stdout = os.Stdout
+	cmd.Stderr = os.Stderr
+
+	if err := cmd.Run(); err != nil {
+		return err
+	}
+
+	return hook.Run(hook.PostEdit, filePath)
+}
+
+// Command runs the editor on the file. The editor is split into a program and
+// its arguments, as in "code --wait", without going through a shell, so it
+// works the same on Windows.
+func Command(editor, filePath string) *exec.Cmd {
+	args := append(Args(editor), filePath)
+	return exec.Command(args[0], args[1:]...)
+}
+
+// Args splits a command line on spaces, keeping text in double quotes
+// together, such as "C:\Program Files\Notepad++\notepad++.exe" -multiInst.
+// Backslashes are kept as they are since they separate Windows paths.
+func Args(command string) []string {
+	args := []string{}
+
+	var current strings.Builder
+	quoted, started := false, false
+	for _, r := range command {
+		switch {
+		case r == '"':
+			quoted = !quoted
+			started = true
+		case unicode.IsSpace(r) && !quoted:
+			if started {
+				args = append(args, current.String())
+				current.Reset()
+				started = false
+			}
+		default:
+			current.WriteRune(r)
+			started = true
+		}
+	}
+	if started {
+		args = append(args, current.String())
+	}
+
+	if len(args) == 0 {
+		return []string{config.Editor}
+	}
 
-	return cmd.Run()
+	return args
 }
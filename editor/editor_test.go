@@ -0,0 +1,25 @@
+package editor
+
+import (
+	"fmt"
+	"testing"
+)
+
+func TestArgs(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected []string
+	}{
+		{"nvim", []string{"nvim"}},
+		{"code --wait", []string{"code", "--wait"}},
+		{`"C:\Program Files\Notepad++\notepad++.exe" -multiInst`, []string{`C:\Program Files\Notepad++\notepad++.exe`, "-multiInst"}},
+	}
+
+	for _, c := range cases {
+		actual := Args(c.given)
+
+		if fmt.Sprint(c.expected) != fmt.Sprint(actual) {
+			t.Fatalf("given: %s\nexpected: %q\nactual: %q\n", c.given, c.expected, actual)
+		}
+	}
+}
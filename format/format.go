@@ -0,0 +1,234 @@
+package format
+
+import (
+	"regexp"
+	"sort"
+	"strings"
+	"unicode/utf8"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// FrontmatterOrder is the order of well known frontmatter keys. Any other keys
+// follow them alphabetically.
+var FrontmatterOrder = []string{"title", "id", "date", "created", "modified", "tags"}
+
+var (
+	heading  = regexp.MustCompile(`^(#{1,6})\s+(.*)$`)
+	listItem = regexp.MustCompile(`^([ \t]*)([-*+]|\d+[.)])[ \t]+(.*)$`)
+	rule     = regexp.MustCompile(`^[ \t]*(?:(?:-[ \t]*){3,}|(?:\*[ \t]*){3,}|(?:_[ \t]*){3,})$`)
+)
+
+// Format normalizes a note: frontmatter keys are ordered, headings get a blank
+// line around them, runs of blank lines are collapsed, trailing whitespace is
+// removed, list markers and nesting are made consistent, and lines longer
+// than width are wrapped when width is positive. Fenced and indented code
+// blocks and thematic breaks are left untouched.
+func Format(content string, width int) (string, error) {
+	n, err := note.Parse(content)
+	if err != nil {
+		return "", err
+	}
+
+	orderFrontmatter(n.Frontmatter)
+	n.Body = formatBody(n.Body, width)
+
+	formatted, err := n.Bytes()
+	if err != nil {
+		return "", err
+	}
+
+	return string(formatted), nil
+}
+
+func orderFrontmatter(fm note.Frontmatter) {
+	rank := func(key interface{}) int {
+		for i, known := range FrontmatterOrder {
+			if key == known {
+				return i
+			}
+		}
+		return len(FrontmatterOrder)
+	}
+
+	sort.SliceStable(fm, func(i, j int) bool {
+		ri, rj := rank(fm[i].Key), rank(fm[j].Key)
+		if ri != rj {
+			return ri < rj
+		}
+		if ri < len(FrontmatterOrder) {
+			return false
+		}
+		return strings.ToLower(toString(fm[i].Key)) < strings.ToLower(toString(fm[j].Key))
+	})
+}
+
+func toString(key interface{}) string {
+	s, _ := key.(string)
+	return s
+}
+
+// listLevel remembers an open list item so that nested items are indented to
+// the content of their parent.
+type listLevel struct {
+	original int
+	indent   int
+	width    int
+}
+
+func formatBody(body string, width int) string {
+	out := []string{}
+	blank := func() bool { return len(out) == 0 || out[len(out)-1] == "" }
+
+	inCode, inIndented := false, false
+	lists := []listLevel{}
+	for _, line := range strings.Split(body, "\n") {
+		trimmed := strings.TrimLeft(line, " \t")
+		if strings.HasPrefix(trimmed, "```") || strings.HasPrefix(trimmed, "~~~") {
+			inCode = !inCode
+			out = append(out, strings.TrimRight(line, " \t"))
+			continue
+		}
+
+		if inCode {
+			out = append(out, line)
+			continue
+		}
+
+		// Lines indented by four columns after a blank line, outside a list,
+		// are code, as are the lines that follow them.
+		if strings.TrimSpace(line) != "" && len(lists) == 0 && columns(line[:len(line)-len(trimmed)]) >= 4 && (inIndented || blank()) {
+			inIndented = true
+			out = append(out, line)
+			continue
+		}
+		if strings.TrimSpace(line) != "" {
+			inIndented = false
+		}
+
+		line = trimTrailing(line)
+		trimmed = strings.TrimLeft(line, " \t")
+
+		if strings.TrimSpace(line) == "" {
+			if !blank() {
+				out = append(out, "")
+			}
+			continue
+		}
+
+		if match := heading.FindStringSubmatch(line); match != nil {
+			lists = nil
+			if !blank() {
+				out = append(out, "")
+			}
+			out = append(out, match[1]+" "+strings.TrimSpace(match[2]), "")
+			continue
+		}
+
+		if rule.MatchString(line) {
+			lists = nil
+			out = append(out, strings.TrimSpace(line))
+			continue
+		}
+
+		if match := listItem.FindStringSubmatch(line); match != nil {
+			original := columns(match[1])
+
+			for len(lists) > 0 && lists[len(lists)-1].original > original {
+				lists = lists[:len(lists)-1]
+			}
+
+			indent := 0
+			if len(lists) > 0 && lists[len(lists)-1].original == original {
+				indent = lists[len(lists)-1].indent
+				lists = lists[:len(lists)-1]
+			} else if len(lists) > 0 {
+				indent = lists[len(lists)-1].indent + lists[len(lists)-1].width
+			}
+
+			marker := match[2]
+			if marker == "*" || marker == "+" {
+				marker = "-"
+			}
+
+			prefix := strings.Repeat(" ", indent) + marker + " "
+			lists = append(lists, listLevel{original, indent, len(marker) + 1})
+
+			out = append(out, wrap(prefix, match[3], strings.Repeat(" ", len(prefix)), width)...)
+			continue
+		}
+
+		if columns(line[:len(line)-len(trimmed)]) == 0 {
+			lists = nil
+		}
+
+		if strings.HasPrefix(trimmed, "|") || strings.HasPrefix(trimmed, ">") {
+			out = append(out, line)
+			continue
+		}
+
+		indent := line[:len(line)-len(trimmed)]
+		out = append(out, wrap(indent, trimmed, indent, width)...)
+	}
+
+	for len(out) > 0 && out[len(out)-1] == "" {
+		out = out[:len(out)-1]
+	}
+
+	return strings.Join(out, "\n") + "\n"
+}
+
+// trimTrailing removes trailing whitespace, keeping two spaces where they mark
+// a hard line break.
+func trimTrailing(line string) string {
+	trimmed := strings.TrimRight(line, " \t")
+	if trimmed != "" && strings.HasSuffix(line, "  ") {
+		return trimmed + "  "
+	}
+	return trimmed
+}
+
+// columns measures indentation with tabs expanded to the next multiple of 4.
+func columns(indent string) int {
+	n := 0
+	for _, r := range indent {
+		if r == '\t' {
+			n += 4 - n%4
+		} else {
+			n++
+		}
+	}
+	return n
+}
+
+// wrap breaks text into lines no longer than width characters, starting the first with
+// prefix and the rest with indent. Words longer than the width are kept whole.
+func wrap(prefix, text, indent string, width int) []string {
+	if width <= 0 || utf8.RuneCountInString(prefix+text) <= width {
+		return []string{prefix + text}
+	}
+
+	hardBreak := strings.HasSuffix(text, "  ")
+
+	lines := []string{}
+	current := prefix
+	empty := true
+	for _, word := range strings.Fields(text) {
+		if !empty && utf8.RuneCountInString(current)+1+utf8.RuneCountInString(word) > width {
+			lines = append(lines, current)
+			current = indent
+			empty = true
+		}
+
+		if !empty {
+			current += " "
+		}
+		current += word
+		empty = false
+	}
+	if hardBreak {
+		current += "  "
+	}
+
+	return append(lines, current)
+}
@@ -0,0 +1,43 @@
+package format
+
+import (
+	"testing"
+)
+
+func TestFormat(t *testing.T) {
+	given := "---\ntags: [work]\ntitle: Alpha\n---\n#  Alpha  \n\n\n\nSome text. \n* one\n    * nested\n```\n*  kept  \n```\n## Next\n"
+	expected := "---\ntitle: Alpha\ntags:\n- work\n---\n# Alpha\n\nSome text.\n- one\n  - nested\n```\n*  kept  \n```\n\n## Next\n"
+
+	actual, err := Format(given, 0)
+	if err != nil {
+		t.Fatalf("given: %q\nunexpected error: %v\n", given, err)
+	}
+
+	if expected != actual {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
+
+func TestFormatWidth(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"one two three four\n", "one two\nthree four\n"},
+		{"ünë twö thrëë fóur\n", "ünë twö\nthrëë fóur\n"},
+		{"- one two three four\n", "- one two\n  three\n  four\n"},
+		{"Code:\n\n    x  :=   a very long line of code\n\tand   a tab\n", "Code:\n\n    x  :=   a very long line of code\n\tand   a tab\n"},
+		{"Above\n\n* * *\n\nBelow\n", "Above\n\n* * *\n\nBelow\n"},
+	}
+
+	for _, c := range cases {
+		actual, err := Format(c.given, 10)
+		if err != nil {
+			t.Fatalf("given: %q\nunexpected error: %v\n", c.given, err)
+		}
+
+		if c.expected != actual {
+			t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", c.given, c.expected, actual)
+		}
+	}
+}
@@ -0,0 +1,138 @@
+package github
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/network"
+)
+
+// Item is an issue or pull request.
+type Item struct {
+	Title  string `json:"title"`
+	URL    string `json:"html_url"`
+	Number int    `json:"number"`
+	Repo   string
+}
+
+// Activity is what needs attention on GitHub, and what got done today.
+type Activity struct {
+	Assigned []Item
+	Reviews  []Item
+	Merged   []Item
+}
+
+// Client talks to the GitHub API as the owner of the token.
+type Client struct {
+	Token string
+	API   string
+	HTTP  *http.Client
+}
+
+// Pull fetches the open issues assigned to the user, the open pull requests
+// waiting on their review, and their pull requests merged on the given day.
+func (c *Client) Pull(day time.Time) (*Activity, error) {
+	a := &Activity{}
+
+	var err error
+	if a.Assigned, err = c.search("is:open is:issue assignee:@me"); err != nil {
+		return nil, err
+	}
+
+	if a.Reviews, err = c.search("is:open is:pr review-requested:@me"); err != nil {
+		return nil, err
+	}
+
+	if a.Merged, err = c.search("is:pr author:@me merged:" + day.Format("2006-01-02")); err != nil {
+		return nil, err
+	}
+
+	return a, nil
+}
+
+func (c *Client) search(q string) ([]Item, error) {
+	req, err := http.NewRequest("GET", strings.TrimRight(c.API, "/")+"/search/issues?per_page=50&q="+url.QueryEscape(q), nil)
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Accept", "application/vnd.github+json")
+	req.Header.Set("Authorization", "Bearer "+c.Token)
+
+	client := c.HTTP
+	if client == nil {
+		if client, err = network.Client(30 * time.Second); err != nil {
+			return nil, err
+		}
+	}
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("GitHub responded %s to %q", resp.Status, q)
+	}
+
+	var result struct {
+		Items []struct {
+			Item
+			RepositoryURL string `json:"repository_url"`
+		} `json:"items"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&result); err != nil {
+		return nil, err
+	}
+
+	items := []Item{}
+	for _, i := range result.Items {
+		item := i.Item
+
+		// The repository URL ends in /repos/<owner>/<name>.
+		parts := strings.Split(i.RepositoryURL, "/")
+		if len(parts) >= 2 {
+			item.Repo = parts[len(parts)-2] + "/" + parts[len(parts)-1]
+		}
+
+		items = append(items, item)
+	}
+
+	return items, nil
+}
+
+// Markdown lists the activity under a subheading for each kind, leaving out
+// the kinds with nothing in them.
+func (a *Activity) Markdown() string {
+	groups := []struct {
+		name  string
+		items []Item
+	}{
+		{"Assigned", a.Assigned},
+		{"Review requests", a.Reviews},
+		{"Merged", a.Merged},
+	}
+
+	sections := []string{}
+	for _, g := range groups {
+		if len(g.items) == 0 {
+			continue
+		}
+
+		lines := []string{"### " + g.name, ""}
+		for _, item := range g.items {
+			lines = append(lines, fmt.Sprintf("- [%s#%d](%s) %s", item.Repo, item.Number, item.URL, item.Title))
+		}
+		sections = append(sections, strings.Join(lines, "\n"))
+	}
+
+	if len(sections) == 0 {
+		return "Nothing on GitHub."
+	}
+
+	return strings.Join(sections, "\n\n")
+}
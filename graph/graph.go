@@ -0,0 +1,116 @@
+package graph
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+)
+
+// Graph is the notes of a notebook and the links between them, along with
+// the tags in their frontmatter.
+type Graph struct {
+	Paths  []string
+	Out    map[string][]string
+	In     map[string][]string
+	Words  map[string]int
+	Tags   map[string][]string
+	Tagged map[string][]string
+}
+
+// Build reads every note under root and follows its wikilinks and relative
+// markdown links to other notes. Links to missing files and links from a note
+// to itself are left out.
+func Build(root string) (*Graph, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+	resolver := link.NewResolver(root, paths)
+
+	g := &Graph{
+		Paths:  paths,
+		Out:    map[string][]string{},
+		In:     map[string][]string{},
+		Words:  map[string]int{},
+		Tags:   map[string][]string{},
+		Tagged: map[string][]string{},
+	}
+
+	exists := map[string]bool{}
+	for _, path := range paths {
+		exists[path] = true
+	}
+
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		body := string(content)
+		if n, err := note.Parse(body); err == nil {
+			body = n.Body
+
+			if value, ok := n.Frontmatter.Get("tags"); ok {
+				for _, tag := range tags(value) {
+					g.Tags[path] = append(g.Tags[path], tag)
+					g.Tagged[tag] = append(g.Tagged[tag], path)
+				}
+			}
+		}
+		g.Words[path] = len(strings.Fields(body))
+
+		targets := []string{}
+		for _, l := range link.Wikilinks(body) {
+			if target, ok := resolver.Resolve(l.Target); ok {
+				targets = append(targets, target)
+			}
+		}
+		for _, l := range link.MarkdownLinks(body) {
+			if target, ok := link.Relative(filepath.Dir(path), l.Target); ok && exists[target] {
+				targets = append(targets, target)
+			}
+		}
+
+		for _, target := range targets {
+			if target != path {
+				g.Out[path] = append(g.Out[path], target)
+				g.In[target] = append(g.In[target], path)
+			}
+		}
+	}
+
+	return g, nil
+}
+
+// Orphans are the notes that link to no other note and that no other note
+// links to.
+func (g *Graph) Orphans() []string {
+	orphans := []string{}
+	for _, path := range g.Paths {
+		if len(g.Out[path]) == 0 && len(g.In[path]) == 0 {
+			orphans = append(orphans, path)
+		}
+	}
+
+	return orphans
+}
+
+// Stubs are the notes with fewer than words words in their body, shortest
+// first.
+func (g *Graph) Stubs(words int) []string {
+	stubs := []string{}
+	for _, path := range g.Paths {
+		if g.Words[path] < words {
+			stubs = append(stubs, path)
+		}
+	}
+
+	sort.SliceStable(stubs, func(i, j int) bool { return g.Words[stubs[i]] < g.Words[stubs[j]] })
+
+	return stubs
+}
@@ -0,0 +1,25 @@
+package graph
+
+import (
+	"fmt"
+	"testing"
+)
+
+func TestRelated(t *testing.T) {
+	given := &Graph{
+		Out: map[string][]string{"a": {"hub"}, "b": {"hub"}, "c": {"a"}},
+		In:  map[string][]string{"hub": {"a", "b"}, "a": {"c"}},
+		Tags: map[string][]string{
+			"a": {"go"},
+			"d": {"go"},
+		},
+		Tagged: map[string][]string{"go": {"a", "d"}},
+	}
+	expected := "[{c 3} {hub 3} {d 2} {b 1}]"
+
+	actual := fmt.Sprint(given.Related("a", 10))
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", "a", expected, actual)
+	}
+}
@@ -0,0 +1,90 @@
+package graph
+
+import (
+	"sort"
+	"strings"
+)
+
+// Relation is a note related to another and how strongly.
+type Relation struct {
+	Path  string
+	Score int
+}
+
+// Related are up to n notes most like the note at path, best first. Notes
+// score for linking to each other, for each note they both link to or are
+// linked from, and for each tag they share.
+func (g *Graph) Related(path string, n int) []Relation {
+	scores := map[string]int{}
+
+	for _, other := range g.Out[path] {
+		scores[other] += 3
+	}
+	for _, other := range g.In[path] {
+		scores[other] += 3
+	}
+
+	// Notes linking to the same note, or linked from the same note, are
+	// about the same thing.
+	for _, target := range g.Out[path] {
+		for _, other := range g.In[target] {
+			scores[other]++
+		}
+	}
+	for _, source := range g.In[path] {
+		for _, other := range g.Out[source] {
+			scores[other]++
+		}
+	}
+
+	for _, tag := range g.Tags[path] {
+		for _, other := range g.Tagged[tag] {
+			scores[other] += 2
+		}
+	}
+
+	delete(scores, path)
+
+	related := []Relation{}
+	for other, score := range scores {
+		related = append(related, Relation{Path: other, Score: score})
+	}
+
+	sort.Slice(related, func(i, j int) bool {
+		if related[i].Score != related[j].Score {
+			return related[i].Score > related[j].Score
+		}
+		return related[i].Path < related[j].Path
+	})
+
+	if len(related) > n {
+		related = related[:n]
+	}
+
+	return related
+}
+
+// tags reads the tags in frontmatter, written as a list or separated by
+// commas, without any leading #.
+func tags(value interface{}) []string {
+	items := []string{}
+	switch v := value.(type) {
+	case []interface{}:
+		for _, item := range v {
+			if s, ok := item.(string); ok {
+				items = append(items, s)
+			}
+		}
+	case string:
+		items = strings.Split(v, ",")
+	}
+
+	tags := []string{}
+	for _, item := range items {
+		if tag := strings.ToLower(strings.TrimPrefix(strings.TrimSpace(item), "#")); tag != "" {
+			tags = append(tags, tag)
+		}
+	}
+
+	return tags
+}
@@ -0,0 +1,150 @@
+package habit
+
+import (
+	"os"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+const Section = "Habits"
+
+// Checklist is the Habits section added to new logs, or nothing when no
+// habits are configured.
+func Checklist(habits []string) string {
+	if len(habits) == 0 {
+		return ""
+	}
+
+	var b strings.Builder
+	b.WriteString("## " + Section + "\n\n")
+	for _, h := range habits {
+		b.WriteString("- [ ] " + h + "\n")
+	}
+
+	return b.String()
+}
+
+type Report struct {
+	Habit  string
+	Done   int
+	Days   int
+	Streak int
+	Best   int
+}
+
+// Reports reads the Habits section of the logs for the given number of days
+// up to today. Habits found in the logs but no longer configured are
+// reported after the configured ones. A day without a log counts as missed.
+func Reports(logDir string, habits []string, today time.Time, days int) ([]Report, error) {
+	order := append([]string{}, habits...)
+	known := map[string]bool{}
+	for _, h := range habits {
+		known[task.Normalize(h)] = true
+	}
+
+	// done[day][habit] is true when the habit was checked off that day, with
+	// day zero being the oldest.
+	done := make([]map[string]bool, days)
+	for i := 0; i < days; i++ {
+		done[i] = map[string]bool{}
+		day := today.AddDate(0, 0, i-days+1)
+
+		content, err := os.ReadFile(logpath.For(logDir, day))
+		if os.IsNotExist(err) {
+			continue
+		} else if err != nil {
+			return nil, err
+		}
+
+		for _, t := range task.Parse("", note.Section(string(content), Section)) {
+			key := task.Normalize(t.Text)
+			if !known[key] {
+				known[key] = true
+				order = append(order, t.Text)
+			}
+			if t.Done {
+				done[i][key] = true
+			}
+		}
+	}
+
+	reports := []Report{}
+	for _, h := range order {
+		key := task.Normalize(h)
+		r := Report{Habit: h, Days: days}
+
+		run := 0
+		for i := 0; i < days; i++ {
+			if done[i][key] {
+				r.Done++
+				run++
+			} else {
+				run = 0
+			}
+			if run > r.Best {
+				r.Best = run
+			}
+		}
+
+		// Today not being checked off yet does not break the streak.
+		last := days - 1
+		if days > 0 && !done[last][key] {
+			last--
+		}
+		for i := last; i >= 0 && done[i][key]; i-- {
+			r.Streak++
+		}
+
+		reports = append(reports, r)
+	}
+
+	return reports, nil
+}
+
+// Entry is whether a habit was checked off in the log of a day.
+type Entry struct {
+	Date  time.Time
+	Habit string
+	Done  bool
+	Path  string
+}
+
+// Entries reads the Habits section of every log, oldest first. Configured
+// habits missing from a log are entries that were not done.
+func Entries(logDir string, habits []string) ([]Entry, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return nil, err
+	}
+
+	entries := []Entry{}
+	for _, path := range paths {
+		day, ok := logpath.Date(logDir, path, time.Local)
+		if !ok {
+			continue
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		seen := map[string]bool{}
+		for _, t := range task.Parse(path, note.Section(string(content), Section)) {
+			seen[task.Normalize(t.Text)] = true
+			entries = append(entries, Entry{Date: day, Habit: t.Text, Done: t.Done, Path: path})
+		}
+
+		for _, h := range habits {
+			if !seen[task.Normalize(h)] {
+				entries = append(entries, Entry{Date: day, Habit: h, Path: path})
+			}
+		}
+	}
+
+	return entries, nil
+}
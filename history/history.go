@@ -0,0 +1,192 @@
+package history
+
+import (
+	"bufio"
+	"crypto/sha1"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/verbose"
+)
+
+// Version is a snapshot of a note. ID is the start of the hash of its content.
+type Version struct {
+	ID   string
+	Time time.Time
+	hash string
+}
+
+// Dir is where snapshots are kept: each distinct content once, under its hash,
+// and for each note a log of the hashes it has had. It is in the data
+// directory of the notebook rather than the notebook itself. History kept in
+// .nb/history in the notebook by earlier versions is moved there on first use,
+// and is used where it is when it cannot be moved.
+func Dir(root string) string {
+	dir := filepath.Join(config.DataDir(root), "history")
+	legacy := filepath.Join(root, ".nb", "history")
+	if dir == legacy {
+		return dir
+	}
+
+	if _, err := os.Stat(legacy); err != nil {
+		return dir
+	}
+	if _, err := os.Stat(dir); err == nil {
+		return dir
+	}
+
+	if err := os.MkdirAll(filepath.Dir(dir), 0755); err == nil {
+		if err := os.Rename(legacy, dir); err == nil {
+			verbose.Debugf("moved history from %s to %s", legacy, dir)
+			return dir
+		}
+	}
+
+	return legacy
+}
+
+// Record snapshots the current content of the note at path, unless it is
+// unchanged since the last snapshot. Files outside the notebook and files that
+// do not exist are ignored.
+func Record(path string, now time.Time) error {
+	root, rel, ok := locate(path)
+	if !ok {
+		return nil
+	}
+
+	content, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return nil
+	} else if err != nil {
+		return err
+	}
+
+	sum := sha1.Sum(content)
+	hash := hex.EncodeToString(sum[:])
+
+	versions, err := list(root, rel)
+	if err != nil {
+		return err
+	}
+	if len(versions) > 0 && versions[len(versions)-1].hash == hash {
+		return nil
+	}
+
+	object := objectPath(root, hash)
+	if _, err := os.Stat(object); os.IsNotExist(err) {
+		if err := os.MkdirAll(filepath.Dir(object), 0755); err != nil {
+			return err
+		}
+		if err := os.WriteFile(object, content, 0644); err != nil {
+			return err
+		}
+	}
+
+	index := indexPath(root, rel)
+	verbose.Debugf("recording version %s of %s in %s", hash[:7], rel, index)
+	if err := os.MkdirAll(filepath.Dir(index), 0755); err != nil {
+		return err
+	}
+
+	f, err := os.OpenFile(index, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0644)
+	if err != nil {
+		return err
+	}
+	defer f.Close()
+
+	_, err = fmt.Fprintf(f, "%s %s\n", now.Format(time.RFC3339), hash)
+	return err
+}
+
+// List returns the snapshots of the note at path, oldest first.
+func List(path string) ([]Version, error) {
+	root, rel, ok := locate(path)
+	if !ok {
+		return nil, fmt.Errorf("%s is not in the notebook", path)
+	}
+
+	return list(root, rel)
+}
+
+// Content returns the content of the note at path in the version with the
+// given ID.
+func Content(path, id string) ([]byte, error) {
+	root, _, ok := locate(path)
+	if !ok {
+		return nil, fmt.Errorf("%s is not in the notebook", path)
+	}
+
+	versions, err := List(path)
+	if err != nil {
+		return nil, err
+	}
+
+	for _, v := range versions {
+		if strings.HasPrefix(v.hash, id) {
+			return os.ReadFile(objectPath(root, v.hash))
+		}
+	}
+
+	return nil, fmt.Errorf("no version %s of %s", id, path)
+}
+
+func list(root, rel string) ([]Version, error) {
+	f, err := os.Open(indexPath(root, rel))
+	if os.IsNotExist(err) {
+		return []Version{}, nil
+	} else if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+
+	versions := []Version{}
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) != 2 || len(fields[1]) < 8 {
+			continue
+		}
+
+		t, err := time.Parse(time.RFC3339, fields[0])
+		if err != nil {
+			continue
+		}
+
+		versions = append(versions, Version{ID: fields[1][:8], Time: t, hash: fields[1]})
+	}
+
+	return versions, scanner.Err()
+}
+
+// locate finds the notebook root and the path of the note from it.
+func locate(path string) (string, string, bool) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		return "", "", false
+	}
+
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return "", "", false
+	}
+
+	rel, err := filepath.Rel(root, abs)
+	if err != nil || strings.HasPrefix(rel, "..") {
+		return "", "", false
+	}
+
+	return root, rel, true
+}
+
+func objectPath(root, hash string) string {
+	return filepath.Join(Dir(root), "objects", hash[:2], hash[2:])
+}
+
+func indexPath(root, rel string) string {
+	return filepath.Join(Dir(root), "index", rel+".log")
+}
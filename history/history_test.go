@@ -0,0 +1,32 @@
+package history
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/spf13/viper"
+)
+
+func TestDirMovesLegacyHistory(t *testing.T) {
+	root := t.TempDir()
+	viper.Set("data_dir", t.TempDir())
+	t.Cleanup(func() { viper.Set("data_dir", "") })
+
+	legacy := filepath.Join(root, ".nb", "history", "index", "Ideas.md.log")
+	if err := os.MkdirAll(filepath.Dir(legacy), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(legacy, []byte("2025-03-04T10:00:00Z 0123456789abcdef\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	dir := Dir(root)
+
+	if _, err := os.Stat(filepath.Join(dir, "index", "Ideas.md.log")); err != nil {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %v\n", legacy, "history moved to "+dir, err)
+	}
+	if _, err := os.Stat(legacy); !os.IsNotExist(err) {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %v\n", legacy, "no history left in the notebook", err)
+	}
+}
@@ -0,0 +1,81 @@
+package hook
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/verbose"
+)
+
+// The points at which a hook can run, named as they are set under `hooks`.
+const (
+	PreLogCreate   = "pre_log_create"
+	PostLogCreate  = "post_log_create"
+	PostNoteCreate = "post_note_create"
+	PreEdit        = "pre_edit"
+	PostEdit       = "post_edit"
+	PostRollover   = "post_rollover"
+	PreBackup      = "pre_backup"
+	PostBackup     = "post_backup"
+)
+
+// Run runs the command set for event under `hooks`, if there is one. The
+// command goes through the shell, sh or cmd on Windows, from the notebook
+// root, with these in its environment:
+//
+//	NB_EVENT     the event, such as post_log_create
+//	NB_FILE      the file the event is about
+//	NB_DATE      today, as YYYY-MM-DD
+//	NB_COMMAND   the nb command being run, such as "log rollover"
+//	NB_NOTEBOOK  the notebook root
+//
+// Its output goes to stderr. A pre_ hook that fails stops nb, so it can
+// prevent what it runs before. A post_ hook runs after the change is made, so
+// its failure is only printed as a warning.
+func Run(event, file string) error {
+	command := config.Hook(event)
+	if command == "" {
+		return nil
+	}
+
+	root, err := config.GetRootDir()
+	if err != nil {
+		return err
+	}
+
+	var cmd *exec.Cmd
+	if runtime.GOOS == "windows" {
+		cmd = exec.Command("cmd", "/C", command)
+	} else {
+		cmd = exec.Command("sh", "-c", command)
+	}
+
+	verbose.Debugf("running %s hook: %s", event, command)
+
+	cmd.Dir = root
+	cmd.Stdout = os.Stderr
+	cmd.Stderr = os.Stderr
+	cmd.Env = append(os.Environ(),
+		"NB_EVENT="+event,
+		"NB_FILE="+file,
+		"NB_DATE="+time.Now().Format(note.DateFormat),
+		"NB_COMMAND="+strings.Join(os.Args[1:], " "),
+		"NB_NOTEBOOK="+root,
+	)
+
+	if err := cmd.Run(); err != nil {
+		if !strings.HasPrefix(event, "pre_") {
+			fmt.Fprintf(os.Stderr, "warning: %s hook failed: %v\n", event, err)
+			return nil
+		}
+		return fmt.Errorf("%s hook failed: %w", event, err)
+	}
+
+	return nil
+}
@@ -0,0 +1,46 @@
+package hook
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"testing"
+
+	"github.com/spf13/viper"
+)
+
+func TestRun(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("hooks run through sh")
+	}
+	viper.Reset()
+	defer viper.Reset()
+
+	root := t.TempDir()
+	viper.Set("notebook_path", root)
+
+	if err := Run(PreEdit, "a.md"); err != nil {
+		t.Fatalf("given: no pre_edit hook\nexpected: no error\nactual: %v\n", err)
+	}
+
+	viper.Set("hooks."+PreEdit, "exit 1")
+	if err := Run(PreEdit, "a.md"); err == nil {
+		t.Fatalf("given: a failing pre_edit hook\nexpected: an error\nactual: none\n")
+	}
+
+	viper.Set("hooks."+PostEdit, "exit 1")
+	if err := Run(PostEdit, "a.md"); err != nil {
+		t.Fatalf("given: a failing post_edit hook\nexpected: no error\nactual: %v\n", err)
+	}
+
+	viper.Set("hooks."+PostEdit, `printf '%s %s %s' "$NB_EVENT" "$NB_FILE" "$NB_NOTEBOOK" > env.txt`)
+	if err := Run(PostEdit, "a.md"); err != nil {
+		t.Fatal(err)
+	}
+
+	expected := PostEdit + " a.md " + root
+	content, err := os.ReadFile(filepath.Join(root, "env.txt"))
+	if actual := string(content); err != nil || expected != actual {
+		t.Fatalf("given: a post_edit hook printing its environment\nexpected: %s\nactual: %s\n", expected, actual)
+	}
+}
@@ -0,0 +1,221 @@
+package importer
+
+import (
+	"fmt"
+	"io/fs"
+	"os"
+	"path"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+// DefaultDatePattern finds dates such as 2025-03-04 in file names.
+const DefaultDatePattern = `(\d{4})-(\d{2})-(\d{2})`
+
+// Mapping moves the files whose path from the source folder matches Pattern
+// into Folder. The part of the path after the fixed start of the pattern is
+// kept, so "journal/*=Log/" moves journal/2021/a.md to Log/2021/a.md.
+type Mapping struct {
+	Pattern string
+	Folder  string
+}
+
+// ParseMapping reads a mapping written as pattern=folder.
+func ParseMapping(text string) (Mapping, error) {
+	i := strings.LastIndex(text, "=")
+	if i <= 0 {
+		return Mapping{}, fmt.Errorf("%q should be written as pattern=folder", text)
+	}
+
+	pattern := strings.Trim(filepath.ToSlash(text[:i]), "/")
+	if _, err := path.Match(pattern, ""); err != nil {
+		return Mapping{}, fmt.Errorf("%q is not a valid pattern", pattern)
+	}
+
+	return Mapping{Pattern: pattern, Folder: strings.Trim(filepath.ToSlash(text[i+1:]), "/")}, nil
+}
+
+// target is where the mapping puts the file at rel, or false when it does not
+// match. A pattern also matches the files below the folders it matches.
+func (m Mapping) target(rel string) (string, bool) {
+	parts := strings.Split(rel, "/")
+	for n := len(parts); n > 0; n-- {
+		if ok, _ := path.Match(m.Pattern, strings.Join(parts[:n], "/")); ok {
+			fixed := 0
+			for _, p := range strings.Split(m.Pattern, "/") {
+				if strings.ContainsAny(p, `*?[\`) {
+					break
+				}
+				fixed++
+			}
+			return path.Join(m.Folder, strings.Join(parts[fixed:], "/")), true
+		}
+	}
+
+	return "", false
+}
+
+// Folder imports a folder of markdown files.
+type Folder struct {
+	Mappings []Mapping
+	// DateFrom is where the date of each note comes from: filename, where
+	// DatePattern finds it, frontmatter, modified, or nothing.
+	DateFrom    string
+	DatePattern *regexp.Regexp
+	// Frontmatter is added to each note that does not have the keys already.
+	Frontmatter note.Frontmatter
+}
+
+// Copy is a file the import copies into the notebook. Skip says why it will
+// not be copied, if it will not.
+type Copy struct {
+	From string
+	To   string
+	Date time.Time
+	Skip string
+}
+
+// Plan works out where each markdown file under src goes in the notebook at
+// root without changing anything. Files that cannot be read, such as ones
+// with broken frontmatter, are skipped. Notes mapped into the log folder with a
+// date go where the log of that date belongs.
+func (f Folder) Plan(src, root string) ([]Copy, error) {
+	logFolder := strings.Trim(filepath.ToSlash(config.LogFolder()), "/")
+	logDir := filepath.Join(root, filepath.FromSlash(logFolder))
+
+	copies := []Copy{}
+	err := filepath.WalkDir(src, func(p string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+
+		if d.IsDir() {
+			if p != src && strings.HasPrefix(d.Name(), ".") {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+
+		if filepath.Ext(p) != ".md" {
+			return nil
+		}
+
+		rel, err := filepath.Rel(src, p)
+		if err != nil {
+			return err
+		}
+		rel = filepath.ToSlash(rel)
+
+		c := Copy{From: p}
+		c.Date, _ = f.date(p)
+
+		to := rel
+		for _, m := range f.Mappings {
+			if target, ok := m.target(rel); ok {
+				to = target
+				break
+			}
+		}
+		c.To = filepath.Join(root, filepath.FromSlash(to))
+
+		if !c.Date.IsZero() && strings.HasPrefix(to, logFolder+"/") {
+			c.To = logpath.For(logDir, c.Date)
+		}
+
+		if _, err := note.Read(p); err != nil {
+			c.Skip = fmt.Sprintf("could not be read: %v", err)
+		} else if _, err := os.Stat(c.To); err == nil {
+			c.Skip = "already exists"
+		}
+		for _, other := range copies {
+			if other.To == c.To && other.Skip == "" && c.Skip == "" {
+				c.Skip = "clashes with " + other.From
+			}
+		}
+
+		copies = append(copies, c)
+		return nil
+	})
+
+	return copies, err
+}
+
+// Apply copies the planned files that are not skipped, adding the date and
+// the extra frontmatter.
+func (f Folder) Apply(copies []Copy) error {
+	for _, c := range copies {
+		if c.Skip != "" {
+			continue
+		}
+
+		n, err := note.Read(c.From)
+		if err != nil {
+			return fmt.Errorf("could not read %s: %w", c.From, err)
+		}
+
+		if !c.Date.IsZero() {
+			if _, ok := n.Frontmatter.Get("date"); !ok {
+				n.Frontmatter.Set("date", c.Date.Format(note.DateFormat))
+			}
+		}
+		n.Frontmatter.Merge(f.Frontmatter)
+
+		if err := os.MkdirAll(filepath.Dir(c.To), 0755); err != nil {
+			return err
+		}
+
+		n.Path = c.To
+		if err := n.Write(); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// date finds the date of the note at p the way DateFrom says to.
+func (f Folder) date(p string) (time.Time, bool) {
+	switch f.DateFrom {
+	case "filename":
+		pattern := f.DatePattern
+		if pattern == nil {
+			pattern = regexp.MustCompile(DefaultDatePattern)
+		}
+
+		match := pattern.FindStringSubmatch(filepath.Base(p))
+		if len(match) < 4 {
+			return time.Time{}, false
+		}
+
+		year, _ := strconv.Atoi(match[1])
+		month, _ := strconv.Atoi(match[2])
+		day, _ := strconv.Atoi(match[3])
+		t := time.Date(year, time.Month(month), day, 0, 0, 0, 0, time.Local)
+		if t.Month() != time.Month(month) || t.Day() != day {
+			return time.Time{}, false
+		}
+		return t, true
+	case "frontmatter":
+		n, err := note.Read(p)
+		if err != nil {
+			return time.Time{}, false
+		}
+		return n.Frontmatter.Date("date")
+	case "modified":
+		info, err := os.Stat(p)
+		if err != nil {
+			return time.Time{}, false
+		}
+		t := info.ModTime()
+		return time.Date(t.Year(), t.Month(), t.Day(), 0, 0, 0, 0, time.Local), true
+	}
+
+	return time.Time{}, false
+}
@@ -0,0 +1,26 @@
+package importer
+
+import "testing"
+
+func TestMappingTarget(t *testing.T) {
+	m, err := ParseMapping("journal/*=Log/")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"journal/2021-01-02.md", "Log/2021-01-02.md"},
+		{"journal/2021/a.md", "Log/2021/a.md"},
+		{"ideas/a.md", ""},
+	}
+
+	for _, c := range cases {
+		actual, _ := m.target(c.given)
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
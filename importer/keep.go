@@ -0,0 +1,225 @@
+package importer
+
+import (
+	"archive/zip"
+	"encoding/json"
+	"io"
+	"os"
+	"path"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// KeepFolder is where notes imported from Google Keep go.
+const KeepFolder = "Keep"
+
+// keepNote is a note in the JSON files of a Google Takeout export of Keep.
+type keepNote struct {
+	Title       string `json:"title"`
+	TextContent string `json:"textContent"`
+	ListContent []struct {
+		Text      string `json:"text"`
+		IsChecked bool   `json:"isChecked"`
+	} `json:"listContent"`
+	Labels []struct {
+		Name string `json:"name"`
+	} `json:"labels"`
+	Annotations []struct {
+		Title string `json:"title"`
+		URL   string `json:"url"`
+	} `json:"annotations"`
+	Attachments []struct {
+		FilePath string `json:"filePath"`
+	} `json:"attachments"`
+	IsPinned                bool  `json:"isPinned"`
+	IsArchived              bool  `json:"isArchived"`
+	IsTrashed               bool  `json:"isTrashed"`
+	CreatedTimestampUsec    int64 `json:"createdTimestampUsec"`
+	UserEditedTimestampUsec int64 `json:"userEditedTimestampUsec"`
+}
+
+// Keep imports the notes in a Google Takeout zip of Keep into the Keep folder
+// of the notebook at root, skipping the trash. Checklists become checkboxes,
+// labels become tags, and pinned and archived notes are marked as such in the
+// frontmatter. Attached images are copied next to the notes. It returns the
+// paths of the new notes.
+func Keep(root, takeout string) ([]string, error) {
+	r, err := zip.OpenReader(takeout)
+	if err != nil {
+		return nil, err
+	}
+	defer r.Close()
+
+	files := map[string]*zip.File{}
+	for _, f := range r.File {
+		files[path.Base(f.Name)] = f
+	}
+
+	dir := filepath.Join(root, KeepFolder)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return nil, err
+	}
+
+	paths := []string{}
+	for _, f := range r.File {
+		if path.Ext(f.Name) != ".json" || !strings.Contains(f.Name, "Keep/") {
+			continue
+		}
+
+		var k keepNote
+		if err := readJSON(f, &k); err != nil {
+			// Takeout puts other JSON, such as Labels.json, beside the notes.
+			continue
+		}
+		if k.IsTrashed || k.empty() {
+			continue
+		}
+
+		base := strings.TrimSuffix(path.Base(f.Name), ".json")
+		n := k.note(base)
+		n.Path = note.Available(filepath.Join(dir, note.FileName(n.Frontmatter.String("title"), base)))
+
+		for _, a := range k.Attachments {
+			attachment, ok := files[path.Base(a.FilePath)]
+			if !ok || strings.HasPrefix(path.Base(a.FilePath), ".") {
+				continue
+			}
+
+			name := path.Base(a.FilePath)
+			if err := extract(attachment, filepath.Join(dir, "attachments", name)); err != nil {
+				return paths, err
+			}
+			n.Body += "\n![](attachments/" + name + ")\n"
+		}
+
+		if err := n.Write(); err != nil {
+			return paths, err
+		}
+		paths = append(paths, n.Path)
+	}
+
+	return paths, nil
+}
+
+// note converts a Keep note to markdown. Untitled notes are named after their
+// first line, or the file they came from.
+func (k keepNote) note(fallback string) *note.Note {
+	n := &note.Note{}
+
+	title := strings.TrimSpace(k.Title)
+	if title == "" {
+		title = firstLine(k.TextContent)
+	}
+	if title == "" && len(k.ListContent) > 0 {
+		title = firstLine(k.ListContent[0].Text)
+	}
+	if title == "" {
+		title = fallback
+	}
+	n.Frontmatter.Set("title", title)
+
+	if k.CreatedTimestampUsec > 0 {
+		n.Frontmatter.Set("created", time.UnixMicro(k.CreatedTimestampUsec).Format(note.DateFormat))
+	}
+	if k.UserEditedTimestampUsec > 0 {
+		n.Frontmatter.Set("modified", time.UnixMicro(k.UserEditedTimestampUsec).Format(note.DateFormat))
+	}
+
+	if len(k.Labels) > 0 {
+		tags := []string{}
+		for _, l := range k.Labels {
+			tags = append(tags, l.Name)
+		}
+		n.Frontmatter.Set("tags", tags)
+	}
+	if k.IsPinned {
+		n.Frontmatter.Set("pinned", true)
+	}
+	if k.IsArchived {
+		n.Frontmatter.Set("archived", true)
+	}
+	n.Frontmatter.Set("source", "keep")
+
+	var b strings.Builder
+	b.WriteString("# " + title + "\n")
+
+	if text := strings.TrimSpace(k.TextContent); text != "" {
+		b.WriteString("\n" + text + "\n")
+	}
+
+	if len(k.ListContent) > 0 {
+		b.WriteString("\n")
+		for _, item := range k.ListContent {
+			mark := " "
+			if item.IsChecked {
+				mark = "x"
+			}
+			b.WriteString("- [" + mark + "] " + strings.TrimSpace(item.Text) + "\n")
+		}
+	}
+
+	if len(k.Annotations) > 0 {
+		b.WriteString("\n")
+		for _, a := range k.Annotations {
+			label := a.Title
+			if label == "" {
+				label = a.URL
+			}
+			b.WriteString("- [" + label + "](" + a.URL + ")\n")
+		}
+	}
+
+	n.Body = b.String()
+
+	return n
+}
+
+func (k keepNote) empty() bool {
+	return strings.TrimSpace(k.Title+k.TextContent) == "" && len(k.ListContent) == 0 && len(k.Attachments) == 0
+}
+
+func readJSON(f *zip.File, v interface{}) error {
+	rc, err := f.Open()
+	if err != nil {
+		return err
+	}
+	defer rc.Close()
+
+	return json.NewDecoder(rc).Decode(v)
+}
+
+func extract(f *zip.File, to string) error {
+	if err := os.MkdirAll(filepath.Dir(to), 0755); err != nil {
+		return err
+	}
+
+	rc, err := f.Open()
+	if err != nil {
+		return err
+	}
+	defer rc.Close()
+
+	out, err := os.Create(to)
+	if err != nil {
+		return err
+	}
+
+	if _, err := io.Copy(out, rc); err != nil {
+		out.Close()
+		return err
+	}
+
+	return out.Close()
+}
+
+func firstLine(text string) string {
+	line := strings.TrimSpace(strings.SplitN(strings.TrimSpace(text), "\n", 2)[0])
+	if runes := []rune(line); len(runes) > 60 {
+		line = strings.TrimSpace(string(runes[:60]))
+	}
+
+	return line
+}
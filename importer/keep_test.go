@@ -0,0 +1,22 @@
+package importer
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestKeepNote(t *testing.T) {
+	given := `{"title": "", "listContent": [{"text": "Milk", "isChecked": true}, {"text": "Eggs", "isChecked": false}], "labels": [{"name": "errands"}], "isPinned": true}`
+	expected := "# Milk\n\n- [x] Milk\n- [ ] Eggs\n"
+
+	var k keepNote
+	if err := json.Unmarshal([]byte(given), &k); err != nil {
+		t.Fatal(err)
+	}
+	n := k.note("fallback")
+	actual := n.Body
+
+	if expected != actual || n.Frontmatter.String("pinned") != "true" || n.Frontmatter.String("tags") != "[errands]" {
+		t.Fatalf("given: %s\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
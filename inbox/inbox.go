@@ -0,0 +1,101 @@
+package inbox
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Name is the file name of the inbox note at the root of the notebook.
+const Name = "Inbox.md"
+
+// Item is a list item in the inbox, waiting to be refiled.
+type Item struct {
+	Line int
+	Text string
+}
+
+// Path returns the path of the inbox note of the notebook at root.
+func Path(root string) string {
+	return filepath.Join(root, Name)
+}
+
+// Add appends the text to the inbox as a list item, creating the inbox if it
+// does not exist.
+func Add(root, text string) error {
+	return note.Capture(Path(root), "", "- "+text)
+}
+
+// Items are the list items in the inbox, in order. Line numbers are
+// zero-indexed. A missing inbox has no items.
+func Items(root string) ([]Item, error) {
+	content, err := os.ReadFile(Path(root))
+	if os.IsNotExist(err) {
+		return []Item{}, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	items := []Item{}
+	for i, line := range strings.Split(string(content), "\n") {
+		text, ok := item(line)
+		if ok {
+			items = append(items, Item{Line: i, Text: text})
+		}
+	}
+
+	return items, nil
+}
+
+// Remove deletes the items from the inbox.
+func Remove(root string, items []Item) error {
+	if len(items) == 0 {
+		return nil
+	}
+
+	path := Path(root)
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return err
+	}
+
+	lines := strings.Split(string(content), "\n")
+	remove := map[int]bool{}
+	for _, it := range items {
+		if it.Line >= len(lines) {
+			return fmt.Errorf("%s:%d no longer holds %q", path, it.Line+1, it.Text)
+		}
+
+		if text, ok := item(lines[it.Line]); !ok || text != it.Text {
+			return fmt.Errorf("%s:%d no longer holds %q", path, it.Line+1, it.Text)
+		}
+		remove[it.Line] = true
+	}
+
+	kept := []string{}
+	for i, line := range lines {
+		if !remove[i] {
+			kept = append(kept, line)
+		}
+	}
+
+	return note.Save(path, []byte(strings.Join(kept, "\n")))
+}
+
+// item returns the text of a top level list item, without any checkbox.
+func item(line string) (string, bool) {
+	if !strings.HasPrefix(line, "- ") && !strings.HasPrefix(line, "* ") {
+		return "", false
+	}
+
+	text := strings.TrimSpace(line[2:])
+	for _, box := range []string{"[ ] ", "[x] ", "[X] "} {
+		text = strings.TrimPrefix(text, box)
+	}
+
+	return text, text != ""
+}
@@ -0,0 +1,156 @@
+package link
+
+import (
+	"path/filepath"
+	"regexp"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+var wikilink = regexp.MustCompile(`\[\[([^\]|#]+)(#[^\]|]*)?(\|[^\]]*)?\]\]`)
+
+// Link is a `[[wikilink]]` in a note. Target excludes any `#heading` or
+// `|alias` part. Line is zero-indexed.
+type Link struct {
+	Target string
+	Line   int
+}
+
+// To is the wikilink to the note at path.
+func To(path string) string {
+	return "[[" + strings.TrimSuffix(filepath.Base(path), ".md") + "]]"
+}
+
+func Wikilinks(content string) []Link {
+	links := []Link{}
+
+	for i, line := range strings.Split(content, "\n") {
+		for _, match := range wikilink.FindAllStringSubmatch(line, -1) {
+			links = append(links, Link{Target: strings.TrimSpace(match[1]), Line: i})
+		}
+	}
+
+	return links
+}
+
+// MarkdownLinks finds each relative `[text](path)` link in the content. Target
+// excludes any `#heading` part and is not unescaped.
+func MarkdownLinks(content string) []Link {
+	links := []Link{}
+
+	for i, line := range strings.Split(content, "\n") {
+		for _, match := range markdownLink.FindAllStringSubmatch(line, -1) {
+			target, _ := splitAnchor(match[2])
+			if _, ok := resolveRelative(".", target); ok {
+				links = append(links, Link{Target: target, Line: i})
+			}
+		}
+	}
+
+	return links
+}
+
+// Relative returns the file a relative markdown link in a note in dir points
+// at.
+func Relative(dir, target string) (string, bool) {
+	return resolveRelative(dir, target)
+}
+
+// Replace changes the target of the wikilinks and markdown links to target on
+// the given line of content, keeping their headings and aliases.
+func Replace(content string, line int, target, replacement string) string {
+	lines := strings.Split(content, "\n")
+	if line < 0 || line >= len(lines) {
+		return content
+	}
+
+	lines[line] = wikilink.ReplaceAllStringFunc(lines[line], func(match string) string {
+		parts := wikilink.FindStringSubmatch(match)
+		if strings.TrimSpace(parts[1]) != target {
+			return match
+		}
+		return "[[" + replacement + parts[2] + parts[3] + "]]"
+	})
+
+	lines[line] = markdownLink.ReplaceAllStringFunc(lines[line], func(match string) string {
+		parts := markdownLink.FindStringSubmatch(match)
+		if t, anchor := splitAnchor(parts[2]); t == target {
+			return parts[1] + replacement + anchor + parts[3]
+		}
+		return match
+	})
+
+	return strings.Join(lines, "\n")
+}
+
+// Resolver finds the note a wikilink points at, either by its file name or by
+// its path from the notebook root, ignoring case and the `.md` extension, or by
+// the `id` in its frontmatter.
+type Resolver struct {
+	byName map[string][]string
+	byPath map[string]string
+	byID   map[string]string
+}
+
+func NewResolver(root string, paths []string) *Resolver {
+	r := &Resolver{byName: map[string][]string{}, byPath: map[string]string{}, byID: map[string]string{}}
+
+	for _, path := range paths {
+		r.byName[key(filepath.Base(path))] = append(r.byName[key(filepath.Base(path))], path)
+
+		if rel, err := filepath.Rel(root, path); err == nil {
+			r.byPath[key(filepath.ToSlash(rel))] = path
+		}
+
+		if n, err := note.Read(path); err == nil {
+			if id := n.Frontmatter.String("id"); id != "" {
+				r.byID[key(id)] = path
+			}
+		}
+	}
+
+	return r
+}
+
+func (r *Resolver) Resolve(target string) (string, bool) {
+	if path, ok := r.byPath[key(target)]; ok {
+		return path, true
+	}
+
+	if path, ok := r.byID[key(target)]; ok {
+		return path, true
+	}
+
+	if paths := r.byName[key(target)]; len(paths) > 0 {
+		return paths[0], true
+	}
+
+	return "", false
+}
+
+// IsID reports whether the target is the id of a note rather than its name or
+// path. Links by id keep working when the note is renamed or moved.
+func (r *Resolver) IsID(target string) bool {
+	_, ok := r.byID[key(target)]
+	return ok
+}
+
+func key(name string) string {
+	return strings.ToLower(strings.TrimSuffix(name, ".md"))
+}
+
+var mention = regexp.MustCompile(`(^|[^\w@])@([\w][\w.-]*)`)
+
+// Mentions finds each `@Name` in the content, skipping email addresses.
+func Mentions(content string) []Link {
+	mentions := []Link{}
+
+	for i, line := range strings.Split(content, "\n") {
+		for _, match := range mention.FindAllStringSubmatch(line, -1) {
+			mentions = append(mentions, Link{Target: strings.TrimRight(match[2], ".-"), Line: i})
+		}
+	}
+
+	return mentions
+}
@@ -0,0 +1,113 @@
+package link
+
+import (
+	"net/url"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+var markdownLink = regexp.MustCompile(`(\]\()([^)\s]+)(\))`)
+
+// Retarget rewrites the links in content that point at the note at oldPath so
+// that they point at newPath. Relative markdown links are resolved from dir,
+// the folder the note containing them lives in. Wikilinks keep their style: a
+// link by path stays a path from root, a link by name stays a name, and a link
+// by id is left alone. Headings and aliases are kept. It returns the new content and the number of links
+// that changed.
+func Retarget(content, root, dir, oldPath, newPath string, r *Resolver) (string, int) {
+	changed := 0
+
+	content = wikilink.ReplaceAllStringFunc(content, func(match string) string {
+		parts := wikilink.FindStringSubmatch(match)
+
+		target := strings.TrimSpace(parts[1])
+		if path, ok := r.Resolve(target); !ok || path != oldPath || r.IsID(target) {
+			return match
+		}
+
+		name := strings.TrimSuffix(filepath.Base(newPath), ".md")
+		if strings.Contains(target, "/") {
+			if rel, err := filepath.Rel(root, newPath); err == nil {
+				name = strings.TrimSuffix(filepath.ToSlash(rel), ".md")
+			}
+		}
+
+		changed++
+		return "[[" + name + parts[2] + parts[3] + "]]"
+	})
+
+	content = markdownLink.ReplaceAllStringFunc(content, func(match string) string {
+		parts := markdownLink.FindStringSubmatch(match)
+
+		target, anchor := splitAnchor(parts[2])
+		path, ok := resolveRelative(dir, target)
+		if !ok || path != oldPath {
+			return match
+		}
+
+		changed++
+		return parts[1] + relative(dir, newPath, target) + anchor + parts[3]
+	})
+
+	return content, changed
+}
+
+// Rebase rewrites the relative markdown links in content, written from oldDir,
+// so they point at the same files from newDir.
+func Rebase(content, oldDir, newDir string) string {
+	if filepath.Clean(oldDir) == filepath.Clean(newDir) {
+		return content
+	}
+
+	return markdownLink.ReplaceAllStringFunc(content, func(match string) string {
+		parts := markdownLink.FindStringSubmatch(match)
+
+		target, anchor := splitAnchor(parts[2])
+		path, ok := resolveRelative(oldDir, target)
+		if !ok {
+			return match
+		}
+
+		return parts[1] + relative(newDir, path, target) + anchor + parts[3]
+	})
+}
+
+func splitAnchor(target string) (string, string) {
+	if i := strings.Index(target, "#"); i >= 0 {
+		return target[:i], target[i:]
+	}
+
+	return target, ""
+}
+
+// resolveRelative returns the file a relative link points at. Absolute paths,
+// URLs, and links within the same note are not relative links.
+func resolveRelative(dir, target string) (string, bool) {
+	if target == "" || strings.HasPrefix(target, "/") || strings.Contains(target, ":") {
+		return "", false
+	}
+
+	unescaped, err := url.PathUnescape(target)
+	if err != nil {
+		return "", false
+	}
+
+	return filepath.Join(dir, filepath.FromSlash(unescaped)), true
+}
+
+// relative is the link from dir to path, escaping spaces like the original
+// link did.
+func relative(dir, path, original string) string {
+	rel, err := filepath.Rel(dir, path)
+	if err != nil {
+		rel = path
+	}
+	rel = filepath.ToSlash(rel)
+
+	if strings.Contains(original, "%20") {
+		rel = strings.ReplaceAll(rel, " ", "%20")
+	}
+
+	return rel
+}
@@ -0,0 +1,29 @@
+package link
+
+import (
+	"testing"
+)
+
+func TestRetarget(t *testing.T) {
+	resolver := NewResolver("/nb", []string{"/nb/A.md", "/nb/C.md"})
+
+	given := "See [[A#Intro|a]] and [a](A.md#x), not [[Other]] or [site](https://a.md)."
+	expected := "See [[B#Intro|a]] and [a](Projects/B.md#x), not [[Other]] or [site](https://a.md)."
+
+	actual, changed := Retarget(given, "/nb", "/nb", "/nb/A.md", "/nb/Projects/B.md", resolver)
+
+	if expected != actual || changed != 2 {
+		t.Fatalf("given: %s\nexpected: %s (2 links)\nactual: %s (%d links)\n", given, expected, actual, changed)
+	}
+}
+
+func TestReplace(t *testing.T) {
+	given := "Intro\nSee [[Nte#Top|the note]] and [it](Nte.md#x), [[Other]]."
+	expected := "Intro\nSee [[Note#Top|the note]] and [it](Nte.md#x), [[Other]]."
+
+	actual := Replace(given, 1, "Nte", "Note")
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
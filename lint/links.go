@@ -0,0 +1,126 @@
+package lint
+
+import (
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/task"
+)
+
+// DeadLink is a wikilink or relative markdown link whose target does not
+// exist.
+type DeadLink struct {
+	Path     string
+	Line     int
+	Target   string
+	Markdown bool
+}
+
+// DeadLinks finds the wikilinks that match no note and the relative markdown
+// links that point at no file, in order of path and line. Lines are
+// zero-indexed.
+func (nb *Notebook) DeadLinks() []DeadLink {
+	dead := []DeadLink{}
+
+	for _, doc := range nb.documents {
+		for _, l := range link.Wikilinks(doc.content) {
+			if _, ok := nb.resolver.Resolve(l.Target); !ok {
+				dead = append(dead, DeadLink{Path: doc.path, Line: l.Line, Target: l.Target})
+			}
+		}
+
+		for _, l := range link.MarkdownLinks(doc.content) {
+			path, ok := link.Relative(filepath.Dir(doc.path), l.Target)
+			if !ok {
+				continue
+			}
+
+			if _, err := os.Stat(path); os.IsNotExist(err) {
+				dead = append(dead, DeadLink{Path: doc.path, Line: l.Line, Target: l.Target, Markdown: true})
+			}
+		}
+	}
+
+	sort.SliceStable(dead, func(i, j int) bool {
+		if dead[i].Path != dead[j].Path {
+			return dead[i].Path < dead[j].Path
+		}
+		return dead[i].Line < dead[j].Line
+	})
+
+	return dead
+}
+
+// Suggest returns up to n replacement targets for a dead link, most similar
+// first. Wikilinks are suggested note names, or paths from the root when the
+// link was a path; markdown links are suggested paths relative to the note.
+func (nb *Notebook) Suggest(d DeadLink, n int) []string {
+	type candidate struct {
+		target string
+		score  float64
+	}
+	candidates := []candidate{}
+
+	wanted := task.Normalize(strings.TrimSuffix(filepath.Base(filepath.FromSlash(d.Target)), ".md"))
+	for _, doc := range nb.documents {
+		name := strings.TrimSuffix(filepath.Base(doc.path), ".md")
+
+		score := task.Similarity(wanted, task.Normalize(name))
+		if score < 0.5 {
+			continue
+		}
+
+		target := name
+		switch {
+		case d.Markdown:
+			rel, err := filepath.Rel(filepath.Dir(d.Path), doc.path)
+			if err != nil {
+				continue
+			}
+			target = filepath.ToSlash(rel)
+			if strings.Contains(d.Target, "%20") {
+				target = strings.ReplaceAll(target, " ", "%20")
+			}
+		case strings.Contains(d.Target, "/"):
+			rel, err := filepath.Rel(nb.Root, doc.path)
+			if err != nil {
+				continue
+			}
+			target = strings.TrimSuffix(filepath.ToSlash(rel), ".md")
+		}
+
+		candidates = append(candidates, candidate{target, score})
+	}
+
+	sort.SliceStable(candidates, func(i, j int) bool { return candidates[i].score > candidates[j].score })
+
+	suggestions := []string{}
+	for _, c := range candidates {
+		if len(suggestions) == n {
+			break
+		}
+		suggestions = append(suggestions, c.target)
+	}
+
+	return suggestions
+}
+
+func brokenLinks(nb *Notebook) []Problem {
+	problems := []Problem{}
+
+	for _, d := range nb.DeadLinks() {
+		if d.Markdown {
+			problems = append(problems, Problem{
+				Rule:    "broken-link",
+				Path:    d.Path,
+				Line:    d.Line + 1,
+				Message: "no file at " + d.Target,
+			})
+		}
+	}
+
+	return problems
+}
@@ -0,0 +1,212 @@
+package lint
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+)
+
+// Problem is a single finding of a rule.
+type Problem struct {
+	Rule    string `json:"rule"`
+	Path    string `json:"path"`
+	Line    int    `json:"line,omitempty"`
+	Message string `json:"message"`
+}
+
+func (p Problem) String() string {
+	if p.Line == 0 {
+		return fmt.Sprintf("%s: [%s] %s", p.Path, p.Rule, p.Message)
+	}
+	return fmt.Sprintf("%s:%d: [%s] %s", p.Path, p.Line, p.Rule, p.Message)
+}
+
+type document struct {
+	path    string
+	content string
+	note    *note.Note
+	err     error
+}
+
+// Notebook is what every rule is checked against.
+type Notebook struct {
+	Root   string
+	LogDir string
+
+	documents []document
+	resolver  *link.Resolver
+}
+
+type Rule struct {
+	Name  string
+	Check func(nb *Notebook) []Problem
+}
+
+var Rules = []Rule{
+	{"broken-wikilink", brokenWikilinks},
+	{"broken-link", brokenLinks},
+	{"duplicate-title", duplicateTitles},
+	{"malformed-frontmatter", malformedFrontmatter},
+	{"template-placeholder", templatePlaceholders},
+	{"log-filename", logFilenames},
+}
+
+func Load(root, logDir string) (*Notebook, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	nb := &Notebook{Root: root, LogDir: logDir, resolver: link.NewResolver(root, paths)}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		n, err := note.Parse(string(content))
+		if n != nil {
+			n.Path = path
+		}
+		nb.documents = append(nb.documents, document{path, string(content), n, err})
+	}
+
+	return nb, nil
+}
+
+// Run checks every rule that is not disabled and returns the problems sorted
+// by path and line.
+func Run(nb *Notebook, disabled []string) []Problem {
+	skip := map[string]bool{}
+	for _, name := range disabled {
+		skip[name] = true
+	}
+
+	problems := []Problem{}
+	for _, rule := range Rules {
+		if !skip[rule.Name] {
+			problems = append(problems, rule.Check(nb)...)
+		}
+	}
+
+	sort.SliceStable(problems, func(i, j int) bool {
+		if problems[i].Path != problems[j].Path {
+			return problems[i].Path < problems[j].Path
+		}
+		return problems[i].Line < problems[j].Line
+	})
+
+	return problems
+}
+
+func brokenWikilinks(nb *Notebook) []Problem {
+	problems := []Problem{}
+
+	for _, doc := range nb.documents {
+		for _, l := range link.Wikilinks(doc.content) {
+			if _, ok := nb.resolver.Resolve(l.Target); !ok {
+				problems = append(problems, Problem{
+					Rule:    "broken-wikilink",
+					Path:    doc.path,
+					Line:    l.Line + 1,
+					Message: fmt.Sprintf("no note matches [[%s]]", l.Target),
+				})
+			}
+		}
+	}
+
+	return problems
+}
+
+func duplicateTitles(nb *Notebook) []Problem {
+	byTitle := map[string][]string{}
+	for _, doc := range nb.documents {
+		if doc.note != nil {
+			title := doc.note.Title()
+			byTitle[title] = append(byTitle[title], doc.path)
+		}
+	}
+
+	problems := []Problem{}
+	for title, paths := range byTitle {
+		if len(paths) < 2 {
+			continue
+		}
+
+		for _, path := range paths {
+			problems = append(problems, Problem{
+				Rule:    "duplicate-title",
+				Path:    path,
+				Message: fmt.Sprintf("%q is also the title of %d other notes", title, len(paths)-1),
+			})
+		}
+	}
+
+	return problems
+}
+
+func malformedFrontmatter(nb *Notebook) []Problem {
+	problems := []Problem{}
+
+	for _, doc := range nb.documents {
+		if doc.err != nil {
+			problems = append(problems, Problem{
+				Rule:    "malformed-frontmatter",
+				Path:    doc.path,
+				Line:    1,
+				Message: doc.err.Error(),
+			})
+		}
+	}
+
+	return problems
+}
+
+var placeholder = regexp.MustCompile(`\{\{[^}]*\}\}`)
+
+func templatePlaceholders(nb *Notebook) []Problem {
+	problems := []Problem{}
+
+	for _, doc := range nb.documents {
+		for i, line := range strings.Split(doc.content, "\n") {
+			for _, match := range placeholder.FindAllString(line, -1) {
+				problems = append(problems, Problem{
+					Rule:    "template-placeholder",
+					Path:    doc.path,
+					Line:    i + 1,
+					Message: fmt.Sprintf("unresolved placeholder %s", match),
+				})
+			}
+		}
+	}
+
+	return problems
+}
+
+func logFilenames(nb *Notebook) []Problem {
+	problems := []Problem{}
+
+	for _, doc := range nb.documents {
+		if filepath.Dir(doc.path) != filepath.Clean(nb.LogDir) {
+			continue
+		}
+
+		name := strings.TrimSuffix(filepath.Base(doc.path), ".md")
+		if _, err := time.Parse(note.DateFormat, name); err != nil {
+			problems = append(problems, Problem{
+				Rule:    "log-filename",
+				Path:    doc.path,
+				Message: "log file names should be YYYY-MM-DD.md",
+			})
+		}
+	}
+
+	return problems
+}
@@ -0,0 +1,18 @@
+//go:build !windows
+// +build !windows
+
+package lock
+
+import (
+	"golang.org/x/sys/unix"
+)
+
+// alive reports whether a process with the pid is running.
+func alive(pid int) bool {
+	if pid <= 0 {
+		return false
+	}
+
+	err := unix.Kill(pid, 0)
+	return err == nil || err == unix.EPERM
+}
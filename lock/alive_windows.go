@@ -0,0 +1,24 @@
+//go:build windows
+// +build windows
+
+package lock
+
+import (
+	"os"
+)
+
+// alive reports whether a process with the pid is running. Finding a process
+// on Windows opens it, which fails once it has exited.
+func alive(pid int) bool {
+	if pid <= 0 {
+		return false
+	}
+
+	p, err := os.FindProcess(pid)
+	if err != nil {
+		return false
+	}
+	p.Release()
+
+	return true
+}
@@ -0,0 +1,114 @@
+package lock
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+)
+
+// Stale is how old a lock can get before it is taken to be left behind by a
+// process that did not clean up. Writes to a note take far less.
+const Stale = time.Minute
+
+// Timeout is how long Acquire waits for a lock held by another process.
+var Timeout = 10 * time.Second
+
+// Lock is an advisory lock on a file, held by this process until Release.
+type Lock struct {
+	path string
+}
+
+// Dir is where lock files are kept. They only have to be seen by processes on
+// this machine, so they stay out of the notebook and its sync, in the data
+// directory of the notebook, which belongs to the user running nb.
+func Dir() string {
+	if root, err := config.GetRootDir(); err == nil {
+		return filepath.Join(config.DataDir(root), "locks")
+	}
+
+	if cache, err := os.UserCacheDir(); err == nil {
+		return filepath.Join(cache, "nb", "locks")
+	}
+
+	return filepath.Join(os.TempDir(), fmt.Sprintf("nb-locks-%d", os.Getuid()))
+}
+
+// Acquire locks the file at path against other nb processes, waiting for a
+// lock held by another process to be released. A lock whose process is gone,
+// or that is older than Stale, is broken.
+func Acquire(path string) (*Lock, error) {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return nil, err
+	}
+	sum := sha1.Sum([]byte(abs))
+	l := &Lock{path: filepath.Join(Dir(), hex.EncodeToString(sum[:])+".lock")}
+
+	if err := os.MkdirAll(Dir(), 0700); err != nil {
+		return nil, err
+	}
+
+	deadline := time.Now().Add(Timeout)
+	for {
+		f, err := os.OpenFile(l.path, os.O_CREATE|os.O_EXCL|os.O_WRONLY, 0644)
+		if err == nil {
+			_, err = fmt.Fprintf(f, "%d\n%s\n", os.Getpid(), time.Now().Format(time.RFC3339))
+			f.Close()
+			return l, err
+		}
+		if !os.IsExist(err) {
+			return nil, err
+		}
+
+		pid, stale := l.holder()
+		if stale {
+			os.Remove(l.path)
+			continue
+		}
+
+		if time.Now().After(deadline) {
+			return nil, fmt.Errorf("%s is locked by process %d", path, pid)
+		}
+		time.Sleep(50 * time.Millisecond)
+	}
+}
+
+// Release gives up the lock.
+func (l *Lock) Release() error {
+	err := os.Remove(l.path)
+	if os.IsNotExist(err) {
+		return nil
+	}
+
+	return err
+}
+
+// holder reads the process holding the lock and whether the lock is stale.
+func (l *Lock) holder() (int, bool) {
+	content, err := os.ReadFile(l.path)
+	if os.IsNotExist(err) {
+		return 0, false
+	}
+
+	fields := strings.Fields(string(content))
+	if err != nil || len(fields) < 2 {
+		// The holder may be between creating and writing the file.
+		info, err := os.Stat(l.path)
+		return 0, err == nil && time.Since(info.ModTime()) > Stale
+	}
+
+	pid, _ := strconv.Atoi(fields[0])
+	since, err := time.Parse(time.RFC3339, fields[1])
+	if err != nil || time.Since(since) > Stale {
+		return pid, true
+	}
+
+	return pid, !alive(pid)
+}
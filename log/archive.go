@@ -0,0 +1,86 @@
+package log
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+// ArchiveDir is the folder that archived logs are moved into, grouped by year.
+func ArchiveDir(logDir string) string {
+	return filepath.Join(logDir, logpath.Archive)
+}
+
+// Archive moves every log dated before the given day into the archive and
+// returns the new paths. Existing files in the archive are never overwritten.
+func Archive(logDir string, before time.Time) ([]string, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return nil, err
+	}
+
+	cutoff := before.Format(note.DateFormat)
+	moved := []string{}
+	for _, path := range paths {
+		date, ok := logpath.Date(logDir, path, time.Local)
+		if !ok || date.Format(note.DateFormat) >= cutoff {
+			continue
+		}
+
+		dest := logpath.Archived(logDir, date)
+		if err := os.MkdirAll(filepath.Dir(dest), 0755); err != nil {
+			return moved, err
+		}
+
+		if _, err := os.Stat(dest); err == nil {
+			return moved, fmt.Errorf("%s already exists", dest)
+		}
+
+		if err := os.Rename(path, dest); err != nil {
+			return moved, err
+		}
+		moved = append(moved, dest)
+	}
+
+	return moved, nil
+}
+
+// IsArchived reports whether path is inside the archive of logDir.
+func IsArchived(logDir, path string) bool {
+	rel, err := filepath.Rel(ArchiveDir(logDir), path)
+	return err == nil && !strings.HasPrefix(rel, "..")
+}
+
+// Find returns the path of the log for the given date, looking in the archive
+// when it is not in the log folder.
+func Find(date time.Time) (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+
+	if path, ok := locate(dir, date); ok {
+		return path, nil
+	}
+
+	return PathFor(date)
+}
+
+// locate finds the log for the given date in logDir or its archive.
+func locate(logDir string, date time.Time) (string, bool) {
+	for _, path := range []string{
+		logpath.For(logDir, date),
+		logpath.Archived(logDir, date),
+	} {
+		if _, err := os.Stat(path); err == nil {
+			return path, true
+		}
+	}
+
+	return "", false
+}
@@ -3,20 +3,87 @@ package log
 import (
 	"fmt"
 	"os"
+	"path/filepath"
+	"strings"
 	"time"
 
+	"github.com/t-eckert/nb/calendar"
 	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	"github.com/t-eckert/nb/habit"
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/placeholder"
+	"github.com/t-eckert/nb/task"
 )
 
-func LogPath(dayOffset int) (string, error) {
+func Dir() (string, error) {
 	root, err := config.GetRootDir()
 	if err != nil {
 		return "", err
 	}
 
-	today := formatDate(time.Now().Add(time.Duration(dayOffset) * 24 * time.Hour))
+	return filepath.Join(root, config.LogFolder()), nil
+}
+
+func LogPath(dayOffset int) (string, error) {
+	return PathFor(time.Now().Add(time.Duration(dayOffset) * 24 * time.Hour))
+}
+
+// PathFor returns the path of the log for the given date.
+func PathFor(date time.Time) (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+
+	return logpath.For(dir, date), nil
+}
+
+// Ensure returns the path of the log for the given day, generating it first
+// if it does not exist yet. With `log.auto_rollover`, a newly generated log for
+// today gets the open tasks of the previous log.
+func Ensure(dayOffset int) (string, error) {
+	logPath, err := LogPath(dayOffset)
+	if err != nil {
+		return "", err
+	}
+
+	logExists, err := DoesLogExist(logPath)
+	if err != nil {
+		return "", err
+	}
+
+	if logExists {
+		return logPath, nil
+	}
+
+	if err := os.MkdirAll(filepath.Dir(logPath), 0755); err != nil {
+		return "", err
+	}
+
+	if err := hook.Run(hook.PreLogCreate, logPath); err != nil {
+		return "", err
+	}
+
+	if err := GenerateNew(logPath, dayOffset); err != nil {
+		return "", err
+	}
+
+	if dayOffset == 0 && config.LogAutoRollover() {
+		logDir, err := Dir()
+		if err != nil {
+			return "", err
+		}
+
+		if _, err := Rollover(logDir, logPath, time.Now()); err != nil {
+			return "", err
+		}
+	}
 
-	return root + "/Log/" + today + ".md", nil
+	return logPath, hook.Run(hook.PostLogCreate, logPath)
 }
 
 func DoesLogExist(logPath string) (bool, error) {
@@ -33,28 +100,178 @@ func DoesLogExist(logPath string) (bool, error) {
 	return false, nil
 }
 
+// Template is the content of a new log. Its placeholders are filled in by
+// GenerateNew.
+const Template = `# {{title}}
+
+{{schedule}}## Tasks
+
+
+{{habits}}{{onthisday}}{{elsewhere}}`
+
 func GenerateNew(logPath string, dayOffset int) error {
-	f, err := os.Create(logPath)
+	date := time.Now().Add(time.Duration(dayOffset) * 24 * time.Hour)
+	habits := habit.Checklist(config.Habits())
+
+	logDir, err := Dir()
 	if err != nil {
 		return err
 	}
 
-	defer f.Close()
+	onThisDay := ""
+	if config.LogOnThisDay() {
+		onThisDay = onThisDayLinks(logDir, date)
+	}
 
-	template := fmt.Sprintf(`# %s 
+	elsewhere := ""
+	if config.LogScheduledElsewhere() {
+		elsewhere = scheduledElsewhere(logDir, date)
+	}
 
-## Tasks
+	separate(&habits, &onThisDay, &elsewhere)
 
+	template, err := logTemplate()
+	if err != nil {
+		return err
+	}
 
-`, formateDateTitle(time.Now().Add(time.Duration(dayOffset)*24*time.Hour)))
+	n, err := placeholder.Note(template, config.TemplateVars(), map[string]string{
+		"title":     formateDateTitle(date),
+		"date":      formatDate(date),
+		"schedule":  schedule(date),
+		"habits":    habits,
+		"onthisday": onThisDay,
+		"elsewhere": elsewhere,
+	}, date, time.Now())
+	if err != nil {
+		return err
+	}
+
+	// Templates with frontmatter get the fields of the log merged into it.
+	if len(n.Frontmatter) > 0 {
+		n.Frontmatter.Merge(note.Frontmatter{
+			{Key: "title", Value: formateDateTitle(date)},
+			{Key: "date", Value: date.Format(note.DateFormat)},
+		})
+	}
+	n.Path = logPath
 
-	_, err = f.WriteString(template)
+	return n.Write()
+}
 
+// logTemplate is the template set with `log.template`, or Template.
+func logTemplate() (string, error) {
+	path := config.LogTemplate()
+	if path == "" {
+		return Template, nil
+	}
+
+	if !filepath.IsAbs(path) {
+		root, err := config.GetRootDir()
+		if err != nil {
+			return "", err
+		}
+		path = filepath.Join(root, path)
+	}
+
+	content, err := os.ReadFile(path)
 	if err != nil {
-		return err
+		return "", fmt.Errorf("could not read the log template: %w", err)
+	}
+
+	return string(content), nil
+}
+
+// separate puts a blank line before each section that follows another.
+func separate(sections ...*string) {
+	previous := false
+	for _, s := range sections {
+		if *s == "" {
+			continue
+		}
+
+		if previous {
+			*s = "\n" + *s
+		}
+		previous = true
 	}
+}
+
+// ScheduledElsewhereSection is the heading of the tasks from other notes that
+// are due, added to new logs when `log.scheduled_elsewhere` is set.
+const ScheduledElsewhereSection = "Scheduled Elsewhere"
+
+// scheduledElsewhere lists the open tasks outside the logs that are due on or
+// before the day, linked to the notes they are in, or nothing when there are
+// none.
+func scheduledElsewhere(logDir string, day time.Time) string {
+	root, err := config.GetRootDir()
+	if err != nil {
+		return ""
+	}
+
+	tasks, err := task.FromNotebook(root)
+	if err != nil {
+		return ""
+	}
+
+	lines := []string{}
+	for _, t := range task.Open(task.Latest(tasks)) {
+		if strings.HasPrefix(t.Path, filepath.Clean(logDir)+string(filepath.Separator)) {
+			continue
+		}
+
+		if due, ok := t.Due(); ok && due.Format(note.DateFormat) <= day.Format(note.DateFormat) {
+			lines = append(lines, "- "+t.Text+" ("+link.To(t.Path)+")")
+		}
+	}
+
+	if len(lines) == 0 {
+		return ""
+	}
+
+	return "## " + ScheduledElsewhereSection + "\n\n" + strings.Join(lines, "\n") + "\n"
+}
+
+// ScheduleSection is the heading of the events pulled from the calendar set
+// with `integrations.calendar.url`.
+const ScheduleSection = "Schedule"
+
+// schedule is the Schedule section of a new log, or nothing when no calendar
+// is configured, nb is offline, or it has no events that day. A calendar that
+// cannot be reached is reported but does not stop the log from being created.
+func schedule(date time.Time) string {
+	url := config.CalendarURL()
+	if url == "" || config.Offline() {
+		return ""
+	}
+
+	username, password := config.CalendarCredentials()
+	events, err := calendar.Fetch(url, username, password)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "could not fetch calendar: %v\n", err)
+		return ""
+	}
+
+	today := calendar.On(events, date)
+	if len(today) == 0 {
+		return ""
+	}
+
+	return "## " + ScheduleSection + "\n\n" + calendar.Schedule(today) + "\n\n"
+}
+
+// AppendTask adds an open task to the end of the Tasks section of a log.
+func AppendTask(logPath string, text string) error {
+	return AppendTasks(logPath, []string{"- [ ] " + text})
+}
 
-	return nil
+// AppendTasks adds checkbox lines, such as a task and its subtasks, to the
+// Tasks section of the log.
+func AppendTasks(logPath string, lines []string) error {
+	return note.Update(logPath, func(content string) (string, error) {
+		return note.AppendToSection(content, "Tasks", strings.Join(lines, "\n")), nil
+	})
 }
 
 func formatDate(t time.Time) string {
@@ -62,5 +279,5 @@ func formatDate(t time.Time) string {
 }
 
 func formateDateTitle(t time.Time) string {
-	return t.Format("2 Jan 2006")
+	return date.Format(t, "2 Jan 2006", config.Locale())
 }
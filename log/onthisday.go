@@ -0,0 +1,59 @@
+package log
+
+import (
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/logpath"
+)
+
+// OnThisDaySection is the heading of the links to earlier logs added to new
+// logs when `log.on_this_day` is set.
+const OnThisDaySection = "On This Day"
+
+// OnThisDay returns the logs written on the same date in earlier years, most
+// recent first, including archived logs. With months, the logs from one,
+// three, and six months before are added after them.
+func OnThisDay(logDir string, day time.Time, months bool) ([]string, error) {
+	logs, err := logpath.List(logDir, true)
+	if err != nil {
+		return nil, err
+	}
+
+	paths := []string{}
+	for i := len(logs) - 1; i >= 0; i-- {
+		date, ok := logpath.Date(logDir, logs[i], time.Local)
+		if !ok || date.Year() >= day.Year() || date.Month() != day.Month() || date.Day() != day.Day() {
+			continue
+		}
+		paths = append(paths, logs[i])
+	}
+
+	if months {
+		for _, m := range []int{1, 3, 6} {
+			if path, ok := locate(logDir, day.AddDate(0, -m, 0)); ok {
+				paths = append(paths, path)
+			}
+		}
+	}
+
+	return paths, nil
+}
+
+// onThisDayLinks is the section linking to the logs from this date in earlier
+// years, or nothing when there are none.
+func onThisDayLinks(logDir string, day time.Time) string {
+	paths, err := OnThisDay(logDir, day, false)
+	if err != nil || len(paths) == 0 {
+		return ""
+	}
+
+	var b strings.Builder
+	b.WriteString("## " + OnThisDaySection + "\n\n")
+	for _, path := range paths {
+		b.WriteString("- " + link.To(path) + "\n")
+	}
+
+	return b.String()
+}
@@ -0,0 +1,94 @@
+package log
+
+import (
+	"errors"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+// Previous returns the path of the most recent log dated before day, or false
+// when there is none.
+func Previous(logDir string, day time.Time) (string, bool, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return "", false, err
+	}
+
+	for i := len(paths) - 1; i >= 0; i-- {
+		date, ok := logpath.Date(logDir, paths[i], day.Location())
+		if ok && date.Format(note.DateFormat) < day.Format(note.DateFormat) {
+			return paths[i], true, nil
+		}
+	}
+
+	return "", false, nil
+}
+
+// errNothingCarried stops a rollover without touching the log when it already
+// has every open task.
+var errNothingCarried = errors.New("nothing to carry over")
+
+// Rollover carries the open tasks in the Tasks section of the most recent log
+// before day, with their open subtasks, over to the log at logPath, most urgent
+// first. Each carried task counts the rollover in a `rolled:N` token. Tasks the
+// log already has are left out, checked with the log locked so that rollovers
+// that overlap do not both carry them. It returns how many tasks were carried
+// over.
+func Rollover(logDir, logPath string, day time.Time) (int, error) {
+	previous, ok, err := Previous(logDir, day)
+	if err != nil || !ok {
+		return 0, err
+	}
+
+	content, err := os.ReadFile(previous)
+	if err != nil {
+		return 0, err
+	}
+
+	tasks := task.Parse(previous, note.Section(string(content), "Tasks"))
+
+	open := []task.Task{}
+	for _, t := range tasks {
+		if !t.Done && t.Parent < 0 {
+			open = append(open, t)
+		}
+	}
+	task.ByPriority(open)
+
+	carried := 0
+	err = note.Update(logPath, func(current string) (string, error) {
+		present := map[string]bool{}
+		for _, t := range task.Parse(logPath, current) {
+			present[t.ID()] = true
+		}
+
+		lines := []string{}
+		for _, t := range open {
+			if present[t.ID()] {
+				continue
+			}
+
+			lines = append(lines, task.Lines(task.RolledOver(t), t, task.Subtasks(tasks, t))...)
+			carried++
+		}
+
+		if carried == 0 {
+			return "", errNothingCarried
+		}
+
+		return note.AppendToSection(current, "Tasks", strings.Join(lines, "\n")), nil
+	})
+	if errors.Is(err, errNothingCarried) {
+		return 0, nil
+	} else if err != nil {
+		return 0, err
+	}
+
+	return carried, hook.Run(hook.PostRollover, logPath)
+}
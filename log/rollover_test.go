@@ -0,0 +1,136 @@
+package log
+
+import (
+	"os"
+	"testing"
+	"time"
+
+	"github.com/spf13/viper"
+	"github.com/t-eckert/nb/logpath"
+)
+
+func writeLogs(t *testing.T, logDir string, logs map[string]string) {
+	t.Helper()
+
+	for date, content := range logs {
+		day, err := time.ParseInLocation("2006-01-02", date, time.Local)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(logpath.For(logDir, day), []byte(content), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+}
+
+func TestPrevious(t *testing.T) {
+	logDir := t.TempDir()
+	writeLogs(t, logDir, map[string]string{
+		"2018-07-10": "# 2018-07-10\n",
+		"2018-07-12": "# 2018-07-12\n",
+		"2018-07-15": "# 2018-07-15\n",
+	})
+
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"2018-07-14", "2018-07-12"},
+		{"2018-07-12", "2018-07-10"},
+		{"2018-07-11", "2018-07-10"},
+		{"2018-07-10", ""},
+	}
+
+	for _, c := range cases {
+		day, _ := time.ParseInLocation("2006-01-02", c.given, time.Local)
+		path, ok, err := Previous(logDir, day)
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		actual := ""
+		if ok {
+			date, _ := logpath.Date(logDir, path, time.Local)
+			actual = date.Format("2006-01-02")
+		}
+
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestRollover(t *testing.T) {
+	root := t.TempDir()
+	viper.Set("notebook_path", root)
+	viper.Set("data_dir", t.TempDir())
+	t.Cleanup(func() {
+		viper.Set("notebook_path", "")
+		viper.Set("data_dir", "")
+	})
+
+	logDir := root
+	writeLogs(t, logDir, map[string]string{
+		"2018-07-12": `# 2018-07-12
+
+## Tasks
+
+- [ ] Write report
+- [x] Ship release ✅ 2018-07-12
+- [/] Review PR rolled:1
+- [ ] Plan trip
+  - [ ] Book flights
+  - [x] Pick dates
+- [ ] Call Sam
+
+## Notes
+
+- [ ] Left where it is
+`,
+		"2018-07-14": `## Tasks
+
+- [ ] Call Sam
+`,
+	})
+
+	day := time.Date(2018, time.July, 14, 9, 0, 0, 0, time.Local)
+	today := logpath.For(logDir, day)
+	expected := `## Tasks
+
+- [ ] Call Sam
+- [ ] Write report rolled:1
+- [/] Review PR rolled:2
+- [ ] Plan trip rolled:1
+  - [ ] Book flights
+`
+
+	carried, err := Rollover(logDir, today, day)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if carried != 3 {
+		t.Fatalf("given: %s\nexpected: %d\nactual: %d\n", day, 3, carried)
+	}
+
+	actual, err := os.ReadFile(today)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if expected != string(actual) {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", day, expected, actual)
+	}
+
+	// A second rollover finds every task already in the log.
+	carried, err = Rollover(logDir, today, day)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	actual, err = os.ReadFile(today)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if carried != 0 || expected != string(actual) {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", day, expected, actual)
+	}
+}
@@ -0,0 +1,135 @@
+package logpath
+
+import (
+	"io/fs"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+)
+
+// Archive is the folder inside the log folder that archived logs are moved
+// into.
+const Archive = "Archive"
+
+var directives = strings.NewReplacer(
+	"%Y", "2006",
+	"%y", "06",
+	"%m", "01",
+	"%d", "02",
+	"%j", "002",
+	"%B", "January",
+	"%b", "Jan",
+	"%A", "Monday",
+	"%a", "Mon",
+	"%%", "%",
+)
+
+// Layout is the time layout of the path of a log inside the log folder, taken
+// from config.LogPathFormat.
+func Layout() string {
+	format := config.LogPathFormat()
+	folder := filepath.ToSlash(config.LogFolder())
+	if folder != "" && folder != "." {
+		format = strings.TrimPrefix(strings.TrimPrefix(format, folder), "/")
+	}
+
+	return directives.Replace(format)
+}
+
+// archiveLayout is the layout of the path of a log inside the archive. Logs
+// kept flat in the log folder are grouped by year; logs already kept in
+// folders keep them.
+func archiveLayout() string {
+	layout := Layout()
+	if !strings.Contains(layout, "/") {
+		return "2006/" + layout
+	}
+
+	return layout
+}
+
+// For is the path of the log for date in logDir.
+func For(logDir string, date time.Time) string {
+	return filepath.Join(logDir, filepath.FromSlash(date.Format(Layout())))
+}
+
+// Archived is the path the log for date has once it is archived.
+func Archived(logDir string, date time.Time) string {
+	return filepath.Join(logDir, Archive, filepath.FromSlash(date.Format(archiveLayout())))
+}
+
+// Date is the day of the log at path in logDir, archived or not, in loc. It is
+// false when path is not a log.
+func Date(logDir, path string, loc *time.Location) (time.Time, bool) {
+	rel, err := filepath.Rel(logDir, path)
+	if err != nil || strings.HasPrefix(rel, "..") {
+		return time.Time{}, false
+	}
+	rel = filepath.ToSlash(rel)
+
+	layout := Layout()
+	if strings.HasPrefix(rel, Archive+"/") {
+		rel = strings.TrimPrefix(rel, Archive+"/")
+		layout = archiveLayout()
+	}
+
+	date, err := time.ParseInLocation(layout, rel, loc)
+	if err != nil {
+		return time.Time{}, false
+	}
+
+	return date, true
+}
+
+// List returns the logs in logDir, oldest first. Archived logs are only
+// included when archived is set.
+func List(logDir string, archived bool) ([]string, error) {
+	type log struct {
+		path string
+		date time.Time
+	}
+	logs := []log{}
+
+	err := filepath.WalkDir(logDir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			if os.IsNotExist(err) && path == logDir {
+				return filepath.SkipDir
+			}
+			return err
+		}
+
+		if d.IsDir() {
+			if path != logDir && strings.HasPrefix(d.Name(), ".") {
+				return filepath.SkipDir
+			}
+			if !archived && path == filepath.Join(logDir, Archive) {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+
+		if date, ok := Date(logDir, path, time.Local); ok {
+			logs = append(logs, log{path, date})
+		}
+
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	sort.SliceStable(logs, func(i, j int) bool {
+		return logs[i].date.Before(logs[j].date)
+	})
+
+	paths := []string{}
+	for _, l := range logs {
+		paths = append(paths, l.path)
+	}
+
+	return paths, nil
+}
@@ -0,0 +1,34 @@
+package logpath
+
+import (
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/spf13/viper"
+)
+
+func TestFor(t *testing.T) {
+	viper.Reset()
+	defer viper.Reset()
+
+	viper.Set("log.path_format", "Journal/%Y/%m/%Y-%m-%d.md")
+	given := time.Date(2025, 3, 4, 0, 0, 0, 0, time.Local)
+	logDir := filepath.Join("Notebook", "Journal")
+
+	cases := map[string]string{
+		For(logDir, given):      filepath.Join(logDir, "2025", "03", "2025-03-04.md"),
+		Archived(logDir, given): filepath.Join(logDir, "Archive", "2025", "03", "2025-03-04.md"),
+	}
+
+	for actual, expected := range cases {
+		if expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given.Format("2006-01-02"), expected, actual)
+		}
+
+		date, ok := Date(logDir, actual, time.Local)
+		if !ok || !date.Equal(given) {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", actual, given.Format("2006-01-02"), date.Format("2006-01-02"))
+		}
+	}
+}
@@ -16,7 +16,13 @@ limitations under the License.
 
 package main
 
-import "github.com/t-eckert/nb/cmd"
+import (
+	// The timezone database is built in for systems without one, such as
+	// Windows, so that the timezone setting works everywhere.
+	_ "time/tzdata"
+
+	"github.com/t-eckert/nb/cmd"
+)
 
 func main() {
 	cmd.Execute()
@@ -0,0 +1,87 @@
+package meeting
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/placeholder"
+	"github.com/t-eckert/nb/task"
+)
+
+// Template is the body of a new meeting note.
+const Template = `# {{title}}
+
+## Agenda
+
+
+## Notes
+
+
+## Actions
+
+`
+
+func Dir(root string) string {
+	return filepath.Join(root, "Meetings")
+}
+
+// New creates a meeting note named after the date and title, with the date,
+// time, and attendees in its frontmatter, and returns its path.
+func New(root, title string, attendees []string, now time.Time) (string, error) {
+	if err := os.MkdirAll(Dir(root), 0755); err != nil {
+		return "", err
+	}
+
+	name := now.Format(note.DateFormat) + " " + strings.ReplaceAll(title, "/", "-")
+	path := filepath.Join(Dir(root), name+".md")
+
+	n, err := placeholder.Note(Template, config.TemplateVars(), map[string]string{
+		"title": title,
+		"date":  now.Format(note.DateFormat),
+	}, now, now)
+	if err != nil {
+		return "", err
+	}
+
+	n.Frontmatter.Merge(note.Frontmatter{
+		{Key: "title", Value: title},
+		{Key: "date", Value: now.Format(note.DateFormat)},
+		{Key: "time", Value: now.Format("15:04")},
+		{Key: "attendees", Value: attendees},
+	})
+	n.Path = path
+
+	content, err := n.Bytes()
+	if err != nil {
+		return "", err
+	}
+
+	// The note is only written if it still does not exist once it is locked.
+	err = note.Update(path, func(string) (string, error) {
+		if _, err := os.Stat(path); err == nil {
+			return "", fmt.Errorf("%s already exists", path)
+		}
+		return string(content), nil
+	})
+	if err != nil {
+		return "", err
+	}
+
+	return path, hook.Run(hook.PostNoteCreate, path)
+}
+
+// Actions are the open tasks written in a meeting note.
+func Actions(path string) ([]task.Task, error) {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+
+	return task.Open(task.Parse(path, string(content))), nil
+}
@@ -0,0 +1,85 @@
+package mood
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+const (
+	Mood   = "mood"
+	Energy = "energy"
+)
+
+// Set records a 1 to 5 rating under key in the frontmatter of the log.
+func Set(logPath, key string, value int) error {
+	if value < 1 || value > 5 {
+		return fmt.Errorf("%s must be between 1 and 5, not %d", key, value)
+	}
+
+	return note.UpdateNote(logPath, func(n *note.Note) error {
+		if _, err := os.Stat(logPath); err != nil {
+			return err
+		}
+
+		n.Frontmatter.Set(key, value)
+		return nil
+	})
+}
+
+// Series reads the rating under key from each of the logs for the given number
+// of days up to today, oldest first. Days without a log or rating are zero.
+func Series(logDir, key string, today time.Time, days int) ([]int, error) {
+	series := make([]int, days)
+
+	for i := range series {
+		day := today.AddDate(0, 0, i-days+1)
+
+		n, err := note.Read(logpath.For(logDir, day))
+		if os.IsNotExist(err) {
+			continue
+		} else if err != nil {
+			return nil, err
+		}
+
+		value, err := strconv.Atoi(n.Frontmatter.String(key))
+		if err == nil && value >= 1 && value <= 5 {
+			series[i] = value
+		}
+	}
+
+	return series, nil
+}
+
+var bars = []rune(" ▁▃▅▇█")
+
+// Sparkline draws one bar per rating, leaving a gap for missing days.
+func Sparkline(series []int) string {
+	line := make([]rune, len(series))
+	for i, value := range series {
+		line[i] = bars[value]
+	}
+
+	return string(line)
+}
+
+// Average of the recorded ratings, ignoring missing days.
+func Average(series []int) float64 {
+	sum, count := 0, 0
+	for _, value := range series {
+		if value > 0 {
+			sum += value
+			count++
+		}
+	}
+
+	if count == 0 {
+		return 0
+	}
+
+	return float64(sum) / float64(count)
+}
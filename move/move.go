@@ -0,0 +1,82 @@
+package move
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+)
+
+// Result counts the links that were rewritten when moving a note, and the
+// notes they were in.
+type Result struct {
+	Links int
+	Notes int
+}
+
+// Note moves the note at from to to, and rewrites every wikilink and relative
+// markdown link in the notebook that pointed at it. Relative links inside the
+// note are updated for its new folder. Both paths must be inside root. An
+// existing file at to is only overwritten with force.
+func Note(root, from, to string, force bool) (Result, error) {
+	result := Result{}
+
+	if _, err := os.Stat(to); err == nil && !force {
+		return result, fmt.Errorf("%s already exists", to)
+	}
+
+	paths, err := note.Paths(root)
+	if err != nil {
+		return result, err
+	}
+	resolver := link.NewResolver(root, paths)
+
+	updates := map[string]string{}
+	for _, path := range paths {
+		if path == to {
+			// The file is being replaced, so its links do not matter.
+			continue
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return result, err
+		}
+
+		updated, changed := link.Retarget(string(content), root, filepath.Dir(path), from, to, resolver)
+		if changed > 0 {
+			result.Links += changed
+			result.Notes++
+		}
+
+		if path == from {
+			rebased := link.Rebase(updated, filepath.Dir(from), filepath.Dir(to))
+			if rebased != string(content) {
+				updates[to] = rebased
+			}
+			continue
+		}
+
+		if changed > 0 {
+			updates[path] = updated
+		}
+	}
+
+	if err := os.MkdirAll(filepath.Dir(to), 0755); err != nil {
+		return result, err
+	}
+
+	if err := os.Rename(from, to); err != nil {
+		return result, err
+	}
+
+	for path, content := range updates {
+		if err := note.Save(path, []byte(content)); err != nil {
+			return result, err
+		}
+	}
+
+	return result, nil
+}
@@ -0,0 +1,50 @@
+package network
+
+import (
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/verbose"
+)
+
+// logged writes each request and how it went to the verbose log, without any
+// credentials in its address. Headers, which carry tokens, are never written.
+type logged struct {
+	next http.RoundTripper
+}
+
+func (l logged) RoundTrip(req *http.Request) (*http.Response, error) {
+	start := time.Now()
+	resp, err := l.next.RoundTrip(req)
+	elapsed := time.Since(start).Round(time.Millisecond)
+
+	if err != nil {
+		verbose.Debugf("%s %s failed after %s: %v", req.Method, redact(req.URL), elapsed, err)
+		return resp, err
+	}
+
+	verbose.Debugf("%s %s: %s in %s", req.Method, redact(req.URL), resp.Status, elapsed)
+	return resp, nil
+}
+
+// redact hides the password in an address and the values of query parameters
+// that look like credentials.
+func redact(u *url.URL) string {
+	redacted := *u
+	if u.User != nil {
+		redacted.User = url.User(u.User.Username())
+	}
+
+	query := u.Query()
+	for key := range query {
+		k := strings.ToLower(key)
+		if strings.Contains(k, "token") || strings.Contains(k, "key") || strings.Contains(k, "secret") || strings.Contains(k, "password") || strings.Contains(k, "signature") {
+			query.Set(key, "REDACTED")
+		}
+	}
+	redacted.RawQuery = query.Encode()
+
+	return redacted.String()
+}
@@ -0,0 +1,70 @@
+package network
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"errors"
+	"fmt"
+	"net"
+	"net/http"
+	"net/url"
+	"os"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+)
+
+// ErrOffline is returned instead of a client when nb is offline.
+var ErrOffline = errors.New("nb is offline, drop --offline or unset NB_OFFLINE to use the network")
+
+// Client is the HTTP client for requests nb makes to other machines, set up
+// with the `network` settings. Requests give up after `network.timeout`, or
+// after fallback when it is not set, and connecting gives up after
+// `network.connect_timeout`. Requests go through `network.proxy`, or else the
+// proxy in the HTTPS_PROXY and HTTP_PROXY environment variables. Servers are
+// trusted when signed by the system's certificate authorities or those in
+// `network.ca_bundle`. There is no client when nb is offline.
+func Client(fallback time.Duration) (*http.Client, error) {
+	if config.Offline() {
+		return nil, ErrOffline
+	}
+
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+
+	if timeout := config.NetworkConnectTimeout(); timeout > 0 {
+		dialer := &net.Dialer{Timeout: timeout, KeepAlive: 30 * time.Second}
+		transport.DialContext = dialer.DialContext
+		transport.TLSHandshakeTimeout = timeout
+	}
+
+	if proxy := config.NetworkProxy(); proxy != "" {
+		u, err := url.Parse(proxy)
+		if err != nil {
+			return nil, fmt.Errorf("could not read network.proxy: %v", err)
+		}
+		transport.Proxy = http.ProxyURL(u)
+	}
+
+	if bundle := config.NetworkCABundle(); bundle != "" {
+		pem, err := os.ReadFile(bundle)
+		if err != nil {
+			return nil, fmt.Errorf("could not read network.ca_bundle: %v", err)
+		}
+
+		pool, err := x509.SystemCertPool()
+		if err != nil {
+			pool = x509.NewCertPool()
+		}
+		if !pool.AppendCertsFromPEM(pem) {
+			return nil, fmt.Errorf("no certificates found in %s", bundle)
+		}
+		transport.TLSClientConfig = &tls.Config{RootCAs: pool}
+	}
+
+	timeout := fallback
+	if t := config.NetworkTimeout(); t > 0 {
+		timeout = t
+	}
+
+	return &http.Client{Timeout: timeout, Transport: logged{next: transport}}, nil
+}
@@ -0,0 +1,57 @@
+package note
+
+import (
+	"os"
+	"path/filepath"
+)
+
+// write puts content in the file through f. It is a variable so tests can
+// make a write fail part of the way through.
+var write = func(f *os.File, content []byte) error {
+	_, err := f.Write(content)
+	return err
+}
+
+// WriteAtomic replaces the file at path with content without ever leaving it
+// half written: content goes to a temporary file in the same folder, which is
+// synced and then renamed over path. The permissions of an existing file are
+// kept.
+func WriteAtomic(path string, content []byte) error {
+	mode := os.FileMode(0644)
+	if info, err := os.Stat(path); err == nil {
+		mode = info.Mode().Perm()
+	}
+
+	f, err := os.CreateTemp(filepath.Dir(path), "."+filepath.Base(path)+".*.tmp")
+	if err != nil {
+		return err
+	}
+	tmp := f.Name()
+
+	fail := func(err error) error {
+		f.Close()
+		os.Remove(tmp)
+		return err
+	}
+
+	if err := write(f, content); err != nil {
+		return fail(err)
+	}
+	if err := f.Chmod(mode); err != nil {
+		return fail(err)
+	}
+	if err := f.Sync(); err != nil {
+		return fail(err)
+	}
+	if err := f.Close(); err != nil {
+		os.Remove(tmp)
+		return err
+	}
+
+	if err := os.Rename(tmp, path); err != nil {
+		os.Remove(tmp)
+		return err
+	}
+
+	return nil
+}
@@ -0,0 +1,73 @@
+package note
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestWriteAtomic(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "Note.md")
+	if err := os.WriteFile(path, []byte("# Before\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	given := "# After\n"
+	if err := WriteAtomic(path, []byte(given)); err != nil {
+		t.Fatalf("given: %q\nunexpected error: %v\n", given, err)
+	}
+
+	actual, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if given != string(actual) {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, given, actual)
+	}
+
+	info, err := os.Stat(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if info.Mode().Perm() != 0600 {
+		t.Fatalf("given: a note with mode 0600\nexpected: 0600\nactual: %o\n", info.Mode().Perm())
+	}
+}
+
+func TestWriteAtomicFailure(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "Note.md")
+	expected := "# Before\n"
+	if err := os.WriteFile(path, []byte(expected), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Fail after writing half of the content, as a full disk or crash would.
+	defer func(original func(*os.File, []byte) error) { write = original }(write)
+	write = func(f *os.File, content []byte) error {
+		f.Write(content[:len(content)/2])
+		return errors.New("disk full")
+	}
+
+	if err := WriteAtomic(path, []byte("# After, and much more\n")); err == nil {
+		t.Fatalf("given: a failing write\nexpected: an error\nactual: none\n")
+	}
+
+	actual, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if expected != string(actual) {
+		t.Fatalf("given: a failing write\nexpected: %q\nactual: %q\n", expected, actual)
+	}
+
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(entries) != 1 {
+		t.Fatalf("given: a failing write\nexpected: no temporary files left\nactual: %d files\n", len(entries))
+	}
+}
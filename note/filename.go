@@ -0,0 +1,45 @@
+package note
+
+import (
+	"fmt"
+	"os"
+	"strings"
+)
+
+// FileName strips the characters from a title that cause trouble in paths or
+// wikilinks, along with leading dots, so the name can neither leave its folder
+// nor hide the file. Titles with nothing left use the fallback instead.
+func FileName(title, fallback string) string {
+	name := strings.Map(func(r rune) rune {
+		if strings.ContainsRune(`/\:*?"<>|[]#^`, r) {
+			return '-'
+		}
+		return r
+	}, title)
+
+	if runes := []rune(name); len(runes) > 100 {
+		name = string(runes[:100])
+	}
+
+	name = strings.TrimSpace(strings.TrimLeft(strings.TrimSpace(name), "."))
+	if name == "" && fallback != "" {
+		return FileName(fallback, "")
+	}
+	if name == "" {
+		return "Untitled"
+	}
+
+	return name
+}
+
+// Available adds ".md" to base, with a number before it if needed to not
+// clash with an existing file.
+func Available(base string) string {
+	path := base + ".md"
+	for i := 2; ; i++ {
+		if _, err := os.Stat(path); os.IsNotExist(err) {
+			return path
+		}
+		path = fmt.Sprintf("%s %d.md", base, i)
+	}
+}
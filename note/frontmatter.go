@@ -0,0 +1,84 @@
+package note
+
+import (
+	"fmt"
+	"time"
+
+	"gopkg.in/yaml.v2"
+)
+
+const DateFormat = "2006-01-02"
+
+// Frontmatter keeps keys in the order they were written so that rewriting a
+// note does not shuffle its metadata.
+type Frontmatter yaml.MapSlice
+
+func (f Frontmatter) Get(key string) (interface{}, bool) {
+	for _, item := range f {
+		if item.Key == key {
+			return item.Value, true
+		}
+	}
+
+	return nil, false
+}
+
+func (f Frontmatter) String(key string) string {
+	value, ok := f.Get(key)
+	if !ok || value == nil {
+		return ""
+	}
+
+	return fmt.Sprint(value)
+}
+
+func (f Frontmatter) Date(key string) (time.Time, bool) {
+	value, ok := f.Get(key)
+	if !ok {
+		return time.Time{}, false
+	}
+
+	switch v := value.(type) {
+	case time.Time:
+		return v, true
+	case string:
+		t, err := time.ParseInLocation(DateFormat, v, time.Local)
+		if err != nil {
+			return time.Time{}, false
+		}
+		return t, true
+	}
+
+	return time.Time{}, false
+}
+
+func (f *Frontmatter) Set(key string, value interface{}) {
+	for i, item := range *f {
+		if item.Key == key {
+			(*f)[i].Value = value
+			return
+		}
+	}
+
+	*f = append(*f, yaml.MapItem{Key: key, Value: value})
+}
+
+func (f *Frontmatter) Delete(key string) {
+	for i, item := range *f {
+		if item.Key == key {
+			*f = append((*f)[:i], (*f)[i+1:]...)
+			return
+		}
+	}
+}
+
+// Merge sets the fields of other that f does not already have, in the order
+// they appear in other.
+func (f *Frontmatter) Merge(other Frontmatter) {
+	for _, item := range other {
+		key := fmt.Sprint(item.Key)
+		if _, ok := f.Get(key); !ok {
+			f.Set(key, item.Value)
+		}
+	}
+}
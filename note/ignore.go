@@ -0,0 +1,56 @@
+package note
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// IgnoreFile lists patterns, one per line, of notes and folders under the
+// notebook root that nb should leave alone. A pattern with a slash is matched
+// against the path from the root, otherwise against each file or folder name.
+// Blank lines and lines starting with # are skipped.
+const IgnoreFile = ".nbignore"
+
+type ignore []string
+
+func readIgnore(root string) ignore {
+	f, err := os.Open(filepath.Join(root, IgnoreFile))
+	if err != nil {
+		return nil
+	}
+	defer f.Close()
+
+	patterns := ignore{}
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		patterns = append(patterns, strings.Trim(line, "/"))
+	}
+
+	return patterns
+}
+
+// matches reports whether the path, relative to the root, is ignored.
+func (i ignore) matches(rel string) bool {
+	rel = filepath.ToSlash(rel)
+
+	for _, pattern := range i {
+		if strings.Contains(pattern, "/") {
+			if ok, _ := filepath.Match(pattern, rel); ok {
+				return true
+			}
+			continue
+		}
+
+		if ok, _ := filepath.Match(pattern, filepath.Base(rel)); ok {
+			return true
+		}
+	}
+
+	return false
+}
@@ -0,0 +1,107 @@
+package note
+
+import (
+	"os"
+	"path/filepath"
+	"sync"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+)
+
+// IDFormat is the layout of a note id, the minute the note was made.
+const IDFormat = "200601021504"
+
+// NewID returns the id for a note made at now. When the id is taken the next
+// free minute is used instead.
+func NewID(now time.Time, taken func(id string) bool) string {
+	id := now.Format(IDFormat)
+	for taken(id) {
+		now = now.Add(time.Minute)
+		id = now.Format(IDFormat)
+	}
+
+	return id
+}
+
+// ids holds the ids of the notes in the notebook, read at most once a minute
+// so that saving many new notes, as an import does, does not read the whole
+// notebook for each.
+var ids struct {
+	sync.Mutex
+	root  string
+	read  time.Time
+	taken map[string]bool
+}
+
+// freshID is an id for a note made at created that no note in the notebook
+// at root has yet.
+func freshID(root string, created time.Time) string {
+	ids.Lock()
+	defer ids.Unlock()
+
+	if ids.root != root || time.Since(ids.read) > time.Minute {
+		ids.root, ids.read, ids.taken = root, time.Now(), map[string]bool{}
+
+		paths, _ := Paths(root)
+		for _, path := range paths {
+			if n, err := Read(path); err == nil && n.Frontmatter.String("id") != "" {
+				ids.taken[n.Frontmatter.String("id")] = true
+			}
+		}
+	}
+
+	id := NewID(created, func(id string) bool { return ids.taken[id] })
+	ids.taken[id] = true
+
+	return id
+}
+
+// maintain fills in the frontmatter fields listed in config.NoteMetadata for
+// the note about to be written to path. An id and a created date are only
+// added when missing, with ids moved on a minute at a time until no other note
+// has them; the created date of a note nb has not seen before is
+// the time its file was last changed. The modified date is always now. Content
+// with broken frontmatter is left as it is.
+func maintain(path string, content []byte, now time.Time) []byte {
+	fields := config.NoteMetadata()
+	if len(fields) == 0 || filepath.Ext(path) != ".md" {
+		return content
+	}
+
+	n, err := Parse(string(content))
+	if err != nil {
+		return content
+	}
+
+	created := now
+	if info, err := os.Stat(path); err == nil {
+		created = info.ModTime()
+	}
+
+	for _, field := range fields {
+		switch field {
+		case "id":
+			if n.Frontmatter.String("id") == "" {
+				root, err := config.GetRootDir()
+				if err != nil {
+					return content
+				}
+				n.Frontmatter.Set("id", freshID(root, created))
+			}
+		case "created":
+			if _, ok := n.Frontmatter.Get("created"); !ok {
+				n.Frontmatter.Set("created", created.Format(DateFormat))
+			}
+		case "modified":
+			n.Frontmatter.Set("modified", now.Format(DateFormat))
+		}
+	}
+
+	updated, err := n.Bytes()
+	if err != nil {
+		return content
+	}
+
+	return updated
+}
@@ -0,0 +1,225 @@
+package note
+
+import (
+	"bytes"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/history"
+	"github.com/t-eckert/nb/lock"
+	"github.com/t-eckert/nb/verbose"
+	"gopkg.in/yaml.v2"
+)
+
+const fence = "---"
+
+// Note is a markdown file in the notebook along with its YAML frontmatter.
+type Note struct {
+	Path        string
+	Frontmatter Frontmatter
+	Body        string
+}
+
+func Read(path string) (*Note, error) {
+	verbose.Tracef("reading %s", path)
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+
+	n, err := Parse(string(content))
+	if err != nil {
+		return nil, err
+	}
+	n.Path = path
+
+	return n, nil
+}
+
+// Parse splits the content of a note into its frontmatter and body. Content
+// without a leading `---` fence is treated as all body.
+func Parse(content string) (*Note, error) {
+	n := &Note{Body: content}
+
+	lines := strings.SplitAfter(content, "\n")
+	if len(lines) == 0 || strings.TrimRight(lines[0], "\r\n") != fence {
+		return n, nil
+	}
+
+	for i := 1; i < len(lines); i++ {
+		if strings.TrimRight(lines[i], "\r\n") != fence {
+			continue
+		}
+
+		var fm yaml.MapSlice
+		if err := yaml.Unmarshal([]byte(strings.Join(lines[1:i], "")), &fm); err != nil {
+			return nil, err
+		}
+		n.Frontmatter = Frontmatter(fm)
+		n.Body = strings.Join(lines[i+1:], "")
+
+		return n, nil
+	}
+
+	return n, nil
+}
+
+func (n *Note) Bytes() ([]byte, error) {
+	if len(n.Frontmatter) == 0 {
+		return []byte(n.Body), nil
+	}
+
+	raw, err := yaml.Marshal(yaml.MapSlice(n.Frontmatter))
+	if err != nil {
+		return nil, err
+	}
+
+	var b bytes.Buffer
+	b.WriteString(fence + "\n")
+	b.Write(raw)
+	b.WriteString(fence + "\n")
+	b.WriteString(n.Body)
+
+	return b.Bytes(), nil
+}
+
+func (n *Note) Write() error {
+	content, err := n.Bytes()
+	if err != nil {
+		return err
+	}
+
+	return Save(n.Path, content)
+}
+
+// Save writes content to the note at path, recording a snapshot of the note
+// before and after so the change can be undone with `nb history restore`. The
+// frontmatter fields set with `notes.metadata` are kept up to date. The note
+// is locked against other nb processes while it is written.
+func Save(path string, content []byte) error {
+	verbose.Debugf("writing %s (%d bytes)", path, len(content))
+
+	l, err := lock.Acquire(path)
+	if err != nil {
+		return err
+	}
+	defer l.Release()
+
+	return save(path, content)
+}
+
+// Update locks the note at path, reads it, and saves the result of edit, so
+// that another nb process cannot change the note in between. A missing note
+// reads as empty.
+func Update(path string, edit func(content string) (string, error)) error {
+	l, err := lock.Acquire(path)
+	if err != nil {
+		return err
+	}
+	defer l.Release()
+
+	content, err := os.ReadFile(path)
+	if err != nil && !os.IsNotExist(err) {
+		return err
+	}
+
+	updated, err := edit(string(content))
+	if err != nil {
+		return err
+	}
+
+	return save(path, []byte(updated))
+}
+
+// UpdateNote is Update for edits to the parsed note, such as to its
+// frontmatter. A missing note reads as empty, so edit can check whether the
+// note exists with the note locked.
+func UpdateNote(path string, edit func(n *Note) error) error {
+	return Update(path, func(content string) (string, error) {
+		n, err := Parse(content)
+		if err != nil {
+			return "", err
+		}
+		n.Path = path
+
+		if err := edit(n); err != nil {
+			return "", err
+		}
+
+		b, err := n.Bytes()
+		return string(b), err
+	})
+}
+
+func save(path string, content []byte) error {
+	now := time.Now()
+
+	if err := history.Record(path, now); err != nil {
+		return err
+	}
+
+	content = maintain(path, content, now)
+
+	if err := WriteAtomic(path, content); err != nil {
+		return err
+	}
+
+	return history.Record(path, now)
+}
+
+// Title is the `title` frontmatter key, falling back to the first level one
+// heading and then to the file name.
+func (n *Note) Title() string {
+	if title := n.Frontmatter.String("title"); title != "" {
+		return title
+	}
+
+	for _, line := range strings.Split(n.Body, "\n") {
+		if strings.HasPrefix(line, "# ") {
+			return strings.TrimSpace(strings.TrimPrefix(line, "# "))
+		}
+	}
+
+	return strings.TrimSuffix(filepath.Base(n.Path), filepath.Ext(n.Path))
+}
+
+// Paths lists every markdown file under root, skipping hidden directories and
+// anything matched by the patterns in IgnoreFile.
+func Paths(root string) ([]string, error) {
+	paths := []string{}
+	ignored := readIgnore(root)
+
+	err := filepath.WalkDir(root, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+
+		if path == root {
+			return nil
+		}
+
+		rel, err := filepath.Rel(root, path)
+		if err != nil {
+			return err
+		}
+
+		if d.IsDir() {
+			if strings.HasPrefix(d.Name(), ".") || ignored.matches(rel) {
+				return filepath.SkipDir
+			}
+			return nil
+		}
+
+		if filepath.Ext(path) == ".md" && !ignored.matches(rel) {
+			paths = append(paths, path)
+		}
+
+		return nil
+	})
+
+	return paths, err
+}
@@ -0,0 +1,127 @@
+package note
+
+import (
+	"testing"
+	"time"
+)
+
+func TestParseFrontmatter(t *testing.T) {
+	given := "---\ntitle: Salary negotiation notes\nremind: 2025-02-10\n---\n# Notes\n"
+	expected := "2025-02-10"
+
+	n, err := Parse(given)
+	if err != nil {
+		t.Fatalf("given: %s\nunexpected error: %v\n", given, err)
+	}
+
+	date, ok := n.Frontmatter.Date("remind")
+	if !ok {
+		t.Fatalf("given: %s\nexpected: %s\nactual: no date\n", given, expected)
+	}
+
+	actual := date.Format(DateFormat)
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+
+	if n.Body != "# Notes\n" {
+		t.Fatalf("given: %s\nexpected body: %q\nactual body: %q\n", given, "# Notes\n", n.Body)
+	}
+}
+
+func TestRoundTrip(t *testing.T) {
+	given := "---\ntitle: Alpha\nstatus: active\n---\nBody\n"
+	expected := given
+
+	n, err := Parse(given)
+	if err != nil {
+		t.Fatalf("given: %s\nunexpected error: %v\n", given, err)
+	}
+
+	actual, err := n.Bytes()
+	if err != nil {
+		t.Fatalf("given: %s\nunexpected error: %v\n", given, err)
+	}
+
+	if expected != string(actual) {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
+
+func TestMergeFrontmatter(t *testing.T) {
+	given := "---\ntitle: Standup\ntags: [work]\n---\nBody\n"
+	expected := "---\ntitle: Standup\ntags:\n- work\nteam: Platform\n---\nBody\n"
+
+	n, err := Parse(given)
+	if err != nil {
+		t.Fatalf("given: %s\nunexpected error: %v\n", given, err)
+	}
+
+	n.Frontmatter.Merge(Frontmatter{
+		{Key: "title", Value: "2025-03-04 Meeting"},
+		{Key: "team", Value: "Platform"},
+	})
+
+	actual, err := n.Bytes()
+	if err != nil {
+		t.Fatalf("given: %s\nunexpected error: %v\n", given, err)
+	}
+
+	if expected != string(actual) {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
+
+func TestAppendToSection(t *testing.T) {
+	given := "# 16 Oct 2026 \n\n## Tasks\n\n\n"
+	expected := "# 16 Oct 2026 \n\n## Tasks\n\n- [ ] Call Sam\n\n## Ideas\n\nA thought\n"
+
+	actual := AppendToSection(AppendToSection(given, "Tasks", "- [ ] Call Sam"), "Ideas", "A thought")
+
+	if expected != actual {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
+
+func TestReplaceSection(t *testing.T) {
+	given := "# Day\n\n## GitHub\n\n- old\n\n## Notes\n\nkeep\n"
+	expected := "# Day\n\n## GitHub\n\n- new\n\n## Notes\n\nkeep\n"
+
+	actual := ReplaceSection(given, "github", "- new\n")
+
+	if expected != actual {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
+
+func TestFileName(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"Q3 plan: draft", "Q3 plan- draft"},
+		{"..", "fallback"},
+		{".", "fallback"},
+		{"  ", "fallback"},
+		{".hidden", "hidden"},
+	}
+
+	for _, c := range cases {
+		actual := FileName(c.given, "fallback")
+		if c.expected != actual {
+			t.Fatalf("given: %q\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestNewID(t *testing.T) {
+	given := time.Date(2025, 3, 4, 12, 31, 0, 0, time.UTC)
+	taken := map[string]bool{"202503041231": true, "202503041232": true}
+	expected := "202503041233"
+
+	actual := NewID(given, func(id string) bool { return taken[id] })
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
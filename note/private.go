@@ -0,0 +1,33 @@
+package note
+
+import (
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/config"
+)
+
+// IsPrivate reports whether the file at path is kept out of what nb shares,
+// such as the pages and feed of nb serve and exports. Notes are private when
+// their frontmatter has `private: true`, and anything in one of the folders set
+// with `notes.private` is private.
+func IsPrivate(path string) bool {
+	if root, err := config.GetRootDir(); err == nil {
+		if rel, err := filepath.Rel(root, path); err == nil {
+			rel = filepath.ToSlash(rel)
+			for _, folder := range config.PrivateFolders() {
+				folder = strings.Trim(filepath.ToSlash(folder), "/")
+				if folder != "" && (rel == folder || strings.HasPrefix(rel, folder+"/")) {
+					return true
+				}
+			}
+		}
+	}
+
+	if filepath.Ext(path) != ".md" {
+		return false
+	}
+
+	n, err := Read(path)
+	return err == nil && n.Frontmatter.String("private") == "true"
+}
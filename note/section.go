@@ -0,0 +1,178 @@
+package note
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+)
+
+// Heading returns the level and text of a markdown heading line, or a level
+// of zero when the line is not a heading.
+func Heading(line string) (int, string) {
+	level := 0
+	for level < len(line) && line[level] == '#' {
+		level++
+	}
+
+	if level == 0 || level > 6 || level == len(line) || line[level] != ' ' {
+		return 0, ""
+	}
+
+	return level, strings.TrimSpace(line[level:])
+}
+
+// AppendToSection adds text to the end of the section whose heading is named
+// section, ignoring case. A missing section is created as a level two heading
+// at the end of the content. With an empty section the text is added to the
+// end of the content.
+func AppendToSection(content, section, text string) string {
+	lines := []string{}
+	if trimmed := strings.TrimRight(content, "\n"); trimmed != "" {
+		lines = strings.Split(trimmed, "\n")
+	}
+
+	start, end := -1, len(lines)
+	if section != "" {
+		level := 0
+		for i, line := range lines {
+			if l, name := Heading(line); l > 0 && strings.EqualFold(name, section) {
+				start, level = i, l
+				break
+			}
+		}
+
+		if start == -1 {
+			if len(lines) > 0 {
+				lines = append(lines, "")
+			}
+			lines = append(lines, "## "+section, "", text)
+			return strings.Join(lines, "\n") + "\n"
+		}
+
+		for i := start + 1; i < len(lines); i++ {
+			if l, _ := Heading(lines[i]); l > 0 && l <= level {
+				end = i
+				break
+			}
+		}
+	}
+
+	// Insert after the last line with content, keeping a blank line after the
+	// heading and before the next one.
+	insert := start + 1
+	for i := start + 1; i < end; i++ {
+		if strings.TrimSpace(lines[i]) != "" {
+			insert = i + 1
+		}
+	}
+
+	added := []string{text}
+	if insert > 0 {
+		if l, _ := Heading(lines[insert-1]); l > 0 {
+			added = []string{"", text}
+		}
+	}
+	if insert < len(lines) {
+		if l, _ := Heading(lines[insert]); l > 0 {
+			added = append(added, "")
+		}
+	}
+
+	lines = append(lines[:insert], append(added, lines[insert:]...)...)
+
+	return strings.Join(lines, "\n") + "\n"
+}
+
+// Capture appends text to a section of the note at path, creating the note
+// and its folders when they do not exist yet.
+func Capture(path, section, text string) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+
+	return Update(path, func(content string) (string, error) {
+		if content == "" {
+			content = "# " + strings.TrimSuffix(filepath.Base(path), ".md") + "\n"
+		}
+		return AppendToSection(content, section, text), nil
+	})
+}
+
+// Target splits a capture target such as "Projects/Alpha#Log" into the path of
+// the note under root and the name of the section. The note part is empty when
+// the target only names a section, as in "#Log".
+func Target(root, target string) (string, string) {
+	name, section := target, ""
+	if i := strings.LastIndex(target, "#"); i != -1 {
+		name, section = target[:i], strings.TrimSpace(target[i+1:])
+	}
+
+	if name == "" {
+		return "", section
+	}
+
+	if !strings.HasSuffix(name, ".md") {
+		name += ".md"
+	}
+
+	return filepath.Join(root, name), section
+}
+
+// Section returns the lines under the heading named section, ignoring case, up
+// to the next heading of the same or a higher level.
+func Section(content, section string) string {
+	lines := strings.Split(content, "\n")
+
+	for i, line := range lines {
+		level, name := Heading(line)
+		if level == 0 || !strings.EqualFold(name, section) {
+			continue
+		}
+
+		end := len(lines)
+		for j := i + 1; j < len(lines); j++ {
+			if l, _ := Heading(lines[j]); l > 0 && l <= level {
+				end = j
+				break
+			}
+		}
+
+		return strings.Join(lines[i+1:end], "\n")
+	}
+
+	return ""
+}
+
+// ReplaceSection swaps the lines under the heading named section, ignoring
+// case, for body. A missing section is created as a level two heading at the
+// end of the content.
+func ReplaceSection(content, section, body string) string {
+	lines := strings.Split(strings.TrimRight(content, "\n"), "\n")
+	body = strings.Trim(body, "\n")
+
+	for i, line := range lines {
+		level, name := Heading(line)
+		if level == 0 || !strings.EqualFold(name, section) {
+			continue
+		}
+
+		end := len(lines)
+		for j := i + 1; j < len(lines); j++ {
+			if l, _ := Heading(lines[j]); l > 0 && l <= level {
+				end = j
+				break
+			}
+		}
+
+		replaced := append([]string{}, lines[:i+1]...)
+		replaced = append(replaced, "", body)
+		if end < len(lines) {
+			replaced = append(replaced, "")
+			replaced = append(replaced, lines[end:]...)
+		}
+
+		return strings.Join(replaced, "\n") + "\n"
+	}
+
+	return AppendToSection(content, section, body)
+}
@@ -0,0 +1,66 @@
+package pandoc
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/note"
+	"gopkg.in/yaml.v2"
+)
+
+// Convert writes the note at path to output in format, any output format
+// pandoc knows such as docx, odt, or epub, by running the pandoc binary set
+// with `export.pandoc`. The frontmatter goes to pandoc as metadata, with the
+// title of the note when it has none, and images are found next to the note.
+func Convert(path, format, output string) error {
+	bin := config.Pandoc()
+	if _, err := exec.LookPath(bin); err != nil {
+		return fmt.Errorf("could not find pandoc at %s, set export.pandoc to its path", bin)
+	}
+
+	n, err := note.Read(path)
+	if err != nil {
+		return err
+	}
+
+	meta := n.Frontmatter
+	if _, ok := meta.Get("title"); !ok {
+		meta.Set("title", n.Title())
+	}
+
+	b, err := yaml.Marshal(yaml.MapSlice(meta))
+	if err != nil {
+		return err
+	}
+
+	metaFile, err := os.CreateTemp("", "nb-pandoc-*.yaml")
+	if err != nil {
+		return err
+	}
+	defer os.Remove(metaFile.Name())
+
+	if _, err := metaFile.Write(b); err != nil {
+		metaFile.Close()
+		return err
+	}
+	if err := metaFile.Close(); err != nil {
+		return err
+	}
+
+	cmd := exec.Command(bin,
+		"--from", "markdown",
+		"--to", format,
+		"--metadata-file", metaFile.Name(),
+		"--resource-path", filepath.Dir(path),
+		"--output", output,
+	)
+	cmd.Stdin = strings.NewReader(n.Body)
+	cmd.Stdout = os.Stdout
+	cmd.Stderr = os.Stderr
+
+	return cmd.Run()
+}
@@ -0,0 +1,149 @@
+package people
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"unicode"
+
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/vcard"
+)
+
+func Dir(root string) string {
+	return filepath.Join(root, "People")
+}
+
+func Path(root, name string) string {
+	return filepath.Join(Dir(root), strings.ReplaceAll(name, "/", "-")+".md")
+}
+
+// Sync creates a People page for each card, or updates the frontmatter of the
+// page if it already exists. It returns how many pages were created and
+// updated.
+func Sync(root string, cards []vcard.Card) (int, int, error) {
+	if err := os.MkdirAll(Dir(root), 0755); err != nil {
+		return 0, 0, err
+	}
+
+	created, updated := 0, 0
+	for _, card := range cards {
+		path := Path(root, card.Name)
+
+		err := note.UpdateNote(path, func(n *note.Note) error {
+			if _, err := os.Stat(path); os.IsNotExist(err) {
+				n.Body = "# " + card.Name + "\n"
+				created++
+			} else if err != nil {
+				return err
+			} else {
+				updated++
+			}
+
+			setIfPresent(&n.Frontmatter, "email", card.Email)
+			setIfPresent(&n.Frontmatter, "org", card.Org)
+			setIfPresent(&n.Frontmatter, "phone", card.Phone)
+			setIfPresent(&n.Frontmatter, "birthday", card.Birthday)
+			return nil
+		})
+		if err != nil {
+			return created, updated, err
+		}
+	}
+
+	return created, updated, nil
+}
+
+func setIfPresent(fm *note.Frontmatter, key, value string) {
+	if value != "" {
+		fm.Set(key, value)
+	}
+}
+
+// New creates the People page for name unless it already exists, and returns
+// its path.
+func New(root, name string) (string, error) {
+	path := Path(root, name)
+
+	if _, err := os.Stat(path); err == nil {
+		return path, nil
+	}
+
+	if err := os.MkdirAll(Dir(root), 0755); err != nil {
+		return "", err
+	}
+
+	if err := note.Save(path, []byte("# "+name+"\n")); err != nil {
+		return "", err
+	}
+
+	return path, hook.Run(hook.PostNoteCreate, path)
+}
+
+// Mention is a line of a note that refers to a person.
+type Mention struct {
+	Path string
+	Line int
+	Text string
+}
+
+// Mentions finds every line in the notebook that refers to the person, either
+// as an `@mention` or as a `[[wikilink]]` to their page. A mention matches the
+// full name without spaces or punctuation, like @SamSmith or @sam_smith, or
+// the first name alone, like @Sam.
+func Mentions(root, name string) ([]Mention, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	full := squash(name)
+	first := squash(strings.Fields(name + " ")[0])
+	page := Path(root, name)
+
+	mentions := []Mention{}
+	for _, path := range paths {
+		if path == page {
+			continue
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+		lines := strings.Split(string(content), "\n")
+
+		matched := map[int]bool{}
+		for _, m := range link.Mentions(string(content)) {
+			if target := squash(m.Target); target == full || target == first {
+				matched[m.Line] = true
+			}
+		}
+		for _, l := range link.Wikilinks(string(content)) {
+			if squash(filepath.Base(l.Target)) == full {
+				matched[l.Line] = true
+			}
+		}
+
+		for i, line := range lines {
+			if matched[i] {
+				mentions = append(mentions, Mention{path, i, strings.TrimSpace(line)})
+			}
+		}
+	}
+
+	return mentions, nil
+}
+
+// squash lowercases a name and drops everything but letters and digits.
+func squash(name string) string {
+	var b strings.Builder
+	for _, r := range strings.ToLower(name) {
+		if unicode.IsLetter(r) || unicode.IsDigit(r) {
+			b.WriteRune(r)
+		}
+	}
+	return b.String()
+}
@@ -0,0 +1,71 @@
+package placeholder
+
+import (
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+)
+
+var layout = strings.NewReplacer(
+	"YYYY", "2006",
+	"YY", "06",
+	"MMMM", "January",
+	"MMM", "Jan",
+	"MM", "01",
+	"M", "1",
+	"DD", "02",
+	"D", "2",
+	"dddd", "Monday",
+	"ddd", "Mon",
+	"HH", "15",
+	"hh", "03",
+	"h", "3",
+	"mm", "04",
+	"ss", "05",
+	"A", "PM",
+)
+
+// Expand fills a template for a note about day, written at now. Besides vars
+// and the custom placeholders from the config, every template can use
+//
+//	{{week}}       the week of day, as in 2025-W10
+//	{{prev_date}}  the day before, as YYYY-MM-DD
+//	{{next_date}}  the day after, as YYYY-MM-DD
+//	{{date:FMT}}   day in a format such as "dddd, MMMM D"
+//	{{time:FMT}}   now in a format such as "HH:mm"
+//
+// Formats use YYYY, MM, DD, HH, mm, and the like, with month and weekday names
+// in the configured locale. vars win over the custom placeholders, which win
+// over the built in ones.
+func Expand(text string, custom, vars map[string]string, day, now time.Time) string {
+	all := map[string]string{}
+
+	all["week"] = date.Week(day, config.WeekStart())
+	all["prev_date"] = day.AddDate(0, 0, -1).Format("2006-01-02")
+	all["next_date"] = day.AddDate(0, 0, 1).Format("2006-01-02")
+	all["time"] = now.Format("15:04")
+
+	for name, value := range custom {
+		all[name] = value
+	}
+	for name, value := range vars {
+		all[name] = value
+	}
+
+	text = pattern.ReplaceAllStringFunc(text, func(match string) string {
+		name := strings.TrimSpace(pattern.FindStringSubmatch(match)[1])
+
+		switch {
+		case strings.HasPrefix(name, "date:"):
+			return date.Format(day, layout.Replace(strings.TrimPrefix(name, "date:")), config.Locale())
+		case strings.HasPrefix(name, "time:"):
+			return date.Format(now, layout.Replace(strings.TrimPrefix(name, "time:")), config.Locale())
+		}
+
+		return match
+	})
+
+	return Fill(text, all)
+}
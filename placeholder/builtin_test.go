@@ -0,0 +1,39 @@
+package placeholder
+
+import (
+	"testing"
+	"time"
+)
+
+func TestExpand(t *testing.T) {
+	day := time.Date(2025, 3, 4, 0, 0, 0, 0, time.UTC)
+	now := time.Date(2025, 3, 4, 9, 5, 0, 0, time.UTC)
+
+	given := "{{week}} {{prev_date}} {{next_date}} {{date:dddd, MMMM D}} {{time:HH:mm}} {{team}} {{title}} {{missing}}"
+	expected := "2025-W10 2025-03-03 2025-03-05 Tuesday, March 4 09:05 Platform Standup {{missing}}"
+
+	actual := Expand(given, map[string]string{"team": "Platform", "title": "Custom"}, map[string]string{"title": "Standup"}, day, now)
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
+
+func TestNote(t *testing.T) {
+	day := time.Date(2025, 3, 4, 0, 0, 0, 0, time.UTC)
+
+	given := "---\ntitle: {{title}}\ntags: [{{team}}]\n---\n# {{title}}\n"
+	expected := "Review: [draft] plans"
+
+	n, err := Note(given, nil, map[string]string{"title": expected, "team": "Platform"}, day, day)
+	if err != nil {
+		t.Fatalf("given: %q\nunexpected error: %v\n", given, err)
+	}
+
+	if actual := n.Frontmatter.String("title"); expected != actual {
+		t.Fatalf("given: %q\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+	if n.Body != "# "+expected+"\n" {
+		t.Fatalf("given: %q\nexpected body: %q\nactual body: %q\n", given, "# "+expected+"\n", n.Body)
+	}
+}
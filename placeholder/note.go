@@ -0,0 +1,72 @@
+package placeholder
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+	"gopkg.in/yaml.v2"
+)
+
+var token = regexp.MustCompile(`nbplaceholder(\d+)x`)
+
+// Note fills a template that may start with frontmatter, as Expand does, and
+// parses the result into a note. Placeholders resolve in frontmatter values as
+// well as in the body, so a template can set `date: {{date}}`. They are
+// expanded after the frontmatter is parsed, so values with colons or brackets,
+// such as a title, cannot break it.
+func Note(template string, custom, vars map[string]string, day, now time.Time) (*note.Note, error) {
+	placeholders := []string{}
+	masked := pattern.ReplaceAllStringFunc(template, func(match string) string {
+		placeholders = append(placeholders, match)
+		return fmt.Sprintf("nbplaceholder%dx", len(placeholders)-1)
+	})
+
+	n, err := note.Parse(masked)
+	if err != nil {
+		return nil, fmt.Errorf("template has broken frontmatter: %w", err)
+	}
+
+	restore := func(text string, expand bool) string {
+		return token.ReplaceAllStringFunc(text, func(match string) string {
+			i, _ := strconv.Atoi(token.FindStringSubmatch(match)[1])
+			if i >= len(placeholders) {
+				return match
+			}
+			if expand {
+				return Expand(placeholders[i], custom, vars, day, now)
+			}
+			return placeholders[i]
+		})
+	}
+
+	var fill func(value interface{}) interface{}
+	fill = func(value interface{}) interface{} {
+		switch v := value.(type) {
+		case string:
+			return restore(v, true)
+		case []interface{}:
+			for i := range v {
+				v[i] = fill(v[i])
+			}
+		case map[interface{}]interface{}:
+			for key, item := range v {
+				v[key] = fill(item)
+			}
+		case yaml.MapSlice:
+			for i := range v {
+				v[i].Key, v[i].Value = fill(v[i].Key), fill(v[i].Value)
+			}
+		}
+		return value
+	}
+
+	for i := range n.Frontmatter {
+		n.Frontmatter[i].Key, n.Frontmatter[i].Value = fill(n.Frontmatter[i].Key), fill(n.Frontmatter[i].Value)
+	}
+	n.Body = Expand(restore(n.Body, false), custom, vars, day, now)
+
+	return n, nil
+}
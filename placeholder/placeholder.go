@@ -0,0 +1,20 @@
+package placeholder
+
+import (
+	"regexp"
+	"strings"
+)
+
+var pattern = regexp.MustCompile(`\{\{\s*([^}]*?)\s*\}\}`)
+
+// Fill replaces each `{{name}}` in text with its value. Placeholders without a
+// value are left as they are so that they stand out.
+func Fill(text string, vars map[string]string) string {
+	return pattern.ReplaceAllStringFunc(text, func(match string) string {
+		name := strings.TrimSpace(pattern.FindStringSubmatch(match)[1])
+		if value, ok := vars[name]; ok {
+			return value
+		}
+		return match
+	})
+}
@@ -0,0 +1,86 @@
+package plugin
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+
+	"github.com/spf13/viper"
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/verbose"
+)
+
+// Prefix starts the name of every plugin executable, so that `nb foo` runs
+// nb-foo.
+const Prefix = "nb-"
+
+// Find returns the path of the plugin for the subcommand name on PATH.
+func Find(name string) (string, error) {
+	return exec.LookPath(Prefix + name)
+}
+
+// List returns the names of the plugins on PATH, without the prefix.
+func List() []string {
+	seen := map[string]bool{}
+	for _, dir := range filepath.SplitList(os.Getenv("PATH")) {
+		entries, err := os.ReadDir(dir)
+		if err != nil {
+			continue
+		}
+
+		for _, e := range entries {
+			name := e.Name()
+			if e.IsDir() || !strings.HasPrefix(name, Prefix) {
+				continue
+			}
+			if runtime.GOOS == "windows" {
+				name = strings.TrimSuffix(name, filepath.Ext(name))
+			}
+			if _, err := exec.LookPath(filepath.Join(dir, e.Name())); err == nil {
+				seen[strings.TrimPrefix(name, Prefix)] = true
+			}
+		}
+	}
+
+	names := []string{}
+	for name := range seen {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	return names
+}
+
+// Run runs the plugin at path with args, connected to the terminal. Besides
+// the environment of nb, the plugin gets
+//
+//	NB_NOTEBOOK  the notebook root
+//	NB_LOG_DIR   the folder of the daily logs
+//	NB_STATE_DIR the folder nb keeps derived state in
+//	NB_CONFIG    the config file in use, if any
+//	NB_EDITOR    the editor command
+func Run(path string, args []string) error {
+	root, err := config.GetRootDir()
+	if err != nil {
+		return err
+	}
+
+	verbose.Debugf("running plugin: %s %s", path, strings.Join(args, " "))
+
+	cmd := exec.Command(path, args...)
+	cmd.Stdin = os.Stdin
+	cmd.Stdout = os.Stdout
+	cmd.Stderr = os.Stderr
+	cmd.Env = append(os.Environ(),
+		"NB_NOTEBOOK="+root,
+		"NB_LOG_DIR="+filepath.Join(root, config.LogFolder()),
+		"NB_STATE_DIR="+config.DataDir(root),
+		"NB_CONFIG="+viper.ConfigFileUsed(),
+		"NB_EDITOR="+config.EditorCommand(),
+	)
+
+	return cmd.Run()
+}
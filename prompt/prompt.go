@@ -0,0 +1,22 @@
+package prompt
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"strings"
+)
+
+var stdin = bufio.NewReader(os.Stdin)
+
+// Ask prints the question and returns the trimmed line typed in response.
+func Ask(question string) (string, error) {
+	fmt.Print(question)
+
+	answer, err := stdin.ReadString('\n')
+	if err != nil && answer == "" {
+		return "", err
+	}
+
+	return strings.TrimSpace(answer), nil
+}
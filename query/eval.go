@@ -0,0 +1,142 @@
+package query
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Expr is a condition on the fields of a note.
+type Expr interface {
+	Eval(lookup func(field string) (interface{}, bool)) bool
+	fields() []string
+}
+
+type and struct{ left, right Expr }
+
+func (e and) Eval(lookup func(string) (interface{}, bool)) bool {
+	return e.left.Eval(lookup) && e.right.Eval(lookup)
+}
+
+func (e and) fields() []string {
+	return append(e.left.fields(), e.right.fields()...)
+}
+
+type or struct{ left, right Expr }
+
+func (e or) Eval(lookup func(string) (interface{}, bool)) bool {
+	return e.left.Eval(lookup) || e.right.Eval(lookup)
+}
+
+func (e or) fields() []string {
+	return append(e.left.fields(), e.right.fields()...)
+}
+
+type not struct{ expr Expr }
+
+func (e not) Eval(lookup func(string) (interface{}, bool)) bool {
+	return !e.expr.Eval(lookup)
+}
+
+func (e not) fields() []string {
+	return e.expr.fields()
+}
+
+type comparison struct {
+	field string
+	op    string
+	value string
+}
+
+// Eval is false whenever the field is missing. A list matches = and contains
+// when any of its items does.
+func (e comparison) Eval(lookup func(string) (interface{}, bool)) bool {
+	actual, ok := lookup(e.field)
+	if !ok || actual == nil {
+		return false
+	}
+
+	if items, ok := actual.([]interface{}); ok {
+		found := false
+		for _, item := range items {
+			if compare(Text(item), e.value) == 0 {
+				found = true
+				break
+			}
+		}
+
+		switch e.op {
+		case "=", "contains":
+			return found
+		case "!=":
+			return !found
+		}
+		return false
+	}
+
+	text := Text(actual)
+	if e.op == "contains" {
+		return strings.Contains(strings.ToLower(text), strings.ToLower(e.value))
+	}
+
+	c := compare(text, e.value)
+	switch e.op {
+	case "=":
+		return c == 0
+	case "!=":
+		return c != 0
+	case "<":
+		return c < 0
+	case "<=":
+		return c <= 0
+	case ">":
+		return c > 0
+	case ">=":
+		return c >= 0
+	}
+
+	return false
+}
+
+func (e comparison) fields() []string {
+	return []string{e.field}
+}
+
+// compare orders numbers by value and everything else as text, which also
+// orders YYYY-MM-DD dates correctly.
+func compare(a, b string) int {
+	x, errX := strconv.ParseFloat(a, 64)
+	y, errY := strconv.ParseFloat(b, 64)
+	if errX == nil && errY == nil {
+		switch {
+		case x < y:
+			return -1
+		case x > y:
+			return 1
+		}
+		return 0
+	}
+
+	return strings.Compare(a, b)
+}
+
+// Text formats a frontmatter value for comparing and printing.
+func Text(value interface{}) string {
+	switch v := value.(type) {
+	case nil:
+		return ""
+	case time.Time:
+		return v.Format(note.DateFormat)
+	case []interface{}:
+		items := []string{}
+		for _, item := range v {
+			items = append(items, Text(item))
+		}
+		return strings.Join(items, ", ")
+	}
+
+	return fmt.Sprint(value)
+}
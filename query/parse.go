@@ -0,0 +1,269 @@
+package query
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"unicode"
+)
+
+// Query selects notes by their frontmatter, in the form
+//
+//	[table <field>, ...] [from <folder>] [where <condition>] [sort by <field> [asc|desc]] [limit <n>]
+//
+// Conditions compare a field to a value with =, !=, <, <=, >, >=, or contains,
+// and are combined with and, or, not, and parentheses. Values are numbers,
+// dates, true or false, or quoted strings.
+type Query struct {
+	Fields []string
+	From   string
+	Where  Expr
+	SortBy string
+	Desc   bool
+	Limit  int
+}
+
+type token struct {
+	text   string
+	quoted bool
+}
+
+func (t token) is(word string) bool {
+	return !t.quoted && strings.EqualFold(t.text, word)
+}
+
+func tokenize(s string) ([]token, error) {
+	tokens := []token{}
+
+	runes := []rune(s)
+	for i := 0; i < len(runes); {
+		r := runes[i]
+
+		switch {
+		case unicode.IsSpace(r):
+			i++
+		case r == '\'' || r == '"':
+			end := i + 1
+			for end < len(runes) && runes[end] != r {
+				end++
+			}
+			if end == len(runes) {
+				return nil, fmt.Errorf("unterminated string starting at %d", i)
+			}
+			tokens = append(tokens, token{string(runes[i+1 : end]), true})
+			i = end + 1
+		case strings.ContainsRune("(),", r):
+			tokens = append(tokens, token{text: string(r)})
+			i++
+		case strings.ContainsRune("=!<>", r):
+			end := i + 1
+			if end < len(runes) && runes[end] == '=' {
+				end++
+			}
+			tokens = append(tokens, token{text: string(runes[i:end])})
+			i = end
+		default:
+			end := i
+			for end < len(runes) && !unicode.IsSpace(runes[end]) && !strings.ContainsRune("(),=!<>'\"", runes[end]) {
+				end++
+			}
+			tokens = append(tokens, token{text: string(runes[i:end])})
+			i = end
+		}
+	}
+
+	return tokens, nil
+}
+
+type parser struct {
+	tokens []token
+	pos    int
+}
+
+func (p *parser) peek() (token, bool) {
+	if p.pos >= len(p.tokens) {
+		return token{}, false
+	}
+
+	return p.tokens[p.pos], true
+}
+
+func (p *parser) next() (token, error) {
+	t, ok := p.peek()
+	if !ok {
+		return token{}, fmt.Errorf("unexpected end of query")
+	}
+	p.pos++
+
+	return t, nil
+}
+
+func (p *parser) accept(word string) bool {
+	if t, ok := p.peek(); ok && t.is(word) {
+		p.pos++
+		return true
+	}
+
+	return false
+}
+
+// Parse reads a query.
+func Parse(s string) (*Query, error) {
+	tokens, err := tokenize(s)
+	if err != nil {
+		return nil, err
+	}
+
+	p := &parser{tokens: tokens}
+	q := &Query{}
+
+	if p.accept("table") {
+		for {
+			t, err := p.next()
+			if err != nil {
+				return nil, err
+			}
+			q.Fields = append(q.Fields, t.text)
+
+			if !p.accept(",") {
+				break
+			}
+		}
+	}
+
+	if p.accept("from") {
+		t, err := p.next()
+		if err != nil {
+			return nil, err
+		}
+		q.From = t.text
+	}
+
+	if p.accept("where") {
+		if q.Where, err = p.or(); err != nil {
+			return nil, err
+		}
+	}
+
+	if p.accept("sort") {
+		if !p.accept("by") {
+			return nil, fmt.Errorf("expected \"by\" after \"sort\"")
+		}
+
+		t, err := p.next()
+		if err != nil {
+			return nil, err
+		}
+		q.SortBy = t.text
+
+		if p.accept("desc") {
+			q.Desc = true
+		} else {
+			p.accept("asc")
+		}
+	}
+
+	if p.accept("limit") {
+		t, err := p.next()
+		if err != nil {
+			return nil, err
+		}
+
+		if q.Limit, err = strconv.Atoi(t.text); err != nil || q.Limit < 0 {
+			return nil, fmt.Errorf("limit should be a number, not %q", t.text)
+		}
+	}
+
+	if t, ok := p.peek(); ok {
+		return nil, fmt.Errorf("unexpected %q", t.text)
+	}
+
+	return q, nil
+}
+
+func (p *parser) or() (Expr, error) {
+	left, err := p.and()
+	if err != nil {
+		return nil, err
+	}
+
+	for p.accept("or") {
+		right, err := p.and()
+		if err != nil {
+			return nil, err
+		}
+		left = or{left, right}
+	}
+
+	return left, nil
+}
+
+func (p *parser) and() (Expr, error) {
+	left, err := p.not()
+	if err != nil {
+		return nil, err
+	}
+
+	for p.accept("and") {
+		right, err := p.not()
+		if err != nil {
+			return nil, err
+		}
+		left = and{left, right}
+	}
+
+	return left, nil
+}
+
+func (p *parser) not() (Expr, error) {
+	if p.accept("not") {
+		expr, err := p.not()
+		if err != nil {
+			return nil, err
+		}
+		return not{expr}, nil
+	}
+
+	if p.accept("(") {
+		expr, err := p.or()
+		if err != nil {
+			return nil, err
+		}
+
+		if !p.accept(")") {
+			return nil, fmt.Errorf("expected \")\"")
+		}
+		return expr, nil
+	}
+
+	return p.comparison()
+}
+
+func (p *parser) comparison() (Expr, error) {
+	field, err := p.next()
+	if err != nil {
+		return nil, err
+	}
+
+	op, err := p.next()
+	if err != nil {
+		return nil, err
+	}
+
+	switch {
+	case op.quoted:
+		return nil, fmt.Errorf("expected an operator after %q, not %q", field.text, op.text)
+	case op.is("contains"):
+		op.text = "contains"
+	case op.text == "=", op.text == "!=", op.text == "<", op.text == "<=", op.text == ">", op.text == ">=":
+	default:
+		return nil, fmt.Errorf("unknown operator %q", op.text)
+	}
+
+	value, err := p.next()
+	if err != nil {
+		return nil, err
+	}
+
+	return comparison{field: field.text, op: op.text, value: value.text}, nil
+}
@@ -0,0 +1,48 @@
+package query
+
+import (
+	"testing"
+)
+
+func TestParseAndEval(t *testing.T) {
+	given := "from Projects where status = 'active' and (due < 2025-06-01 or tags contains urgent) sort by due desc limit 5"
+
+	q, err := Parse(given)
+	if err != nil {
+		t.Fatalf("given: %s\nexpected: no error\nactual: %v\n", given, err)
+	}
+
+	if q.From != "Projects" || q.SortBy != "due" || !q.Desc || q.Limit != 5 {
+		t.Fatalf("given: %s\nexpected: from Projects, sort by due desc, limit 5\nactual: %+v\n", given, q)
+	}
+
+	cases := []struct {
+		fields   map[string]interface{}
+		expected bool
+	}{
+		{map[string]interface{}{"status": "active", "due": "2025-05-01"}, true},
+		{map[string]interface{}{"status": "active", "due": "2025-07-01"}, false},
+		{map[string]interface{}{"status": "active", "due": "2025-07-01", "tags": []interface{}{"urgent"}}, true},
+		{map[string]interface{}{"status": "done", "due": "2025-05-01"}, false},
+		{map[string]interface{}{"due": "2025-05-01"}, false},
+	}
+
+	for _, c := range cases {
+		lookup := func(field string) (interface{}, bool) {
+			value, ok := c.fields[field]
+			return value, ok
+		}
+
+		if actual := q.Where.Eval(lookup); actual != c.expected {
+			t.Fatalf("given: %s on %v\nexpected: %v\nactual: %v\n", given, c.fields, c.expected, actual)
+		}
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	for _, given := range []string{"where status", "where status ~ 1", "sort due", "limit many", "where (a = 1"} {
+		if _, err := Parse(given); err == nil {
+			t.Fatalf("given: %s\nexpected: an error\nactual: none\n", given)
+		}
+	}
+}
@@ -0,0 +1,144 @@
+package query
+
+import (
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Row is a note that matched a query, with the value of each column. Values
+// are nil where the note does not have the field.
+type Row struct {
+	Path   string
+	Values []interface{}
+}
+
+// Columns are the fields listed after `table`, or otherwise the title followed
+// by every field the query looks at.
+func (q *Query) Columns() []string {
+	if len(q.Fields) > 0 {
+		return q.Fields
+	}
+
+	columns := []string{"title"}
+	seen := map[string]bool{"title": true}
+
+	fields := []string{}
+	if q.Where != nil {
+		fields = q.Where.fields()
+	}
+	if q.SortBy != "" {
+		fields = append(fields, q.SortBy)
+	}
+
+	for _, field := range fields {
+		if !seen[field] {
+			columns = append(columns, field)
+			seen[field] = true
+		}
+	}
+
+	return columns
+}
+
+// Run evaluates the query against every note under root. Besides frontmatter
+// keys, fields can be `file.name`, `file.path`, and `file.folder`. The
+// `title` field falls back to the title of the note.
+func (q *Query) Run(root string) ([]Row, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	from := strings.ToLower(strings.Trim(filepath.ToSlash(q.From), "/"))
+	columns := q.Columns()
+
+	type match struct {
+		row  Row
+		sort interface{}
+	}
+	matches := []match{}
+
+	for _, path := range paths {
+		rel, err := filepath.Rel(root, path)
+		if err != nil {
+			continue
+		}
+		rel = filepath.ToSlash(rel)
+
+		if from != "" && !strings.HasPrefix(strings.ToLower(rel), from+"/") {
+			continue
+		}
+
+		n, err := note.Read(path)
+		if err != nil {
+			// Notes with malformed frontmatter cannot match anything.
+			continue
+		}
+
+		lookup := func(field string) (interface{}, bool) {
+			return Lookup(n, rel, field)
+		}
+
+		if q.Where != nil && !q.Where.Eval(lookup) {
+			continue
+		}
+
+		row := Row{Path: path}
+		for _, column := range columns {
+			value, _ := lookup(column)
+			row.Values = append(row.Values, value)
+		}
+
+		m := match{row: row}
+		if q.SortBy != "" {
+			m.sort, _ = lookup(q.SortBy)
+		}
+		matches = append(matches, m)
+	}
+
+	if q.SortBy != "" {
+		sort.SliceStable(matches, func(i, j int) bool {
+			a, b := matches[i].sort, matches[j].sort
+
+			// Notes without the field go last either way.
+			if a == nil || b == nil {
+				return a != nil && b == nil
+			}
+
+			if q.Desc {
+				return compare(Text(a), Text(b)) > 0
+			}
+			return compare(Text(a), Text(b)) < 0
+		})
+	}
+
+	rows := []Row{}
+	for _, m := range matches {
+		if q.Limit > 0 && len(rows) == q.Limit {
+			break
+		}
+		rows = append(rows, m.row)
+	}
+
+	return rows, nil
+}
+
+// Lookup finds the value of a field of the note at rel, the path of the note
+// from the notebook root.
+func Lookup(n *note.Note, rel, field string) (interface{}, bool) {
+	switch field {
+	case "file.name":
+		return strings.TrimSuffix(filepath.Base(rel), ".md"), true
+	case "file.path":
+		return rel, true
+	case "file.folder":
+		return filepath.ToSlash(filepath.Dir(rel)), true
+	case "title":
+		return n.Title(), true
+	}
+
+	return n.Frontmatter.Get(field)
+}
@@ -0,0 +1,40 @@
+package query
+
+import (
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// SavedDir is the folder of notes that each hold a saved query in their
+// `query` frontmatter key, named after the note.
+const SavedDir = "+Queries"
+
+// Saved returns the saved queries by name: those configured, then those in
+// SavedDir, which win when both use a name.
+func Saved(root string, configured map[string]string) (map[string]string, error) {
+	saved := map[string]string{}
+	for name, q := range configured {
+		saved[name] = q
+	}
+
+	paths, err := filepath.Glob(filepath.Join(root, SavedDir, "*.md"))
+	if err != nil {
+		return nil, err
+	}
+
+	for _, path := range paths {
+		n, err := note.Read(path)
+		if err != nil {
+			// A broken query note should not hide the others.
+			continue
+		}
+
+		if q := n.Frontmatter.String("query"); q != "" {
+			saved[strings.TrimSuffix(filepath.Base(path), ".md")] = q
+		}
+	}
+
+	return saved, nil
+}
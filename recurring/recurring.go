@@ -0,0 +1,150 @@
+package recurring
+
+import (
+	"fmt"
+	"sort"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	"github.com/t-eckert/nb/note"
+	"gopkg.in/yaml.v2"
+)
+
+// Event is the next occurrence of a date that comes around every year, such
+// as a birthday from a People page or an anniversary listed under
+// `recurring_dates` in the frontmatter of a note.
+type Event struct {
+	Title string
+	Label string
+	Date  time.Time
+	// Years since the original date, or zero when its year is unknown.
+	Years int
+}
+
+func (e Event) String() string {
+	when := "on " + date.Format(e.Date, "Mon 2 Jan", config.Locale())
+
+	if e.Label == "birthday" {
+		if e.Years == 0 {
+			return fmt.Sprintf("%s's birthday is %s", e.Title, when)
+		}
+		return fmt.Sprintf("%s turns %d %s", e.Title, e.Years, when)
+	}
+
+	if e.Years == 0 {
+		return fmt.Sprintf("%s for '%s' %s", e.Label, e.Title, when)
+	}
+	return fmt.Sprintf("%s (%d years) for '%s' %s", e.Label, e.Years, e.Title, when)
+}
+
+// Upcoming returns the events in the notebook falling within their lead time
+// of the given day, soonest first. Each `recurring_dates` entry may set its
+// own `lead` in days; otherwise lead is used.
+func Upcoming(root string, day time.Time, lead int) ([]Event, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	start := time.Date(day.Year(), day.Month(), day.Day(), 0, 0, 0, 0, day.Location())
+
+	events := []Event{}
+	for _, path := range paths {
+		n, err := note.Read(path)
+		if err != nil {
+			continue
+		}
+
+		for _, d := range dates(n) {
+			if d.lead == 0 {
+				d.lead = lead
+			}
+
+			original, hasYear, ok := parseDate(d.date, start.Location())
+			if !ok {
+				continue
+			}
+
+			next := time.Date(start.Year(), original.Month(), original.Day(), 0, 0, 0, 0, start.Location())
+			if next.Before(start) {
+				next = next.AddDate(1, 0, 0)
+			}
+
+			if !next.Before(start.AddDate(0, 0, d.lead+1)) {
+				continue
+			}
+
+			event := Event{Title: n.Title(), Label: d.label, Date: next}
+			if hasYear {
+				event.Years = next.Year() - original.Year()
+			}
+			events = append(events, event)
+		}
+	}
+
+	sort.Slice(events, func(i, j int) bool {
+		return events[i].Date.Before(events[j].Date)
+	})
+
+	return events, nil
+}
+
+type recurringDate struct {
+	date  string
+	label string
+	lead  int
+}
+
+// dates collects the `birthday` key and the `recurring_dates` list of a note.
+// Entries of the list are either a bare date or a mapping with `date`,
+// `label`, and `lead` keys.
+func dates(n *note.Note) []recurringDate {
+	found := []recurringDate{}
+
+	if birthday := n.Frontmatter.String("birthday"); birthday != "" {
+		found = append(found, recurringDate{date: birthday, label: "birthday"})
+	}
+
+	value, ok := n.Frontmatter.Get("recurring_dates")
+	if !ok {
+		return found
+	}
+
+	entries, ok := value.([]interface{})
+	if !ok {
+		return found
+	}
+
+	for _, entry := range entries {
+		switch e := entry.(type) {
+		case string:
+			found = append(found, recurringDate{date: e, label: "Anniversary"})
+		case yaml.MapSlice:
+			fm := note.Frontmatter(e)
+			d := recurringDate{date: fm.String("date"), label: fm.String("label")}
+			if d.label == "" {
+				d.label = "Anniversary"
+			}
+			if lead, ok := fm.Get("lead"); ok {
+				d.lead, _ = lead.(int)
+			}
+			found = append(found, d)
+		}
+	}
+
+	return found
+}
+
+// parseDate accepts YYYY-MM-DD, or --MM-DD when the year is not known.
+func parseDate(value string, loc *time.Location) (time.Time, bool, bool) {
+	if t, err := time.ParseInLocation(note.DateFormat, value, loc); err == nil {
+		return t, true, true
+	}
+
+	if t, err := time.ParseInLocation("--01-02", value, loc); err == nil {
+		return t, false, true
+	}
+
+	return time.Time{}, false, false
+}
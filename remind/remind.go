@@ -0,0 +1,87 @@
+package remind
+
+import (
+	"sort"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+const Key = "remind"
+
+// Due returns every note whose `remind` date is on or before the given day.
+func Due(root string, day time.Time) ([]*note.Note, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	due := []*note.Note{}
+	for _, path := range paths {
+		n, err := note.Read(path)
+		if err != nil {
+			// Malformed frontmatter should not hide the rest of the reminders.
+			continue
+		}
+
+		date, ok := n.Frontmatter.Date(Key)
+		if !ok {
+			continue
+		}
+
+		if date.Format(note.DateFormat) <= day.Format(note.DateFormat) {
+			due = append(due, n)
+		}
+	}
+
+	return due, nil
+}
+
+// Notes returns the reminders set with the `remind` key in the frontmatter of
+// the notes under root, earliest first. They are due at the start of their day
+// and are named after the title of the note.
+func Notes(root string) ([]Reminder, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	reminders := []Reminder{}
+	for _, path := range paths {
+		n, err := note.Read(path)
+		if err != nil {
+			continue
+		}
+
+		if at, ok := n.Frontmatter.Date(Key); ok {
+			reminders = append(reminders, Reminder{Task: task.Task{Path: path, Text: n.Title()}, At: at})
+		}
+	}
+
+	sort.Slice(reminders, func(i, j int) bool {
+		return reminders[i].At.Before(reminders[j].At)
+	})
+
+	return reminders, nil
+}
+
+// Clear removes the reminder from the note, rereading it with the note locked
+// so that changes made since it was read are kept.
+func Clear(n *note.Note) error {
+	n.Frontmatter.Delete(Key)
+	return note.UpdateNote(n.Path, func(current *note.Note) error {
+		current.Frontmatter.Delete(Key)
+		return nil
+	})
+}
+
+// Reschedule moves the reminder of the note to date, rereading it with the
+// note locked so that changes made since it was read are kept.
+func Reschedule(n *note.Note, date time.Time) error {
+	n.Frontmatter.Set(Key, date.Format(note.DateFormat))
+	return note.UpdateNote(n.Path, func(current *note.Note) error {
+		current.Frontmatter.Set(Key, date.Format(note.DateFormat))
+		return nil
+	})
+}
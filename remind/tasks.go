@@ -0,0 +1,56 @@
+package remind
+
+import (
+	"os"
+	"sort"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/task"
+)
+
+// Reminder is an open task with a `remind:` token.
+type Reminder struct {
+	task.Task
+	At time.Time
+}
+
+// Tasks returns the reminders on open tasks in the logs, earliest first. A task
+// carried across several logs is only reminded about once, and completing it
+// in any later log drops the reminder.
+func Tasks(logDir string) ([]Reminder, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return nil, err
+	}
+
+	byID := map[string]Reminder{}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		for _, t := range task.Parse(path, string(content)) {
+			if t.Done {
+				delete(byID, t.ID())
+				continue
+			}
+
+			if at, ok := t.Remind(); ok {
+				byID[t.ID()] = Reminder{Task: t, At: at}
+			}
+		}
+	}
+
+	reminders := []Reminder{}
+	for _, r := range byID {
+		reminders = append(reminders, r)
+	}
+
+	sort.Slice(reminders, func(i, j int) bool {
+		return reminders[i].At.Before(reminders[j].At)
+	})
+
+	return reminders, nil
+}
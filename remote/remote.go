@@ -0,0 +1,39 @@
+package remote
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"time"
+
+	"github.com/t-eckert/nb/network"
+)
+
+// Store is somewhere off the machine to keep copies of the notebook.
+type Store interface {
+	Name() string
+
+	// Put uploads size bytes from body as the file called name, replacing
+	// any file already there.
+	Put(name string, body io.Reader, size int64) error
+}
+
+func do(req *http.Request) error {
+	client, err := network.Client(5 * time.Minute)
+	if err != nil {
+		return err
+	}
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		body, _ := io.ReadAll(io.LimitReader(resp.Body, 512))
+		return fmt.Errorf("unexpected status %s: %s", resp.Status, body)
+	}
+
+	return nil
+}
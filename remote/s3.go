@@ -0,0 +1,125 @@
+package remote
+
+import (
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// S3 stores files in an Amazon S3 bucket, or a bucket of a service with the
+// same API when Endpoint is set.
+type S3 struct {
+	Bucket          string
+	Region          string
+	Prefix          string
+	AccessKeyID     string
+	SecretAccessKey string
+	// SessionToken is set along with temporary credentials.
+	SessionToken string
+
+	// Endpoint is the address of an S3 compatible service, such as MinIO or
+	// Backblaze B2. Buckets there are addressed by path.
+	Endpoint string
+}
+
+func (s *S3) Name() string {
+	return "S3"
+}
+
+func (s *S3) Put(name string, body io.Reader, size int64) error {
+	key := strings.Trim(s.Prefix, "/")
+	if key != "" {
+		key += "/"
+	}
+	key += name
+
+	req, err := http.NewRequest("PUT", s.url(key), body)
+	if err != nil {
+		return err
+	}
+	req.ContentLength = size
+
+	s.sign(req, time.Now().UTC())
+
+	return do(req)
+}
+
+func (s *S3) url(key string) string {
+	segments := []string{}
+	for _, segment := range strings.Split(key, "/") {
+		segments = append(segments, url.PathEscape(segment))
+	}
+	path := strings.Join(segments, "/")
+
+	if s.Endpoint != "" {
+		return strings.TrimRight(s.Endpoint, "/") + "/" + url.PathEscape(s.Bucket) + "/" + path
+	}
+
+	return fmt.Sprintf("https://%s.s3.%s.amazonaws.com/%s", s.Bucket, s.region(), path)
+}
+
+func (s *S3) region() string {
+	if s.Region == "" {
+		return "us-east-1"
+	}
+
+	return s.Region
+}
+
+// sign adds an AWS Signature Version 4 to the request. The body is left out
+// of the signature so that it can be streamed.
+func (s *S3) sign(req *http.Request, now time.Time) {
+	const payload = "UNSIGNED-PAYLOAD"
+
+	amzDate := now.Format("20060102T150405Z")
+	day := now.Format("20060102")
+
+	req.Header.Set("x-amz-date", amzDate)
+	req.Header.Set("x-amz-content-sha256", payload)
+
+	signed := "host;x-amz-content-sha256;x-amz-date"
+	headers := "host:" + req.URL.Host + "\n" +
+		"x-amz-content-sha256:" + payload + "\n" +
+		"x-amz-date:" + amzDate + "\n"
+	if s.SessionToken != "" {
+		req.Header.Set("x-amz-security-token", s.SessionToken)
+		signed += ";x-amz-security-token"
+		headers += "x-amz-security-token:" + s.SessionToken + "\n"
+	}
+
+	canonical := strings.Join([]string{
+		req.Method,
+		req.URL.EscapedPath(),
+		req.URL.RawQuery,
+		headers,
+		signed,
+		payload,
+	}, "\n")
+
+	scope := day + "/" + s.region() + "/s3/aws4_request"
+	hash := sha256.Sum256([]byte(canonical))
+	toSign := "AWS4-HMAC-SHA256\n" + amzDate + "\n" + scope + "\n" + hex.EncodeToString(hash[:])
+
+	key := hmacSHA256([]byte("AWS4"+s.SecretAccessKey), day)
+	key = hmacSHA256(key, s.region())
+	key = hmacSHA256(key, "s3")
+	key = hmacSHA256(key, "aws4_request")
+	signature := hex.EncodeToString(hmacSHA256(key, toSign))
+
+	req.Header.Set("Authorization", fmt.Sprintf(
+		"AWS4-HMAC-SHA256 Credential=%s/%s, SignedHeaders=%s, Signature=%s",
+		s.AccessKeyID, scope, signed, signature,
+	))
+}
+
+func hmacSHA256(key []byte, data string) []byte {
+	mac := hmac.New(sha256.New, key)
+	mac.Write([]byte(data))
+	return mac.Sum(nil)
+}
@@ -0,0 +1,34 @@
+package remote
+
+import (
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+)
+
+// WebDAV stores files in a folder of a WebDAV server, such as Nextcloud or a
+// NAS.
+type WebDAV struct {
+	URL      string
+	Username string
+	Password string
+}
+
+func (w *WebDAV) Name() string {
+	return "WebDAV"
+}
+
+func (w *WebDAV) Put(name string, body io.Reader, size int64) error {
+	req, err := http.NewRequest("PUT", strings.TrimRight(w.URL, "/")+"/"+url.PathEscape(name), body)
+	if err != nil {
+		return err
+	}
+	req.ContentLength = size
+
+	if w.Username != "" {
+		req.SetBasicAuth(w.Username, w.Password)
+	}
+
+	return do(req)
+}
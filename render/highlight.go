@@ -0,0 +1,190 @@
+package render
+
+import (
+	"html"
+	"strings"
+)
+
+// syntax is what the highlighter needs to know about a language.
+type syntax struct {
+	keywords []string
+	// comment starts a comment that runs to the end of the line, and block
+	// holds the delimiters of comments that can span lines.
+	comment string
+	block   [2]string
+	quotes  string
+}
+
+var (
+	cLike = syntax{
+		keywords: []string{"auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum", "extern", "float", "for", "goto", "if", "int", "long", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef", "union", "unsigned", "void", "volatile", "while", "class", "namespace", "template", "public", "private", "protected", "new", "delete", "true", "false", "nullptr", "#include", "#define"},
+		comment:  "//",
+		block:    [2]string{"/*", "*/"},
+		quotes:   `"'`,
+	}
+	javascript = syntax{
+		keywords: []string{"async", "await", "break", "case", "catch", "class", "const", "continue", "default", "delete", "do", "else", "export", "extends", "false", "finally", "for", "from", "function", "if", "import", "in", "instanceof", "interface", "let", "new", "null", "of", "return", "static", "super", "switch", "this", "throw", "true", "try", "type", "typeof", "undefined", "var", "void", "while", "yield"},
+		comment:  "//",
+		block:    [2]string{"/*", "*/"},
+		quotes:   "\"'`",
+	}
+	golang = syntax{
+		keywords: []string{"break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough", "for", "func", "go", "goto", "if", "import", "interface", "map", "package", "range", "return", "select", "struct", "switch", "type", "var", "nil", "true", "false"},
+		comment:  "//",
+		block:    [2]string{"/*", "*/"},
+		quotes:   "\"'`",
+	}
+	rust = syntax{
+		keywords: []string{"as", "async", "await", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while"},
+		comment:  "//",
+		block:    [2]string{"/*", "*/"},
+		quotes:   `"`,
+	}
+	python = syntax{
+		keywords: []string{"and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else", "except", "False", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "None", "nonlocal", "not", "or", "pass", "raise", "return", "True", "try", "while", "with", "yield"},
+		comment:  "#",
+		quotes:   `"'`,
+	}
+	sql = syntax{
+		keywords: []string{"select", "from", "where", "and", "or", "not", "insert", "into", "values", "update", "set", "delete", "create", "table", "drop", "alter", "join", "left", "right", "inner", "outer", "on", "group", "by", "order", "having", "limit", "as", "null", "is", "in", "distinct", "SELECT", "FROM", "WHERE", "AND", "OR", "NOT", "INSERT", "INTO", "VALUES", "UPDATE", "SET", "DELETE", "CREATE", "TABLE", "DROP", "ALTER", "JOIN", "LEFT", "RIGHT", "INNER", "OUTER", "ON", "GROUP", "BY", "ORDER", "HAVING", "LIMIT", "AS", "NULL", "IS", "IN", "DISTINCT"},
+		comment:  "--",
+		block:    [2]string{"/*", "*/"},
+		quotes:   `'"`,
+	}
+	yaml = syntax{
+		keywords: []string{"true", "false", "null", "yes", "no"},
+		comment:  "#",
+		quotes:   `"'`,
+	}
+	json = syntax{
+		keywords: []string{"true", "false", "null"},
+		quotes:   `"`,
+	}
+	shell = syntax{
+		keywords: []string{"if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac", "in", "function", "return", "export", "local", "echo", "exit"},
+		comment:  "#",
+		quotes:   `"'`,
+	}
+)
+
+// languages are the languages of code fences that are highlighted, by the
+// names they are written with.
+var languages = map[string]syntax{
+	"go":         golang,
+	"rust":       rust,
+	"rs":         rust,
+	"python":     python,
+	"py":         python,
+	"sql":        sql,
+	"yaml":       yaml,
+	"yml":        yaml,
+	"json":       json,
+	"c":          cLike,
+	"cpp":        cLike,
+	"c++":        cLike,
+	"java":       cLike,
+	"javascript": javascript,
+	"js":         javascript,
+	"typescript": javascript,
+	"ts":         javascript,
+	"sh":         shell,
+	"bash":       shell,
+	"shell":      shell,
+	"zsh":        shell,
+}
+
+// highlight marks up the keywords, strings, comments, and numbers of code in
+// lang with classes for the stylesheet. Code in languages it does not know is
+// only escaped.
+func highlight(lang, code string) string {
+	s, ok := languages[strings.ToLower(lang)]
+	if !ok {
+		return html.EscapeString(code)
+	}
+
+	keywords := map[string]bool{}
+	for _, k := range s.keywords {
+		keywords[k] = true
+	}
+
+	var b strings.Builder
+	span := func(class, text string) {
+		b.WriteString(`<span class="hl-` + class + `">` + html.EscapeString(text) + "</span>")
+	}
+
+	for i := 0; i < len(code); {
+		rest := code[i:]
+
+		switch {
+		case s.comment != "" && strings.HasPrefix(rest, s.comment):
+			end := strings.IndexByte(rest, '\n')
+			if end == -1 {
+				end = len(rest)
+			}
+			span("comment", rest[:end])
+			i += end
+		case s.block[0] != "" && strings.HasPrefix(rest, s.block[0]):
+			end := strings.Index(rest[len(s.block[0]):], s.block[1])
+			if end == -1 {
+				end = len(rest)
+			} else {
+				end += len(s.block[0]) + len(s.block[1])
+			}
+			span("comment", rest[:end])
+			i += end
+		case strings.IndexByte(s.quotes, rest[0]) != -1:
+			end := quoted(rest)
+			span("string", rest[:end])
+			i += end
+		case isDigit(rest[0]) && (i == 0 || !isWord(code[i-1])):
+			end := 1
+			for end < len(rest) && (isWord(rest[end]) || rest[end] == '.') {
+				end++
+			}
+			span("number", rest[:end])
+			i += end
+		case isWord(rest[0]) || rest[0] == '#':
+			end := 1
+			for end < len(rest) && isWord(rest[end]) {
+				end++
+			}
+			if keywords[rest[:end]] {
+				span("keyword", rest[:end])
+			} else {
+				b.WriteString(html.EscapeString(rest[:end]))
+			}
+			i += end
+		default:
+			b.WriteString(html.EscapeString(rest[:1]))
+			i++
+		}
+	}
+
+	return b.String()
+}
+
+// quoted is the length of the string literal at the start of text, up to the
+// closing quote or the end of the line. Backquoted strings can span lines.
+func quoted(text string) int {
+	quote := text[0]
+	for i := 1; i < len(text); i++ {
+		switch {
+		case text[i] == '\\' && quote != '`':
+			i++
+		case text[i] == quote:
+			return i + 1
+		case text[i] == '\n' && quote != '`':
+			return i
+		}
+	}
+
+	return len(text)
+}
+
+func isWord(c byte) bool {
+	return c == '_' || isDigit(c) || c >= 'a' && c <= 'z' || c >= 'A' && c <= 'Z'
+}
+
+func isDigit(c byte) bool {
+	return c >= '0' && c <= '9'
+}
@@ -0,0 +1,328 @@
+package render
+
+import (
+	"fmt"
+	"html"
+	"regexp"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+var (
+	ordered = regexp.MustCompile(`^(\s*)\d+[.)] (.*)$`)
+	rule    = regexp.MustCompile(`^\s*(?:(?:-\s*){3,}|(?:\*\s*){3,}|(?:_\s*){3,})$`)
+	divider = regexp.MustCompile(`^\s*\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?\s*$`)
+
+	// Inline math may not start or end with a space, so that prices such as
+	// "$5 and $10" stay text.
+	math = regexp.MustCompile(`\$\$[^$]+\$\$|\$[^$\s](?:[^$]*[^$\s])?\$`)
+)
+
+// Document is a note rendered as HTML.
+type Document struct {
+	Body string
+	// Mermaid is set when the note has diagrams for mermaid.js to draw.
+	Mermaid bool
+	// Math is set when the note has TeX for KaTeX to typeset.
+	Math bool
+}
+
+// HTML renders a note for the browser. The frontmatter is left out, code
+// fences keep their language as a class and are highlighted, and mermaid
+// fences are kept as diagrams for mermaid.js. Wikilinks point at the address
+// resolve returns for their target, or are left unlinked when it returns
+// nothing. TeX between $ and $$ is kept as written, with its delimiters, in
+// elements of class math.
+func HTML(content string, resolve func(target string) string) Document {
+	if n, err := note.Parse(content); err == nil {
+		content = n.Body
+	}
+
+	h := &htmlWriter{resolve: resolve}
+	lines := strings.Split(strings.TrimRight(content, "\n"), "\n")
+	for i := 0; i < len(lines); i++ {
+		line := lines[i]
+
+		if fence := strings.TrimSpace(line); strings.HasPrefix(fence, "```") {
+			h.close()
+			lang := strings.TrimSpace(strings.TrimPrefix(fence, "```"))
+
+			code := []string{}
+			for i++; i < len(lines) && !strings.HasPrefix(strings.TrimSpace(lines[i]), "```"); i++ {
+				code = append(code, lines[i])
+			}
+			h.code(lang, strings.Join(code, "\n"))
+			continue
+		}
+
+		if strings.TrimSpace(line) == "$$" {
+			h.close()
+
+			tex := []string{}
+			for i++; i < len(lines) && strings.TrimSpace(lines[i]) != "$$"; i++ {
+				tex = append(tex, lines[i])
+			}
+			h.math = true
+			h.b.WriteString(`<div class="math display">$$` + html.EscapeString(strings.Join(tex, "\n")) + "$$</div>\n")
+			continue
+		}
+
+		if strings.TrimSpace(line) == "" {
+			h.close()
+			continue
+		}
+
+		if level, text := note.Heading(line); level > 0 {
+			h.close()
+			fmt.Fprintf(&h.b, "<h%d>%s</h%d>\n", level, h.inline(text), level)
+			continue
+		}
+
+		if rule.MatchString(line) {
+			h.close()
+			h.b.WriteString("<hr>\n")
+			continue
+		}
+
+		if strings.HasPrefix(line, "|") && i+1 < len(lines) && divider.MatchString(lines[i+1]) {
+			h.close()
+			rows := []string{line}
+			for i += 2; i < len(lines) && strings.HasPrefix(lines[i], "|"); i++ {
+				rows = append(rows, lines[i])
+			}
+			i--
+			h.table(rows)
+			continue
+		}
+
+		if match := checkbox.FindStringSubmatch(line); match != nil {
+			done := match[2] == "x" || match[2] == "X"
+			class, checked := "", ""
+			if done {
+				class, checked = ` class="done"`, " checked"
+			}
+			h.item(len(match[1]), "ul", fmt.Sprintf(`<li%s><input type="checkbox" disabled%s> %s`, class, checked, h.inline(match[3])))
+			continue
+		}
+
+		if match := bullet.FindStringSubmatch(line); match != nil {
+			h.item(len(match[1]), "ul", "<li>"+h.inline(match[2]))
+			continue
+		}
+
+		if match := ordered.FindStringSubmatch(line); match != nil {
+			h.item(len(match[1]), "ol", "<li>"+h.inline(match[2]))
+			continue
+		}
+
+		if strings.HasPrefix(line, ">") {
+			h.block("blockquote")
+			h.paragraph(strings.TrimSpace(strings.TrimPrefix(line, ">")))
+			continue
+		}
+
+		if len(h.lists) > 0 {
+			// Text under a list item continues it.
+			h.b.WriteString(" " + h.inline(strings.TrimSpace(line)))
+			continue
+		}
+
+		h.paragraph(strings.TrimSpace(line))
+	}
+	h.close()
+
+	return Document{Body: h.b.String(), Mermaid: h.mermaid, Math: h.math}
+}
+
+type list struct {
+	indent int
+	tag    string
+}
+
+type htmlWriter struct {
+	b       strings.Builder
+	resolve func(string) string
+	mermaid bool
+	math    bool
+
+	lists []list
+	// quote is set inside a blockquote, and open inside a paragraph.
+	quote bool
+	open  bool
+}
+
+func (h *htmlWriter) close() {
+	h.closeParagraph()
+	for len(h.lists) > 0 {
+		h.popList()
+	}
+	if h.quote {
+		h.b.WriteString("</blockquote>\n")
+		h.quote = false
+	}
+}
+
+func (h *htmlWriter) closeParagraph() {
+	if h.open {
+		h.b.WriteString("</p>\n")
+		h.open = false
+	}
+}
+
+func (h *htmlWriter) block(tag string) {
+	if !h.quote {
+		h.close()
+		h.b.WriteString("<" + tag + ">\n")
+		h.quote = true
+	}
+}
+
+func (h *htmlWriter) paragraph(text string) {
+	if h.open {
+		h.b.WriteString("\n" + h.inline(text))
+		return
+	}
+
+	h.b.WriteString("<p>" + h.inline(text))
+	h.open = true
+}
+
+func (h *htmlWriter) popList() {
+	h.b.WriteString("</li>\n</" + h.lists[len(h.lists)-1].tag + ">\n")
+	h.lists = h.lists[:len(h.lists)-1]
+}
+
+func (h *htmlWriter) item(indent int, tag, li string) {
+	h.closeParagraph()
+	if h.quote {
+		h.close()
+	}
+
+	for len(h.lists) > 0 && h.lists[len(h.lists)-1].indent > indent {
+		h.popList()
+	}
+
+	switch top := len(h.lists) - 1; {
+	case top >= 0 && h.lists[top].indent == indent && h.lists[top].tag == tag:
+		h.b.WriteString("</li>\n")
+	case top >= 0 && h.lists[top].indent == indent:
+		h.popList()
+		h.b.WriteString("<" + tag + ">\n")
+		h.lists = append(h.lists, list{indent, tag})
+	default:
+		if top >= 0 {
+			h.b.WriteString("\n")
+		}
+		h.b.WriteString("<" + tag + ">\n")
+		h.lists = append(h.lists, list{indent, tag})
+	}
+
+	h.b.WriteString(li)
+}
+
+func (h *htmlWriter) code(lang, code string) {
+	if lang == "mermaid" {
+		h.mermaid = true
+		h.b.WriteString(`<pre class="mermaid">` + html.EscapeString(code) + "</pre>\n")
+		return
+	}
+
+	class := ""
+	if lang != "" {
+		class = ` class="language-` + html.EscapeString(lang) + `"`
+	}
+	h.b.WriteString("<pre><code" + class + ">" + highlight(lang, code) + "</code></pre>\n")
+}
+
+func (h *htmlWriter) table(rows []string) {
+	h.b.WriteString("<table>\n")
+	for i, row := range rows {
+		cell := "td"
+		if i == 0 {
+			cell = "th"
+		}
+
+		h.b.WriteString("<tr>")
+		for _, text := range strings.Split(strings.Trim(strings.TrimSpace(row), "|"), "|") {
+			h.b.WriteString("<" + cell + ">" + h.inline(strings.TrimSpace(text)) + "</" + cell + ">")
+		}
+		h.b.WriteString("</tr>\n")
+	}
+	h.b.WriteString("</table>\n")
+}
+
+// inline renders the emphasis, code, and links of a line of text.
+func (h *htmlWriter) inline(text string) string {
+	// Code spans and math are set aside so nothing inside them is formatted.
+	spans := []string{}
+	text = code.ReplaceAllStringFunc(text, func(m string) string {
+		spans = append(spans, "<code>"+html.EscapeString(code.FindStringSubmatch(m)[1])+"</code>")
+		return fmt.Sprintf("\x00%d\x00", len(spans)-1)
+	})
+	text = math.ReplaceAllStringFunc(text, func(m string) string {
+		class := "math"
+		if strings.HasPrefix(m, "$$") {
+			class = "math display"
+		}
+		h.math = true
+		spans = append(spans, `<span class="`+class+`">`+html.EscapeString(m)+"</span>")
+		return fmt.Sprintf("\x00%d\x00", len(spans)-1)
+	})
+
+	text = html.EscapeString(text)
+	text = bold.ReplaceAllString(text, "<strong>$1</strong>")
+	text = italic.ReplaceAllString(text, "$1<em>$2</em>")
+	text = wikilink.ReplaceAllStringFunc(text, func(m string) string {
+		match := wikilink.FindStringSubmatch(m)
+		label := match[1]
+		if match[2] != "" {
+			label = match[2]
+		}
+
+		href := ""
+		if h.resolve != nil {
+			href = h.resolve(html.UnescapeString(match[1]))
+		}
+		if href == "" {
+			return `<span class="missing">` + label + "</span>"
+		}
+		return `<a href="` + html.EscapeString(href) + `">` + label + "</a>"
+	})
+	text = link.ReplaceAllStringFunc(text, func(m string) string {
+		match := link.FindStringSubmatch(m)
+		if !safeURL(html.UnescapeString(match[2])) {
+			return match[1]
+		}
+		return `<a href="` + match[2] + `">` + match[1] + "</a>"
+	})
+
+	for i, span := range spans {
+		text = strings.Replace(text, fmt.Sprintf("\x00%d\x00", i), span, 1)
+	}
+
+	return text
+}
+
+// safeURL is whether a link may point at href: relative addresses and ones
+// with the http, https, or mailto scheme. Addresses with control characters,
+// which browsers drop before reading the scheme, are refused.
+func safeURL(href string) bool {
+	for _, r := range href {
+		if r < 0x20 || r == 0x7f {
+			return false
+		}
+	}
+
+	end := strings.IndexAny(href, ":/?#")
+	if end == -1 || href[end] != ':' {
+		return true
+	}
+
+	switch strings.ToLower(href[:end]) {
+	case "http", "https", "mailto":
+		return true
+	}
+
+	return false
+}
@@ -0,0 +1,137 @@
+package render
+
+import (
+	"regexp"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Style is how a kind of text is shown. Empty styles leave the text plain.
+type Style struct {
+	Heading string
+	Done    string
+	Code    string
+	Link    string
+	Quote   string
+	Bold    string
+	Italic  string
+}
+
+const reset = "\033[0m"
+
+// Colors is the style used on terminals.
+var Colors = Style{
+	Heading: "\033[1;36m",
+	Done:    "\033[2;9m",
+	Code:    "\033[33m",
+	Link:    "\033[4;34m",
+	Quote:   "\033[2m",
+	Bold:    "\033[1m",
+	Italic:  "\033[3m",
+}
+
+// Plain is the style used when the output is not a terminal.
+var Plain = Style{}
+
+var (
+	checkbox = regexp.MustCompile(`^(\s*)[-*+] \[([ xX/])\] (.*)$`)
+	bullet   = regexp.MustCompile(`^(\s*)[-*+] (.*)$`)
+
+	code     = regexp.MustCompile("`([^`]+)`")
+	bold     = regexp.MustCompile(`\*\*([^*]+)\*\*`)
+	italic   = regexp.MustCompile(`(^|[^*\w])[*_]([^*_]+)[*_]`)
+	link     = regexp.MustCompile(`\[([^\]]+)\]\(([^)\s]+)\)`)
+	wikilink = regexp.MustCompile(`\[\[([^\]|]+)(?:\|([^\]]+))?\]\]`)
+)
+
+// Markdown renders a note for reading in a terminal: headings and inline
+// emphasis are styled, checkboxes and bullets become symbols, code fences are
+// dropped in favour of indented, styled code, and the frontmatter is left out.
+func Markdown(content string, s Style) string {
+	if n, err := note.Parse(content); err == nil {
+		content = n.Body
+	}
+
+	out := []string{}
+	fenced := false
+	for _, line := range strings.Split(strings.TrimRight(content, "\n"), "\n") {
+		if strings.HasPrefix(strings.TrimSpace(line), "```") {
+			fenced = !fenced
+			continue
+		}
+
+		if fenced {
+			out = append(out, "    "+s.apply(s.Code, line))
+			continue
+		}
+
+		out = append(out, s.line(line))
+	}
+
+	return strings.Join(out, "\n") + "\n"
+}
+
+func (s Style) line(line string) string {
+	if level, text := note.Heading(line); level > 0 {
+		text = s.inline(text)
+		if level == 1 {
+			text = strings.ToUpper(text)
+		}
+		return s.apply(s.Heading, text)
+	}
+
+	if match := checkbox.FindStringSubmatch(line); match != nil {
+		switch match[2] {
+		case "x", "X":
+			return match[1] + "☑ " + s.apply(s.Done, match[3])
+		case "/":
+			return match[1] + "◐ " + s.inline(match[3])
+		}
+		return match[1] + "☐ " + s.inline(match[3])
+	}
+
+	if match := bullet.FindStringSubmatch(line); match != nil {
+		return match[1] + "• " + s.inline(match[2])
+	}
+
+	if strings.HasPrefix(line, ">") {
+		return s.apply(s.Quote, "│ "+strings.TrimSpace(strings.TrimPrefix(line, ">")))
+	}
+
+	return s.inline(line)
+}
+
+func (s Style) inline(text string) string {
+	text = code.ReplaceAllStringFunc(text, func(m string) string {
+		return s.apply(s.Code, code.FindStringSubmatch(m)[1])
+	})
+	text = bold.ReplaceAllStringFunc(text, func(m string) string {
+		return s.apply(s.Bold, bold.FindStringSubmatch(m)[1])
+	})
+	text = italic.ReplaceAllStringFunc(text, func(m string) string {
+		match := italic.FindStringSubmatch(m)
+		return match[1] + s.apply(s.Italic, match[2])
+	})
+	text = wikilink.ReplaceAllStringFunc(text, func(m string) string {
+		match := wikilink.FindStringSubmatch(m)
+		if match[2] != "" {
+			return s.apply(s.Link, match[2])
+		}
+		return s.apply(s.Link, match[1])
+	})
+	text = link.ReplaceAllStringFunc(text, func(m string) string {
+		match := link.FindStringSubmatch(m)
+		return s.apply(s.Link, match[1]) + " (" + match[2] + ")"
+	})
+
+	return text
+}
+
+func (s Style) apply(style, text string) string {
+	if style == "" || text == "" {
+		return text
+	}
+
+	return style + text + reset
+}
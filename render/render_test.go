@@ -0,0 +1,96 @@
+package render
+
+import (
+	"testing"
+)
+
+func TestMarkdown(t *testing.T) {
+	given := "---\ntitle: x\n---\n# Day\n\n- [ ] Call **Sam**\n- [x] Done\n- see [[Link|alias]]\n```\ncode\n```\n"
+	expected := "DAY\n\n☐ Call Sam\n☑ Done\n• see alias\n    code\n"
+
+	actual := Markdown(given, Plain)
+
+	if expected != actual {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
+
+func TestHTML(t *testing.T) {
+	given := "# Plan\n\nSee [[Alpha]] and `a*b*c`.\n\n- [x] Draft\n- Review\n  - **Legal**\n\n```mermaid\ngraph TD; A-->B\n```\n"
+	expected := `<h1>Plan</h1>
+<p>See <a href="/notes/Alpha.md">Alpha</a> and <code>a*b*c</code>.</p>
+<ul>
+<li class="done"><input type="checkbox" disabled checked> Draft</li>
+<li>Review
+<ul>
+<li><strong>Legal</strong></li>
+</ul>
+</li>
+</ul>
+<pre class="mermaid">graph TD; A--&gt;B</pre>
+`
+
+	actual := HTML(given, func(target string) string { return "/notes/" + target + ".md" })
+
+	if expected != actual.Body || !actual.Mermaid {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual.Body)
+	}
+}
+
+func TestHTMLMath(t *testing.T) {
+	given := "Energy is $E = m_1 c^2$, not $5 or $10.\n"
+	expected := `<p>Energy is <span class="math">$E = m_1 c^2$</span>, not $5 or $10.</p>
+`
+
+	actual := HTML(given, nil)
+
+	if expected != actual.Body || !actual.Math {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual.Body)
+	}
+}
+
+func TestHighlight(t *testing.T) {
+	given := "x := \"a<b\" // 1\nreturn 42"
+	expected := `x := <span class="hl-string">&#34;a&lt;b&#34;</span> <span class="hl-comment">// 1</span>
+<span class="hl-keyword">return</span> <span class="hl-number">42</span>`
+
+	actual := highlight("go", given)
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
+
+func TestHTMLLinks(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"[a](https://example.com)", `<p><a href="https://example.com">a</a></p>` + "\n"},
+		{"[a](../b.md)", `<p><a href="../b.md">a</a></p>` + "\n"},
+		{"[a](mailto:me@example.com)", `<p><a href="mailto:me@example.com">a</a></p>` + "\n"},
+		{"[a](\x01javascript:alert)", "<p>a</p>\n"},
+		{"[a](JavaScript:alert)", "<p>a</p>\n"},
+		{"[a](vbscript:msgbox)", "<p>a</p>\n"},
+		{"[a](data:text/html,hi)", "<p>a</p>\n"},
+	}
+
+	for _, c := range cases {
+		actual := HTML(c.given, nil)
+		if c.expected != actual.Body {
+			t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", c.given, c.expected, actual.Body)
+		}
+	}
+}
+
+func TestHTMLRule(t *testing.T) {
+	for _, given := range []string{"---", "* * *", "___", "- - -"} {
+		expected := "<hr>\n"
+
+		actual := HTML(given, nil)
+
+		if expected != actual.Body {
+			t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual.Body)
+		}
+	}
+}
@@ -0,0 +1,59 @@
+// Package rfc reads the content lines shared by iCalendar (RFC 5545) and
+// vCard (RFC 6350) files.
+package rfc
+
+import (
+	"bufio"
+	"io"
+	"strings"
+)
+
+// Unfold reads the content lines of r, joining continuation lines, which start
+// with a space or a tab, onto the line before them.
+func Unfold(r io.Reader) ([]string, error) {
+	lines := []string{}
+
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 64*1024), 1024*1024)
+	for scanner.Scan() {
+		line := strings.TrimRight(scanner.Text(), "\r")
+		if len(lines) > 0 && (strings.HasPrefix(line, " ") || strings.HasPrefix(line, "\t")) {
+			lines[len(lines)-1] += line[1:]
+			continue
+		}
+		lines = append(lines, line)
+	}
+
+	return lines, scanner.Err()
+}
+
+// Property splits a content line such as `DTSTART;TZID=Europe/Berlin:2025...`
+// into its upper-cased name, without any group prefix such as `item1.`, its
+// parameters, and its value. It returns false for lines without a value.
+func Property(line string) (string, map[string]string, string, bool) {
+	colon := strings.Index(line, ":")
+	if colon == -1 {
+		return "", nil, "", false
+	}
+
+	parts := strings.Split(line[:colon], ";")
+	params := map[string]string{}
+	for _, p := range parts[1:] {
+		if i := strings.Index(p, "="); i != -1 {
+			params[strings.ToUpper(p[:i])] = strings.Trim(p[i+1:], `"`)
+		}
+	}
+
+	name := strings.ToUpper(parts[0])
+	if dot := strings.LastIndex(name, "."); dot != -1 {
+		name = name[dot+1:]
+	}
+
+	return name, params, line[colon+1:], true
+}
+
+// Unescape replaces the escaped newlines, commas, semicolons, and backslashes
+// of a text value.
+func Unescape(text string) string {
+	return strings.NewReplacer(`\n`, "\n", `\N`, "\n", `\,`, ",", `\;`, ";", `\\`, `\`).Replace(text)
+}
@@ -0,0 +1,22 @@
+package rfc
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestUnfold(t *testing.T) {
+	given := "BEGIN:VEVENT\r\nSUMMARY:Plan the\r\n  offsite\\, again\r\nEND:VEVENT\r\n"
+	expected := "Plan the offsite, again"
+
+	lines, err := Unfold(strings.NewReader(given))
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, _, value, _ := Property(lines[1])
+	actual := Unescape(value)
+
+	if len(lines) != 3 || expected != actual {
+		t.Fatalf("given: %q\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+}
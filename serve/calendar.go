@@ -0,0 +1,28 @@
+package serve
+
+import (
+	"net/http"
+	"time"
+
+	"github.com/t-eckert/nb/calendar"
+	noteLog "github.com/t-eckert/nb/log"
+)
+
+// serveCalendar writes the events and due tasks of the logs as an iCalendar
+// file, so that a calendar app can subscribe to it.
+func serveCalendar(w http.ResponseWriter, r *http.Request) {
+	logDir, err := noteLog.Dir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	events, err := calendar.FromLogs(logDir)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	w.Header().Set("Content-Type", "text/calendar; charset=utf-8")
+	w.Write([]byte(calendar.ICS(events, time.Now())))
+}
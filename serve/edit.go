@@ -0,0 +1,273 @@
+package serve
+
+import (
+	"errors"
+	"fmt"
+	"html/template"
+	"io"
+	"log"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/hook"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/note"
+)
+
+var editPage = template.Must(template.New("edit").Parse(`<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Edit {{.Path}}</title>
+<link rel="stylesheet" href="/theme.css">
+<style>
+textarea { width: 100%; min-height: 70vh; font: 0.9rem/1.5 ui-monospace, monospace; background: var(--code-background); color: var(--text); border: 1px solid var(--border); border-radius: 4px; padding: 0.75rem; box-sizing: border-box; }
+nav { display: flex; gap: 1rem; align-items: center; margin-bottom: 1rem; }
+</style>
+</head>
+<body>
+<nav>
+<a href="/notes/{{.Path}}">{{.Path}}</a>
+<button id="save">Save</button>
+<span id="message"></span>
+</nav>
+<textarea id="content" spellcheck="false">{{.Content}}</textarea>
+<script>
+let etag = {{.ETag}};
+const message = document.getElementById("message");
+const content = document.getElementById("content");
+
+async function save() {
+  const headers = etag ? { "If-Match": etag } : { "If-None-Match": "*" };
+  const resp = await fetch("/api/notes/" + {{.Path}}, { method: "PUT", headers, body: content.value });
+  if (resp.status === 412) {
+    message.textContent = "The note changed since it was opened. Reload to see the changes.";
+    return;
+  }
+  if (!resp.ok) {
+    message.textContent = "Could not save: " + await resp.text();
+    return;
+  }
+  etag = resp.headers.get("ETag");
+  message.textContent = "Saved";
+}
+
+document.getElementById("save").addEventListener("click", save);
+document.addEventListener("keydown", (e) => {
+  if ((e.ctrlKey || e.metaKey) && e.key === "s") {
+    e.preventDefault();
+    save();
+  }
+});
+content.addEventListener("input", () => { message.textContent = ""; });
+</script>
+</body>
+</html>
+`))
+
+var (
+	errChanged = errors.New("the note has changed")
+	errExists  = errors.New("the note already exists")
+)
+
+// etag identifies a version of a file by when it was modified and its size.
+func etag(info os.FileInfo) string {
+	return fmt.Sprintf(`"%x-%x"`, info.ModTime().UnixNano(), info.Size())
+}
+
+// serveEdit shows an editor for the note at /edit/<path from the root>. Notes
+// that do not exist yet are created when they are first saved.
+func serveEdit(w http.ResponseWriter, r *http.Request) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	rel := strings.TrimPrefix(r.URL.Path, "/edit/")
+	path, ok := notePath(root, rel)
+	if !ok || note.IsPrivate(path) {
+		http.NotFound(w, r)
+		return
+	}
+
+	content, tag := "", ""
+	if info, err := os.Stat(path); err == nil {
+		b, err := os.ReadFile(path)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+		content, tag = string(b), etag(info)
+	}
+
+	editPage.Execute(w, struct {
+		Path    string
+		Content string
+		ETag    string
+	}{rel, content, tag})
+}
+
+var startPage = template.Must(template.New("start").Parse(`<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Today</title>
+<link rel="stylesheet" href="/theme.css">
+</head>
+<body>
+<p>There is no log for today yet.</p>
+<form method="post" action="/today"><button>Start today's log</button></form>
+</body>
+</html>
+`))
+
+// serveToday opens today's log in the editor. When there is no log for today,
+// GET offers to start one and POST makes it, so that nothing is written by
+// following a link.
+func serveToday(w http.ResponseWriter, r *http.Request) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	var logPath string
+	switch r.Method {
+	case http.MethodGet:
+		logPath, err = noteLog.LogPath(0)
+		if err == nil {
+			if _, statErr := os.Stat(logPath); os.IsNotExist(statErr) {
+				startPage.Execute(w, nil)
+				return
+			}
+		}
+	case http.MethodPost:
+		logPath, err = noteLog.Ensure(0)
+	default:
+		w.Header().Set("Allow", "GET, POST")
+		http.Error(w, "method not allowed", http.StatusMethodNotAllowed)
+		return
+	}
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	rel, err := filepath.Rel(root, logPath)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	http.Redirect(w, r, "/edit/"+filepath.ToSlash(rel), http.StatusSeeOther)
+}
+
+// serveAPINote reads and writes the markdown of the note at
+// /api/notes/<path from the root>. Writes must carry the ETag of the version
+// they replace in If-Match, or If-None-Match: * to create a note, and fail
+// with 412 Precondition Failed when the note has changed in the meantime.
+// Writes go through note.Update, so they are locked, atomic, and recorded in
+// the history.
+// Private notes cannot be read or written.
+func serveAPINote(w http.ResponseWriter, r *http.Request) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	path, ok := notePath(root, strings.TrimPrefix(r.URL.Path, "/api/notes/"))
+	if !ok || note.IsPrivate(path) {
+		http.NotFound(w, r)
+		return
+	}
+
+	switch r.Method {
+	case http.MethodGet:
+		info, err := os.Stat(path)
+		if os.IsNotExist(err) {
+			http.NotFound(w, r)
+			return
+		} else if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+
+		w.Header().Set("Content-Type", "text/markdown; charset=utf-8")
+		w.Header().Set("ETag", etag(info))
+		w.Write(content)
+	case http.MethodPut:
+		if !writable {
+			http.Error(w, "the notebook is served read only", http.StatusForbidden)
+			return
+		}
+
+		match, noneMatch := r.Header.Get("If-Match"), r.Header.Get("If-None-Match")
+		if match == "" && noneMatch == "" {
+			http.Error(w, "If-Match or If-None-Match is required", http.StatusPreconditionRequired)
+			return
+		}
+
+		content, err := io.ReadAll(r.Body)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusBadRequest)
+			return
+		}
+
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+
+		// The preconditions are checked with the note locked, so that nothing
+		// can change it between the check and the write.
+		err = note.Update(path, func(string) (string, error) {
+			info, err := os.Stat(path)
+			exists := err == nil
+			if err != nil && !os.IsNotExist(err) {
+				return "", err
+			}
+
+			switch {
+			case match != "" && (!exists || (match != "*" && match != etag(info))):
+				return "", errChanged
+			case noneMatch == "*" && exists:
+				return "", errExists
+			}
+
+			return string(content), nil
+		})
+		switch {
+		case errors.Is(err, errChanged), errors.Is(err, errExists):
+			http.Error(w, err.Error(), http.StatusPreconditionFailed)
+			return
+		case err != nil:
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+
+		if err := hook.Run(hook.PostEdit, path); err != nil {
+			log.Printf("could not run %s hook: %v", hook.PostEdit, err)
+		}
+
+		if info, err := os.Stat(path); err == nil {
+			w.Header().Set("ETag", etag(info))
+		}
+		w.WriteHeader(http.StatusNoContent)
+	default:
+		w.Header().Set("Allow", "GET, PUT")
+		http.Error(w, "method not allowed", http.StatusMethodNotAllowed)
+	}
+}
@@ -0,0 +1,117 @@
+package serve
+
+import (
+	"encoding/xml"
+	"net/http"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+type atomLink struct {
+	Href string `xml:"href,attr"`
+	Rel  string `xml:"rel,attr,omitempty"`
+}
+
+type atomContent struct {
+	Type string `xml:"type,attr"`
+	Body string `xml:",chardata"`
+}
+
+type atomEntry struct {
+	Title   string      `xml:"title"`
+	ID      string      `xml:"id"`
+	Updated string      `xml:"updated"`
+	Link    atomLink    `xml:"link"`
+	Content atomContent `xml:"content"`
+}
+
+type atomFeed struct {
+	XMLName xml.Name    `xml:"http://www.w3.org/2005/Atom feed"`
+	Title   string      `xml:"title"`
+	ID      string      `xml:"id"`
+	Updated string      `xml:"updated"`
+	Link    atomLink    `xml:"link"`
+	Entries []atomEntry `xml:"entry"`
+}
+
+// serveFeed writes an Atom feed of the most recent daily logs. Private logs
+// are left out.
+func serveFeed(w http.ResponseWriter, r *http.Request) {
+	logDir, err := noteLog.Dir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+	for i, j := 0, len(paths)-1; i < j; i, j = i+1, j-1 {
+		paths[i], paths[j] = paths[j], paths[i]
+	}
+
+	base := "http://" + r.Host
+	feed := atomFeed{
+		Title: "nb",
+		ID:    base + "/feed.xml",
+		Link:  atomLink{Href: base + "/feed.xml", Rel: "self"},
+	}
+
+	var updated time.Time
+	for _, path := range paths {
+		if len(feed.Entries) == config.FeedSize() {
+			break
+		}
+
+		n, err := note.Read(path)
+		if err != nil || note.IsPrivate(path) {
+			continue
+		}
+
+		info, err := os.Stat(path)
+		if err != nil {
+			continue
+		}
+		if info.ModTime().After(updated) {
+			updated = info.ModTime()
+		}
+
+		rel, err := filepath.Rel(root, path)
+		if err != nil {
+			continue
+		}
+		href := base + "/files/" + filepath.ToSlash(rel)
+
+		feed.Entries = append(feed.Entries, atomEntry{
+			Title:   n.Title(),
+			ID:      href,
+			Updated: info.ModTime().Format(time.RFC3339),
+			Link:    atomLink{Href: href},
+			Content: atomContent{Type: "text", Body: n.Body},
+		})
+	}
+	feed.Updated = updated.Format(time.RFC3339)
+
+	w.Header().Set("Content-Type", "application/atom+xml; charset=utf-8")
+	w.Write([]byte(xml.Header))
+
+	enc := xml.NewEncoder(w)
+	enc.Indent("", "  ")
+	if err := enc.Encode(feed); err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+	}
+}
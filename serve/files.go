@@ -0,0 +1,57 @@
+package serve
+
+import (
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// publicDir is the notebook at its root, without the private files and the
+// hidden ones, such as .nb.toml and .trash, which are neither opened nor listed.
+type publicDir string
+
+func (d publicDir) Open(name string) (http.File, error) {
+	path := filepath.Join(string(d), filepath.FromSlash(name))
+	if hidden(name) || note.IsPrivate(path) {
+		return nil, os.ErrNotExist
+	}
+
+	f, err := http.Dir(d).Open(name)
+	if err != nil {
+		return nil, err
+	}
+
+	return publicFile{File: f, path: path}, nil
+}
+
+type publicFile struct {
+	http.File
+	path string
+}
+
+func (f publicFile) Readdir(count int) ([]os.FileInfo, error) {
+	infos, err := f.File.Readdir(count)
+
+	public := []os.FileInfo{}
+	for _, info := range infos {
+		if !hidden(info.Name()) && !note.IsPrivate(filepath.Join(f.path, info.Name())) {
+			public = append(public, info)
+		}
+	}
+
+	return public, err
+}
+
+// hidden is whether any part of the slash separated name starts with a dot.
+func hidden(name string) bool {
+	for _, part := range strings.Split(name, "/") {
+		if strings.HasPrefix(part, ".") {
+			return true
+		}
+	}
+
+	return false
+}
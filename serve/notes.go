@@ -0,0 +1,217 @@
+package serve
+
+import (
+	"bytes"
+	"html/template"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/graph"
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/render"
+)
+
+var notePage = template.Must(template.New("note").Parse(`<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{{.Title}}</title>
+{{if .Style}}<style>
+{{.Style}}</style>{{else}}<link rel="stylesheet" href="/theme.css">{{end}}
+{{- if .Math}}
+<link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/katex@0.16/dist/katex.min.css">
+{{- end}}
+</head>
+<body>
+{{- if .Edit}}
+<nav><a href="{{.Edit}}">Edit</a></nav>
+{{- end}}
+{{.Body}}
+{{- if .Related}}
+<aside>
+<h2>Related</h2>
+<ul>
+{{- range .Related}}
+<li><a href="{{.Href}}">{{.Name}}</a></li>
+{{- end}}
+</ul>
+</aside>
+{{- end}}
+{{- if .Mermaid}}
+<script type="module">
+import mermaid from "https://cdn.jsdelivr.net/npm/mermaid@10/dist/mermaid.esm.min.mjs";
+const dark = window.matchMedia("(prefers-color-scheme: dark)").matches;
+mermaid.initialize({ startOnLoad: true, theme: dark ? "dark" : "default" });
+</script>
+{{- end}}
+{{- if .Math}}
+<script src="https://cdn.jsdelivr.net/npm/katex@0.16/dist/katex.min.js"></script>
+<script>
+for (const el of document.querySelectorAll(".math")) {
+  const display = el.classList.contains("display");
+  const tex = el.textContent.slice(display ? 2 : 1, display ? -2 : -1);
+  katex.render(tex, el, { displayMode: display, throwOnError: false });
+}
+</script>
+{{- end}}
+</body>
+</html>
+`))
+
+// page is a note laid out as a web page.
+type page struct {
+	Title   string
+	Body    template.HTML
+	Mermaid bool
+	// Math is only set when math rendering is turned on with serve.math.
+	Math bool
+	// Edit is the address of the editor for the note, when it can be edited.
+	Edit    string
+	Related []related
+	// Style is the stylesheet to carry in the page, for pages read without nb
+	// serve.
+	Style template.CSS
+}
+
+// related is a link to a related note.
+type related struct {
+	Name string
+	Href string
+}
+
+// renderNote renders the note at path, linking wikilinks to the address href
+// returns for the note they point at.
+func renderNote(root, path string, href func(target string) string) (page, error) {
+	content, err := os.ReadFile(path)
+	if err != nil {
+		return page{}, err
+	}
+
+	paths, err := note.Paths(root)
+	if err != nil {
+		return page{}, err
+	}
+	resolver := link.NewResolver(root, paths)
+
+	doc := render.HTML(string(content), func(target string) string {
+		if resolved, ok := resolver.Resolve(target); ok && !note.IsPrivate(resolved) {
+			return href(resolved)
+		}
+		return ""
+	})
+
+	title := strings.TrimSuffix(filepath.Base(path), ".md")
+	if n, err := note.Parse(string(content)); err == nil {
+		n.Path = path
+		title = n.Title()
+	}
+
+	return page{
+		Title:   title,
+		Body:    template.HTML(doc.Body),
+		Mermaid: doc.Mermaid,
+		Math:    doc.Math && config.ServeMath(),
+	}, nil
+}
+
+// notePath is the path of the note at rel, a slash separated path from the
+// root, or false when rel is not a markdown file inside the notebook or is
+// hidden.
+func notePath(root, rel string) (string, bool) {
+	path := filepath.Join(root, filepath.FromSlash(rel))
+	if hidden(rel) || !strings.HasPrefix(path, filepath.Clean(root)+string(filepath.Separator)) || filepath.Ext(path) != ".md" {
+		return "", false
+	}
+
+	return path, true
+}
+
+// serveNote renders the note at /notes/<path from the root>.
+func serveNote(w http.ResponseWriter, r *http.Request) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	path, ok := notePath(root, strings.TrimPrefix(r.URL.Path, "/notes/"))
+	if !ok || note.IsPrivate(path) {
+		http.NotFound(w, r)
+		return
+	}
+
+	p, err := renderNote(root, path, func(target string) string {
+		rel, err := filepath.Rel(root, target)
+		if err != nil {
+			return ""
+		}
+		return "/notes/" + filepath.ToSlash(rel)
+	})
+	if os.IsNotExist(err) {
+		http.NotFound(w, r)
+		return
+	} else if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	if writable {
+		p.Edit = "/edit/" + strings.TrimPrefix(r.URL.Path, "/notes/")
+	}
+
+	if config.ServeRelated() {
+		g, err := graph.Build(root)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+
+		for _, rel := range g.Related(path, 5) {
+			if note.IsPrivate(rel.Path) {
+				continue
+			}
+			if href, err := filepath.Rel(root, rel.Path); err == nil {
+				p.Related = append(p.Related, related{
+					Name: strings.TrimSuffix(filepath.Base(rel.Path), ".md"),
+					Href: "/notes/" + filepath.ToSlash(href),
+				})
+			}
+		}
+	}
+
+	notePage.Execute(w, p)
+}
+
+// Standalone renders the note at path as a web page that needs nothing from
+// nb serve, carrying the stylesheet of the configured theme. Wikilinks point
+// at the markdown files of the notes they name.
+func Standalone(root, path string) ([]byte, error) {
+	p, err := renderNote(root, path, func(target string) string {
+		rel, err := filepath.Rel(filepath.Dir(path), target)
+		if err != nil {
+			return ""
+		}
+		return filepath.ToSlash(rel)
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	css, err := stylesheet(config.ServeTheme())
+	if err != nil {
+		return nil, err
+	}
+	p.Style = template.CSS(css)
+
+	var b bytes.Buffer
+	if err := notePage.Execute(&b, p); err != nil {
+		return nil, err
+	}
+
+	return b.Bytes(), nil
+}
@@ -0,0 +1,114 @@
+package serve
+
+import (
+	"html/template"
+	"net/http"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/query"
+)
+
+var queriesPage = template.Must(template.New("queries").Parse(`<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>{{.Title}}</title><link rel="stylesheet" href="/theme.css"></head>
+<body>
+<h1>{{.Title}}</h1>
+{{if .Query}}<p><code>{{.Query}}</code></p>
+<table>
+<tr>{{range .Columns}}<th>{{.}}</th>{{end}}</tr>
+{{range .Rows}}<tr>{{range $i, $cell := .Cells}}<td>{{if eq $i 0}}<a href="{{$.Files}}{{.Href}}">{{$cell.Text}}</a>{{else}}{{$cell.Text}}{{end}}</td>{{end}}</tr>
+{{end}}</table>
+{{else}}<ul>
+{{range .Names}}<li><a href="/queries/{{.}}">{{.}}</a></li>
+{{end}}</ul>
+{{end}}</body>
+</html>
+`))
+
+type queryCell struct {
+	Text string
+	Href string
+}
+
+type queryRow struct {
+	Cells []queryCell
+}
+
+type queryView struct {
+	Title   string
+	Names   []string
+	Query   string
+	Columns []string
+	Rows    []queryRow
+	Files   string
+}
+
+// serveQueries lists the saved queries at /queries/ and shows the current
+// results of one at /queries/<name>.
+func serveQueries(w http.ResponseWriter, r *http.Request) {
+	root, err := config.GetRootDir()
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	saved, err := query.Saved(root, config.Queries())
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	name := strings.TrimPrefix(r.URL.Path, "/queries/")
+	if name == "" {
+		view := queryView{Title: "Queries", Names: []string{}}
+		for name := range saved {
+			view.Names = append(view.Names, name)
+		}
+		sort.Strings(view.Names)
+
+		queriesPage.Execute(w, view)
+		return
+	}
+
+	text, ok := saved[name]
+	if !ok {
+		http.NotFound(w, r)
+		return
+	}
+
+	q, err := query.Parse(text)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	rows, err := q.Run(root)
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	view := queryView{Title: name, Query: text, Columns: q.Columns(), Files: "/files/"}
+	for _, row := range rows {
+		if note.IsPrivate(row.Path) {
+			continue
+		}
+
+		href := ""
+		if rel, err := filepath.Rel(root, row.Path); err == nil {
+			href = filepath.ToSlash(rel)
+		}
+
+		cells := []queryCell{}
+		for _, value := range row.Values {
+			cells = append(cells, queryCell{Text: query.Text(value), Href: href})
+		}
+		view.Rows = append(view.Rows, queryRow{Cells: cells})
+	}
+
+	queriesPage.Execute(w, view)
+}
@@ -4,29 +4,48 @@ import (
 	"fmt"
 	"html/template"
 	"log"
+	"net"
 	"net/http"
 	"path/filepath"
+	"strconv"
 
 	"github.com/t-eckert/nb/config"
 )
 
-func Serve() error {
+// writable is set when editing is turned on, which is the only time anything
+// writes to the notebook.
+var writable bool
+
+// Serve serves the notebook at host on the port set with `serve.port`. Private
+// notes are not served, and notes can only be edited when edit is set.
+func Serve(host string, edit bool) error {
 	root, err := config.GetRootDir()
 	if err != nil {
 		return err
 	}
+	writable = edit
 
 	static := http.FileServer(http.Dir("./static"))
 	http.Handle("/static/", http.StripPrefix("/static/", static))
 
-	fs := http.FileServer(http.Dir(root))
+	fs := http.FileServer(publicDir(root))
 	http.Handle("/files/", http.StripPrefix("/files/", fs))
 
+	http.HandleFunc("/theme.css", serveTheme)
+	http.HandleFunc("/notes/", serveNote)
+	http.HandleFunc("/api/notes/", serveAPINote)
+	if edit {
+		http.HandleFunc("/edit/", serveEdit)
+		http.HandleFunc("/today", serveToday)
+	}
+	http.HandleFunc("/feed.xml", serveFeed)
+	http.HandleFunc("/calendar.ics", serveCalendar)
+	http.HandleFunc("/queries/", serveQueries)
 	http.HandleFunc("/", serveUI)
 
-	port := fmt.Sprintf(":%d", config.Port)
-	fmt.Printf("Serving on http://localhost%s", port)
-	if err := http.ListenAndServe(port, nil); err != nil {
+	addr := net.JoinHostPort(host, strconv.Itoa(config.ServePort()))
+	fmt.Printf("Serving on http://%s\n", addr)
+	if err := http.ListenAndServe(addr, nil); err != nil {
 		log.Fatal(err)
 	}
 
@@ -0,0 +1,74 @@
+package serve
+
+import (
+	"embed"
+	"net/http"
+	"os"
+	"path/filepath"
+
+	"github.com/t-eckert/nb/config"
+)
+
+//go:embed themes/*.css
+var themes embed.FS
+
+// stylesheet is the CSS of a built in theme: light, dark, sepia, or auto,
+// which is light or dark following the browser.
+func stylesheet(theme string) (string, error) {
+	base, err := themes.ReadFile("themes/base.css")
+	if err != nil {
+		return "", err
+	}
+
+	if theme == "auto" {
+		light, err := themes.ReadFile("themes/light.css")
+		if err != nil {
+			return "", err
+		}
+
+		dark, err := themes.ReadFile("themes/dark.css")
+		if err != nil {
+			return "", err
+		}
+
+		return string(light) + "\n@media (prefers-color-scheme: dark) {\n" + string(dark) + "}\n\n" + string(base), nil
+	}
+
+	colors, err := themes.ReadFile("themes/" + theme + ".css")
+	if err != nil {
+		return "", err
+	}
+
+	return string(colors) + "\n" + string(base), nil
+}
+
+// serveTheme writes the theme set with `serve.theme`, followed by the CSS
+// file set with `serve.css` so that it can override any of it.
+func serveTheme(w http.ResponseWriter, r *http.Request) {
+	css, err := stylesheet(config.ServeTheme())
+	if err != nil {
+		http.Error(w, err.Error(), http.StatusInternalServerError)
+		return
+	}
+
+	if path := config.ServeCSS(); path != "" {
+		if !filepath.IsAbs(path) {
+			root, err := config.GetRootDir()
+			if err != nil {
+				http.Error(w, err.Error(), http.StatusInternalServerError)
+				return
+			}
+			path = filepath.Join(root, path)
+		}
+
+		custom, err := os.ReadFile(path)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusInternalServerError)
+			return
+		}
+		css += "\n" + string(custom)
+	}
+
+	w.Header().Set("Content-Type", "text/css; charset=utf-8")
+	w.Write([]byte(css))
+}
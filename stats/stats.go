@@ -0,0 +1,158 @@
+package stats
+
+import (
+	"os"
+	"sort"
+	"strings"
+	"time"
+
+	noteLog "github.com/t-eckert/nb/log"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+	"github.com/t-eckert/nb/task"
+)
+
+type Day struct {
+	Date      string `json:"date"`
+	Words     int    `json:"words"`
+	TasksDone int    `json:"tasks_done"`
+}
+
+type Week struct {
+	Week  string `json:"week"`
+	Words int    `json:"words"`
+}
+
+type Stats struct {
+	Logs           int     `json:"logs"`
+	Notes          int     `json:"notes"`
+	Words          int     `json:"words"`
+	TasksTotal     int     `json:"tasks_total"`
+	TasksDone      int     `json:"tasks_done"`
+	CompletionRate float64 `json:"completion_rate"`
+	LongestStreak  int     `json:"longest_streak"`
+	CurrentStreak  int     `json:"current_streak"`
+	Days           []Day   `json:"days"`
+	Weeks          []Week  `json:"weeks"`
+}
+
+// Compute gathers writing statistics from the daily logs in logDir. Notes
+// elsewhere under root are only counted. Archived logs are left out unless
+// archived is set.
+func Compute(root, logDir string, today time.Time, archived bool) (*Stats, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	s := &Stats{Days: []Day{}, Weeks: []Week{}}
+	weeks := map[string]int{}
+	dates := []time.Time{}
+	logged := map[string]time.Time{}
+	tasks := []task.Task{}
+	for _, path := range paths {
+		inArchive := noteLog.IsArchived(logDir, path)
+		if inArchive && !archived {
+			continue
+		}
+
+		date, ok := logpath.Date(logDir, path, today.Location())
+		if !ok {
+			if !inArchive {
+				s.Notes++
+			}
+			continue
+		}
+
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		words := len(strings.Fields(string(content)))
+		s.Logs++
+		s.Words += words
+		s.Days = append(s.Days, Day{Date: date.Format(note.DateFormat), Words: words})
+		dates = append(dates, date)
+		logged[path] = date
+
+		weeks[weekOf(date)] += words
+
+		tasks = append(tasks, task.Parse(path, string(content))...)
+	}
+
+	// A task carried over from log to log counts once, on the day it was
+	// completed, or on the day of its log when it has no completion date.
+	sort.SliceStable(tasks, func(i, j int) bool { return logged[tasks[i].Path].Before(logged[tasks[j].Path]) })
+
+	done := map[string]int{}
+	for _, t := range task.Latest(tasks) {
+		s.TasksTotal++
+		if !t.Done {
+			continue
+		}
+
+		s.TasksDone++
+		completed, ok := t.Completed()
+		if !ok {
+			completed = logged[t.Path]
+		}
+		done[completed.Format(note.DateFormat)]++
+	}
+
+	for i := range s.Days {
+		s.Days[i].TasksDone = done[s.Days[i].Date]
+	}
+
+	if s.TasksTotal > 0 {
+		s.CompletionRate = float64(s.TasksDone) / float64(s.TasksTotal)
+	}
+
+	sort.Slice(s.Days, func(i, j int) bool { return s.Days[i].Date < s.Days[j].Date })
+
+	for week, words := range weeks {
+		s.Weeks = append(s.Weeks, Week{week, words})
+	}
+	sort.Slice(s.Weeks, func(i, j int) bool { return s.Weeks[i].Week < s.Weeks[j].Week })
+
+	s.LongestStreak, s.CurrentStreak = streaks(dates, today)
+
+	return s, nil
+}
+
+// streaks finds the longest run of consecutive days with a log, and the run
+// ending today or yesterday.
+func streaks(dates []time.Time, today time.Time) (int, int) {
+	logged := map[string]bool{}
+	for _, d := range dates {
+		logged[d.Format(note.DateFormat)] = true
+	}
+
+	longest := 0
+	for _, d := range dates {
+		// Only count runs from their first day.
+		if logged[d.AddDate(0, 0, -1).Format(note.DateFormat)] {
+			continue
+		}
+
+		run := 0
+		for logged[d.AddDate(0, 0, run).Format(note.DateFormat)] {
+			run++
+		}
+		if run > longest {
+			longest = run
+		}
+	}
+
+	day := today
+	if !logged[day.Format(note.DateFormat)] {
+		day = day.AddDate(0, 0, -1)
+	}
+
+	current := 0
+	for logged[day.AddDate(0, 0, -current).Format(note.DateFormat)] {
+		current++
+	}
+
+	return longest, current
+}
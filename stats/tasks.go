@@ -0,0 +1,136 @@
+package stats
+
+import (
+	"sort"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/task"
+)
+
+// TaskWeek counts the tasks first written down and the tasks completed in a
+// week.
+type TaskWeek struct {
+	Week      string `json:"week"`
+	Created   int    `json:"created"`
+	Completed int    `json:"completed"`
+}
+
+// Carried is an open task that has been carried over from log to log.
+type Carried struct {
+	ID    string `json:"id"`
+	Text  string `json:"text"`
+	Times int    `json:"times"`
+}
+
+// TaskReport shows how tasks come and go in the daily logs.
+type TaskReport struct {
+	Weeks          []TaskWeek `json:"weeks"`
+	Open           int        `json:"open"`
+	AverageOpenAge float64    `json:"average_open_age_days"`
+	Carried        []Carried  `json:"carried"`
+}
+
+// Tasks reports on the tasks in the logs of logDir over the last weeks weeks,
+// up to today. A task is created on the day of the first log it appears in,
+// and completed on its completion date or the day of the first log where it is
+// checked off. Open tasks carried over to more than carried later logs, or
+// rolled over more than carried times, are listed, most carried first.
+func Tasks(logDir string, today time.Time, weeks, carried int) (*TaskReport, error) {
+	tasks, err := task.FromLogs(logDir)
+	if err != nil {
+		return nil, err
+	}
+
+	type history struct {
+		task      task.Task
+		created   time.Time
+		completed time.Time
+		logs      int
+	}
+	histories := map[string]*history{}
+	order := []string{}
+
+	for _, t := range tasks {
+		day, ok := logpath.Date(logDir, t.Path, today.Location())
+		if !ok {
+			continue
+		}
+
+		h, ok := histories[t.ID()]
+		if !ok {
+			h = &history{created: day}
+			histories[t.ID()] = h
+			order = append(order, t.ID())
+		}
+		if day.Before(h.created) {
+			h.created = day
+		}
+
+		h.logs++
+		h.task = t
+
+		if !t.Done {
+			continue
+		}
+
+		completed, ok := t.Completed()
+		if !ok {
+			completed = day
+		}
+		if h.completed.IsZero() || completed.Before(h.completed) {
+			h.completed = completed
+		}
+	}
+
+	report := &TaskReport{Weeks: []TaskWeek{}, Carried: []Carried{}}
+
+	index := map[string]int{}
+	start := today.AddDate(0, 0, -7*(weeks-1))
+	for d := start; !d.After(today); d = d.AddDate(0, 0, 7) {
+		index[weekOf(d)] = len(report.Weeks)
+		report.Weeks = append(report.Weeks, TaskWeek{Week: weekOf(d)})
+	}
+
+	age := 0.0
+	for _, id := range order {
+		h := histories[id]
+
+		if i, ok := index[weekOf(h.created)]; ok {
+			report.Weeks[i].Created++
+		}
+
+		if !h.completed.IsZero() {
+			if i, ok := index[weekOf(h.completed)]; ok {
+				report.Weeks[i].Completed++
+			}
+			continue
+		}
+
+		report.Open++
+		age += today.Sub(h.created).Hours() / 24
+
+		times := h.logs - 1
+		if h.task.Rolled() > times {
+			times = h.task.Rolled()
+		}
+		if times > carried {
+			report.Carried = append(report.Carried, Carried{ID: id, Text: h.task.Text, Times: times})
+		}
+	}
+
+	if report.Open > 0 {
+		report.AverageOpenAge = age / float64(report.Open)
+	}
+
+	sort.SliceStable(report.Carried, func(i, j int) bool { return report.Carried[i].Times > report.Carried[j].Times })
+
+	return report, nil
+}
+
+// weekOf names the week t is in, with weeks starting on config.WeekStart.
+func weekOf(t time.Time) string {
+	return date.Week(t, config.WeekStart())
+}
@@ -0,0 +1,61 @@
+package task
+
+import (
+	"regexp"
+	"strings"
+)
+
+var (
+	contextToken = regexp.MustCompile(`(^|\s)@([\w-]+)`)
+	projectToken = regexp.MustCompile(`(^|\s)\+([\w-]+)`)
+)
+
+// Contexts are the todo.txt style `@context` tokens in the text of the task.
+func (t Task) Contexts() []string {
+	return tokens(contextToken, t.Text)
+}
+
+// Projects are the todo.txt style `+project` tokens in the text of the task.
+func (t Task) Projects() []string {
+	return tokens(projectToken, t.Text)
+}
+
+// Filter keeps the tasks that have every one of the contexts and projects,
+// ignoring case.
+func Filter(tasks []Task, contexts, projects []string) []Task {
+	kept := []Task{}
+	for _, t := range tasks {
+		if hasAll(t.Contexts(), contexts) && hasAll(t.Projects(), projects) {
+			kept = append(kept, t)
+		}
+	}
+
+	return kept
+}
+
+func tokens(pattern *regexp.Regexp, text string) []string {
+	found := []string{}
+	for _, match := range pattern.FindAllStringSubmatch(text, -1) {
+		found = append(found, match[2])
+	}
+
+	return found
+}
+
+func hasAll(have, want []string) bool {
+	for _, w := range want {
+		found := false
+		for _, h := range have {
+			if strings.EqualFold(h, w) {
+				found = true
+				break
+			}
+		}
+
+		if !found {
+			return false
+		}
+	}
+
+	return true
+}
@@ -0,0 +1,58 @@
+package task
+
+import (
+	"fmt"
+	"testing"
+)
+
+func TestContextsAndProjects(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"Call Sam @phone +move", "[phone] [move]"},
+		{"@home Fix the sink @weekend", "[home weekend] []"},
+		{"Email sam@example.com about 1+1", "[] []"},
+		{"Plan +house-move with @Sam", "[Sam] [house-move]"},
+	}
+
+	for _, c := range cases {
+		task := Task{Text: c.given}
+		actual := fmt.Sprint(task.Contexts(), " ", task.Projects())
+
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestFilter(t *testing.T) {
+	tasks := []Task{
+		{Text: "Call Sam @phone +move"},
+		{Text: "Pack the kitchen @home +move"},
+		{Text: "Call the bank @phone"},
+	}
+
+	cases := []struct {
+		contexts []string
+		projects []string
+		expected string
+	}{
+		{nil, nil, "[Call Sam @phone +move Pack the kitchen @home +move Call the bank @phone]"},
+		{[]string{"PHONE"}, nil, "[Call Sam @phone +move Call the bank @phone]"},
+		{[]string{"phone"}, []string{"move"}, "[Call Sam @phone +move]"},
+		{nil, []string{"garden"}, "[]"},
+	}
+
+	for _, c := range cases {
+		texts := []string{}
+		for _, task := range Filter(tasks, c.contexts, c.projects) {
+			texts = append(texts, task.Text)
+		}
+		actual := fmt.Sprint(texts)
+
+		if c.expected != actual {
+			t.Fatalf("given: %v %v\nexpected: %s\nactual: %s\n", c.contexts, c.projects, c.expected, actual)
+		}
+	}
+}
@@ -0,0 +1,52 @@
+package task
+
+type ChangeKind string
+
+const (
+	Added     ChangeKind = "added"
+	Removed   ChangeKind = "removed"
+	Completed ChangeKind = "completed"
+	Reopened  ChangeKind = "reopened"
+)
+
+type Change struct {
+	Kind ChangeKind
+	Text string
+}
+
+// Diff compares two sets of tasks by their IDs, so that completion dates and
+// status and defer tokens do not count as new tasks, and reports which were
+// added, removed, completed, or reopened going from a to b.
+func Diff(a, b []Task) []Change {
+	before := map[string]Task{}
+	for _, t := range a {
+		before[t.ID()] = t
+	}
+
+	after := map[string]bool{}
+	changes := []Change{}
+	for _, t := range b {
+		key := t.ID()
+		after[key] = true
+
+		old, ok := before[key]
+		switch {
+		case !ok:
+			changes = append(changes, Change{Added, t.Text})
+		case !old.Done && t.Done:
+			changes = append(changes, Change{Completed, t.Text})
+		case old.Done && !t.Done:
+			changes = append(changes, Change{Reopened, t.Text})
+		}
+	}
+
+	for _, t := range a {
+		key := t.ID()
+		if !after[key] {
+			changes = append(changes, Change{Removed, t.Text})
+			after[key] = true
+		}
+	}
+
+	return changes
+}
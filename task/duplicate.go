@@ -0,0 +1,87 @@
+package task
+
+import (
+	"strings"
+	"unicode"
+)
+
+// DuplicateThreshold is the similarity above which two tasks are considered
+// the same piece of work written slightly differently.
+const DuplicateThreshold = 0.8
+
+// Duplicates returns the tasks whose text is similar to the given text. Each
+// distinct task text is only returned once, so a task copied across several
+// logs is not reported several times.
+func Duplicates(text string, tasks []Task) []Task {
+	target := Normalize(text)
+	seen := map[string]bool{}
+
+	duplicates := []Task{}
+	for _, t := range tasks {
+		normalized := Normalize(t.Text)
+		if seen[normalized] {
+			continue
+		}
+
+		if Similarity(target, normalized) >= DuplicateThreshold {
+			seen[normalized] = true
+			duplicates = append(duplicates, t)
+		}
+	}
+
+	return duplicates
+}
+
+// Normalize lowercases the text, drops punctuation, and collapses whitespace.
+func Normalize(text string) string {
+	var b strings.Builder
+	for _, r := range strings.ToLower(text) {
+		switch {
+		case unicode.IsLetter(r), unicode.IsDigit(r):
+			b.WriteRune(r)
+		case unicode.IsSpace(r):
+			b.WriteRune(' ')
+		}
+	}
+
+	return strings.Join(strings.Fields(b.String()), " ")
+}
+
+// Similarity is the Sørensen–Dice coefficient of the character bigrams of two
+// strings, from 0 (nothing in common) to 1 (identical).
+func Similarity(a, b string) float64 {
+	if a == b {
+		return 1
+	}
+
+	as, bs := bigrams(a), bigrams(b)
+	if len(as) == 0 || len(bs) == 0 {
+		return 0
+	}
+
+	counts := map[string]int{}
+	for _, bigram := range as {
+		counts[bigram]++
+	}
+
+	shared := 0
+	for _, bigram := range bs {
+		if counts[bigram] > 0 {
+			counts[bigram]--
+			shared++
+		}
+	}
+
+	return 2 * float64(shared) / float64(len(as)+len(bs))
+}
+
+func bigrams(s string) []string {
+	runes := []rune(s)
+
+	pairs := make([]string, 0, len(runes))
+	for i := 0; i < len(runes)-1; i++ {
+		pairs = append(pairs, string(runes[i:i+2]))
+	}
+
+	return pairs
+}
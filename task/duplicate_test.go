@@ -0,0 +1,20 @@
+package task
+
+import (
+	"testing"
+)
+
+func TestDuplicates(t *testing.T) {
+	given := []Task{
+		{Text: "Follow up with Sam about the Q3 budget"},
+		{Text: "follow up with sam about the Q3 budget!"},
+		{Text: "Water the plants"},
+	}
+	expected := 1
+
+	actual := len(Duplicates("Follow up with Sam about Q3 budget", given))
+
+	if expected != actual {
+		t.Fatalf("given: %v\nexpected: %d\nactual: %d\n", given, expected, actual)
+	}
+}
@@ -0,0 +1,141 @@
+package task
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/note"
+)
+
+// Rewrite replaces the line of the task in its file with the result of edit.
+// The line is checked to still be the task before it is changed.
+func Rewrite(t Task, edit func(line string) string) error {
+	return note.Update(t.Path, func(content string) (string, error) {
+		lines := strings.Split(content, "\n")
+		if t.Line >= len(lines) || !strings.Contains(lines[t.Line], t.Text) {
+			return "", fmt.Errorf("%s:%d no longer holds the task %q", t.Path, t.Line+1, t.Text)
+		}
+
+		lines[t.Line] = edit(lines[t.Line])
+
+		return strings.Join(lines, "\n"), nil
+	})
+}
+
+// SetStatus rewrites the task line for the new status. The built in statuses
+// change the checkbox; any other status is written as a `status:` token. Done
+// tasks are marked with the date they were completed on.
+func SetStatus(t Task, status string) error {
+	today := time.Now().Format(note.DateFormat)
+
+	return Rewrite(t, func(line string) string {
+		match := checkbox.FindStringSubmatch(line)
+		if match == nil {
+			return line
+		}
+
+		text := statusToken.ReplaceAllString(match[4], "")
+		text = strings.TrimSpace(doneToken.ReplaceAllString(text, ""))
+
+		mark := " "
+		switch status {
+		case Done:
+			mark = "x"
+			text += " ✅ " + today
+		case Doing:
+			mark = "/"
+		case Todo:
+		default:
+			text += " status:" + status
+		}
+
+		return match[1] + match[2] + " [" + mark + "] " + text
+	})
+}
+
+// Find returns the task with the given ID, preferring the last one found when
+// the same task appears in several places.
+func Find(tasks []Task, id string) (Task, bool) {
+	for i := len(tasks) - 1; i >= 0; i-- {
+		if tasks[i].ID() == id {
+			return tasks[i], true
+		}
+	}
+
+	return Task{}, false
+}
+
+// Remove deletes the lines of the tasks from their files.
+func Remove(tasks ...Task) error {
+	byPath := map[string][]Task{}
+	paths := []string{}
+	for _, t := range tasks {
+		if _, ok := byPath[t.Path]; !ok {
+			paths = append(paths, t.Path)
+		}
+		byPath[t.Path] = append(byPath[t.Path], t)
+	}
+
+	for _, path := range paths {
+		err := note.Update(path, func(content string) (string, error) {
+			lines := strings.Split(content, "\n")
+			remove := map[int]bool{}
+			for _, t := range byPath[path] {
+				if t.Line >= len(lines) || !strings.Contains(lines[t.Line], t.Text) {
+					return "", fmt.Errorf("%s:%d no longer holds the task %q", t.Path, t.Line+1, t.Text)
+				}
+				remove[t.Line] = true
+			}
+
+			kept := []string{}
+			for i, line := range lines {
+				if !remove[i] {
+					kept = append(kept, line)
+				}
+			}
+
+			return strings.Join(kept, "\n"), nil
+		})
+		if err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+// Deferred is the text of the task marked with a `deferred:` token for the
+// date it was moved from, replacing any earlier one.
+func Deferred(t Task, from time.Time) string {
+	text := strings.TrimSpace(deferToken.ReplaceAllString(t.Text, ""))
+	return text + " deferred:" + from.Format(note.DateFormat)
+}
+
+// RolledOver is the text of the task with its `rolled:N` token counting one
+// more rollover.
+func RolledOver(t Task) string {
+	text := strings.TrimSpace(rolledToken.ReplaceAllString(t.Text, ""))
+	return text + " rolled:" + strconv.Itoa(t.Rolled()+1)
+}
+
+// Lines are the checkbox lines of t and its subtasks, indented relative to t.
+// Each keeps the mark of its checkbox, so a task in progress stays in progress.
+func Lines(text string, t Task, subtasks []Task) []string {
+	lines := []string{checkboxPrefix(t) + text}
+	for _, s := range subtasks {
+		lines = append(lines, strings.TrimPrefix(s.Indent, t.Indent)+checkboxPrefix(s)+s.Text)
+	}
+
+	return lines
+}
+
+func checkboxPrefix(t Task) string {
+	mark := t.Mark
+	if mark == "" {
+		mark = " "
+	}
+
+	return "- [" + mark + "] "
+}
@@ -0,0 +1,99 @@
+package task
+
+import (
+	"regexp"
+	"sort"
+	"strings"
+)
+
+// Priorities, from most to least urgent. Tasks without a marker have None,
+// which sorts between Medium and Low.
+const (
+	Highest = 3
+	High    = 2
+	Medium  = 1
+	None    = 0
+	Low     = -1
+	Lowest  = -2
+)
+
+var (
+	letterPriority = regexp.MustCompile(`^\(([A-Z])\)\s`)
+	wordPriority   = regexp.MustCompile(`(?i)(^|\s)!(highest|high|medium|low|lowest)\b`)
+
+	emojiPriority = map[string]int{
+		"🔺": Highest,
+		"⏫": High,
+		"🔼": Medium,
+		"🔽": Low,
+		"⏬": Lowest,
+	}
+
+	words = map[string]int{
+		"highest": Highest,
+		"high":    High,
+		"medium":  Medium,
+		"low":     Low,
+		"lowest":  Lowest,
+	}
+)
+
+// priority reads the priority marker in the text of a task: a todo.txt letter
+// such as `(A)` at the start, a word such as `!high`, or one of the emoji used
+// by Obsidian Tasks. A, B, and C are highest, high, and medium; later letters
+// are low.
+func priority(text string) int {
+	if match := letterPriority.FindStringSubmatch(text + " "); match != nil {
+		switch match[1] {
+		case "A":
+			return Highest
+		case "B":
+			return High
+		case "C":
+			return Medium
+		}
+		return Low
+	}
+
+	if match := wordPriority.FindStringSubmatch(text); match != nil {
+		return words[strings.ToLower(match[2])]
+	}
+
+	for emoji, p := range emojiPriority {
+		if strings.Contains(text, emoji) {
+			return p
+		}
+	}
+
+	return None
+}
+
+// withoutPriority is the text of a task without its priority markers.
+func withoutPriority(text string) string {
+	text = strings.TrimPrefix(letterPriority.ReplaceAllString(text+" ", ""), " ")
+	text = wordPriority.ReplaceAllString(text, "$1")
+	for emoji := range emojiPriority {
+		text = strings.ReplaceAll(text, emoji, "")
+	}
+
+	return strings.TrimSpace(text)
+}
+
+// PriorityName is the word for a priority, such as high, or nothing for None.
+func PriorityName(p int) string {
+	for word, value := range words {
+		if value == p {
+			return word
+		}
+	}
+
+	return ""
+}
+
+// ByPriority orders tasks from most to least urgent, keeping the order of
+// tasks with the same priority.
+func ByPriority(tasks []Task) {
+	sort.SliceStable(tasks, func(i, j int) bool {
+		return tasks[i].Priority > tasks[j].Priority
+	})
+}
@@ -0,0 +1,95 @@
+package task
+
+import (
+	"fmt"
+	"testing"
+)
+
+func TestPriority(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected int
+	}{
+		{"(A) File taxes", Highest},
+		{"(B) File taxes", High},
+		{"(C) File taxes", Medium},
+		{"(D) File taxes", Low},
+		{"(a) File taxes", None},
+		{"File taxes (A)", None},
+		{"File taxes !high", High},
+		{"File taxes !LOWEST", Lowest},
+		{"Email sam!high", None},
+		{"File taxes 🔺", Highest},
+		{"File taxes 🔼", Medium},
+		{"File taxes ⏬", Lowest},
+		{"File taxes", None},
+	}
+
+	for _, c := range cases {
+		actual := priority(c.given)
+
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %d\nactual: %d\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestWithoutPriority(t *testing.T) {
+	cases := []struct {
+		given    string
+		expected string
+	}{
+		{"(A) File taxes", "File taxes"},
+		{"File taxes !high", "File taxes"},
+		{"File taxes ⏫", "File taxes"},
+		{"Email sam!high", "Email sam!high"},
+		{"File taxes", "File taxes"},
+	}
+
+	for _, c := range cases {
+		actual := withoutPriority(c.given)
+
+		if c.expected != actual {
+			t.Fatalf("given: %s\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
+
+func TestByPriority(t *testing.T) {
+	given := []string{"Water the plants", "(C) Pay rent", "Call Sam !low", "(A) File taxes", "Buy milk"}
+	expected := []string{"(A) File taxes", "(C) Pay rent", "Water the plants", "Buy milk", "Call Sam !low"}
+
+	tasks := []Task{}
+	for _, text := range given {
+		tasks = append(tasks, Task{Text: text, Priority: priority(text)})
+	}
+	ByPriority(tasks)
+
+	actual := []string{}
+	for _, task := range tasks {
+		actual = append(actual, task.Text)
+	}
+
+	if fmt.Sprint(expected) != fmt.Sprint(actual) {
+		t.Fatalf("given: %q\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
+
+func TestPriorityName(t *testing.T) {
+	cases := []struct {
+		given    int
+		expected string
+	}{
+		{Highest, "highest"},
+		{Low, "low"},
+		{None, ""},
+	}
+
+	for _, c := range cases {
+		actual := PriorityName(c.given)
+
+		if c.expected != actual {
+			t.Fatalf("given: %d\nexpected: %s\nactual: %s\n", c.given, c.expected, actual)
+		}
+	}
+}
@@ -0,0 +1,260 @@
+package task
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"os"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/logpath"
+	"github.com/t-eckert/nb/note"
+)
+
+const (
+	Todo  = "todo"
+	Doing = "doing"
+	Done  = "done"
+)
+
+var (
+	checkbox    = regexp.MustCompile(`^(\s*)([-*]) \[([ xX/])\] (.*)$`)
+	bullet      = regexp.MustCompile(`^\s*([-*+]|\d+\.) `)
+	statusToken = regexp.MustCompile(`(^|\s)status:(\S+)`)
+	dueToken    = regexp.MustCompile(`(^|\s)due:(\d{4}-\d{2}-\d{2})`)
+	remindToken = regexp.MustCompile(`(^|\s)remind:\s?(\d{4}-\d{2}-\d{2})(?:[ T](\d{1,2}:\d{2}))?`)
+	doneToken   = regexp.MustCompile(`(^|\s)✅\s?(\d{4}-\d{2}-\d{2})`)
+	deferToken  = regexp.MustCompile(`(^|\s)deferred:(\d{4}-\d{2}-\d{2})`)
+	rolledToken = regexp.MustCompile(`(^|\s)rolled:(\d+)`)
+)
+
+// Task is a markdown checkbox line. Its status comes from the checkbox, where
+// `[/]` marks a task in progress, unless the text has a `status:` token. A task
+// indented under another is its subtask, and Parent is the line of the task it
+// is nested under, or -1. Section is the heading the task is under, if any,
+// and Mark is the character between the brackets of the checkbox.
+type Task struct {
+	Path     string
+	Line     int
+	Indent   string
+	Parent   int
+	Section  string
+	Mark     string
+	Done     bool
+	Status   string
+	Priority int
+	Text     string
+}
+
+// ID is a short hash of the normalized text of the task, so it stays the same
+// when the task moves within or between logs, is rolled over, or changes
+// status, priority, or completion date.
+func (t Task) ID() string {
+	text := statusToken.ReplaceAllString(withoutPriority(t.Text), "")
+	text = deferToken.ReplaceAllString(doneToken.ReplaceAllString(text, ""), "")
+	text = rolledToken.ReplaceAllString(text, "")
+	sum := sha1.Sum([]byte(Normalize(text)))
+	return hex.EncodeToString(sum[:])[:6]
+}
+
+// Due is the date of the `due:YYYY-MM-DD` token in the task text, if any.
+func (t Task) Due() (time.Time, bool) {
+	match := dueToken.FindStringSubmatch(t.Text)
+	if match == nil {
+		return time.Time{}, false
+	}
+
+	due, err := time.ParseInLocation("2006-01-02", match[2], time.Local)
+	if err != nil {
+		return time.Time{}, false
+	}
+
+	return due, true
+}
+
+// Remind is the time of the `remind: YYYY-MM-DD HH:MM` token in the task text,
+// if any. Without a time the reminder is due at the start of the day.
+func (t Task) Remind() (time.Time, bool) {
+	match := remindToken.FindStringSubmatch(t.Text)
+	if match == nil {
+		return time.Time{}, false
+	}
+
+	value, layout := match[2], "2006-01-02"
+	if match[3] != "" {
+		value, layout = value+" "+match[3], "2006-01-02 15:04"
+	}
+
+	at, err := time.ParseInLocation(layout, value, time.Local)
+	if err != nil {
+		return time.Time{}, false
+	}
+
+	return at, true
+}
+
+// Rolled is how many times the task has been carried over to a new log, from
+// its `rolled:N` token.
+func (t Task) Rolled() int {
+	match := rolledToken.FindStringSubmatch(t.Text)
+	if match == nil {
+		return 0
+	}
+
+	n, _ := strconv.Atoi(match[2])
+	return n
+}
+
+// Completed is the date of the `✅ YYYY-MM-DD` mark that nb adds when the task
+// is checked off, if any.
+func (t Task) Completed() (time.Time, bool) {
+	match := doneToken.FindStringSubmatch(t.Text)
+	if match == nil {
+		return time.Time{}, false
+	}
+
+	completed, err := time.ParseInLocation("2006-01-02", match[2], time.Local)
+	if err != nil {
+		return time.Time{}, false
+	}
+
+	return completed, true
+}
+
+// Parse finds every checkbox in the content. Line numbers are zero-indexed.
+func Parse(path, content string) []Task {
+	tasks := []Task{}
+
+	// parents holds the tasks that later, further indented tasks can be
+	// nested under, from the outermost in.
+	parents := []Task{}
+	section := ""
+	for i, line := range strings.Split(content, "\n") {
+		if level, name := note.Heading(line); level > 0 {
+			section = name
+		}
+
+		match := checkbox.FindStringSubmatch(line)
+		if match == nil {
+			// Only list items and blank lines keep a list of tasks together.
+			trimmed := strings.TrimSpace(line)
+			if trimmed != "" && trimmed == line && !bullet.MatchString(line) {
+				parents = parents[:0]
+			}
+			continue
+		}
+
+		for len(parents) > 0 && len(parents[len(parents)-1].Indent) >= len(match[1]) {
+			parents = parents[:len(parents)-1]
+		}
+
+		parent := -1
+		if len(parents) > 0 {
+			parent = parents[len(parents)-1].Line
+		}
+
+		tasks = append(tasks, Task{
+			Path:     path,
+			Line:     i,
+			Indent:   match[1],
+			Parent:   parent,
+			Section:  section,
+			Mark:     match[3],
+			Done:     match[3] == "x" || match[3] == "X",
+			Status:   status(match[3], match[4]),
+			Priority: priority(strings.TrimSpace(match[4])),
+			Text:     strings.TrimSpace(match[4]),
+		})
+		parents = append(parents, tasks[len(tasks)-1])
+	}
+
+	return tasks
+}
+
+// FromLogs parses the tasks of every daily log in logDir, leaving out the
+// archive.
+func FromLogs(logDir string) ([]Task, error) {
+	paths, err := logpath.List(logDir, false)
+	if err != nil {
+		return nil, err
+	}
+
+	tasks := []Task{}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		tasks = append(tasks, Parse(path, string(content))...)
+	}
+
+	return tasks, nil
+}
+
+// FromNotebook parses the tasks of every note under root, leaving out the
+// notes matched by the notebook's ignore file.
+func FromNotebook(root string) ([]Task, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return nil, err
+	}
+
+	tasks := []Task{}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		tasks = append(tasks, Parse(path, string(content))...)
+	}
+
+	return tasks, nil
+}
+
+// Latest keeps the last occurrence of each task, so that a task carried over
+// from log to log is only counted once, in its most recent state.
+func Latest(tasks []Task) []Task {
+	last := map[string]int{}
+	for i, t := range tasks {
+		last[t.ID()] = i
+	}
+
+	latest := []Task{}
+	for i, t := range tasks {
+		if last[t.ID()] == i {
+			latest = append(latest, t)
+		}
+	}
+
+	return latest
+}
+
+func Open(tasks []Task) []Task {
+	open := []Task{}
+	for _, t := range tasks {
+		if !t.Done {
+			open = append(open, t)
+		}
+	}
+
+	return open
+}
+
+func status(mark, text string) string {
+	if match := statusToken.FindStringSubmatch(text); match != nil {
+		return strings.ToLower(match[2])
+	}
+
+	switch mark {
+	case "x", "X":
+		return Done
+	case "/":
+		return Doing
+	}
+
+	return Todo
+}
@@ -0,0 +1,70 @@
+package task
+
+import (
+	"fmt"
+	"testing"
+)
+
+func TestCompleted(t *testing.T) {
+	given := Task{Text: "Send the invoice ✅ 2025-03-04"}
+	expected := "2025-03-04"
+
+	completed, ok := given.Completed()
+	actual := completed.Format("2006-01-02")
+
+	if !ok || expected != actual {
+		t.Fatalf("given: %v\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+
+	open := Task{Text: "Send the invoice"}
+	if given.ID() != open.ID() {
+		t.Fatalf("given: %v\nexpected: %s\nactual: %s\n", given, open.ID(), given.ID())
+	}
+}
+
+func TestParseSubtasks(t *testing.T) {
+	given := "## Tasks\n\n- [ ] Plan the trip\n  - [ ] Book flights\n    - [x] Compare prices\n  - [ ] Book hotel\n- [ ] Water the plants\n"
+	expected := []int{-1, 2, 3, 2, -1}
+
+	tasks := Parse("log.md", given)
+	actual := []int{}
+	for _, task := range tasks {
+		actual = append(actual, task.Parent)
+	}
+
+	if fmt.Sprint(expected) != fmt.Sprint(actual) {
+		t.Fatalf("given: %q\nexpected: %v\nactual: %v\n", given, expected, actual)
+	}
+
+	subtasks := Subtasks(tasks, tasks[0])
+	if len(subtasks) != 2 {
+		t.Fatalf("given: %q\nexpected: 2 open subtasks\nactual: %v\n", given, subtasks)
+	}
+}
+
+func TestDiffCompleted(t *testing.T) {
+	given := "- [ ] Send the invoice\n"
+	done := "- [x] Send the invoice ✅ 2025-03-04\n"
+	expected := fmt.Sprint([]Change{{Completed, "Send the invoice ✅ 2025-03-04"}})
+
+	actual := fmt.Sprint(Diff(Parse("a.md", given), Parse("b.md", done)))
+
+	if expected != actual {
+		t.Fatalf("given: %q and %q\nexpected: %s\nactual: %s\n", given, done, expected, actual)
+	}
+}
+
+func TestRolledOver(t *testing.T) {
+	given := Task{Text: "Call the bank rolled:2"}
+	expected := "Call the bank rolled:3"
+
+	actual := RolledOver(given)
+
+	if expected != actual {
+		t.Fatalf("given: %v\nexpected: %s\nactual: %s\n", given, expected, actual)
+	}
+
+	if given.ID() != (Task{Text: "Call the bank"}).ID() {
+		t.Fatalf("given: %v\nexpected: the ID of the task without rolled:2\nactual: %s\n", given, given.ID())
+	}
+}
@@ -0,0 +1,53 @@
+package task
+
+// Node is a task with the subtasks nested under it.
+type Node struct {
+	Task
+	Children []*Node
+}
+
+// Tree nests each task under its parent, keeping the order of the tasks. A
+// task whose parent is not among the tasks is at the top level.
+func Tree(tasks []Task) []*Node {
+	type key struct {
+		path string
+		line int
+	}
+
+	nodes := map[key]*Node{}
+	for _, t := range tasks {
+		nodes[key{t.Path, t.Line}] = &Node{Task: t}
+	}
+
+	roots := []*Node{}
+	for _, t := range tasks {
+		n := nodes[key{t.Path, t.Line}]
+		if parent, ok := nodes[key{t.Path, t.Parent}]; ok && t.Parent >= 0 {
+			parent.Children = append(parent.Children, n)
+			continue
+		}
+		roots = append(roots, n)
+	}
+
+	return roots
+}
+
+// Subtasks are the open tasks nested under t, at any depth, in the order they
+// appear in its file.
+func Subtasks(tasks []Task, t Task) []Task {
+	nested := map[int]bool{t.Line: true}
+
+	subtasks := []Task{}
+	for _, s := range tasks {
+		if s.Path != t.Path || s.Parent < 0 || !nested[s.Parent] {
+			continue
+		}
+
+		nested[s.Line] = true
+		if !s.Done {
+			subtasks = append(subtasks, s)
+		}
+	}
+
+	return subtasks
+}
@@ -0,0 +1,21 @@
+//go:build windows
+// +build windows
+
+package terminal
+
+import (
+	"os"
+	"strconv"
+)
+
+// Size is the width and height of the terminal, taken from the COLUMNS and
+// LINES environment variables. It is not ok when they are not set.
+func Size() (int, int, bool) {
+	width, errWidth := strconv.Atoi(os.Getenv("COLUMNS"))
+	height, errHeight := strconv.Atoi(os.Getenv("LINES"))
+	if errWidth != nil || errHeight != nil {
+		return 0, 0, false
+	}
+
+	return width, height, true
+}
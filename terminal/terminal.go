@@ -0,0 +1,45 @@
+package terminal
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+)
+
+// Page prints text, piping it through $PAGER (less by default, more on
+// Windows) when stdout is a terminal that it does not fit in.
+func Page(text string) error {
+	_, height, ok := Size()
+	if !ok || strings.Count(text, "\n") < height {
+		fmt.Print(text)
+		return nil
+	}
+
+	fields := strings.Fields(os.Getenv("PAGER"))
+	if len(fields) == 0 {
+		fields = []string{"less", "-R"}
+		if runtime.GOOS == "windows" {
+			fields = []string{"more"}
+		}
+	}
+
+	cmd := exec.Command(fields[0], fields[1:]...)
+	cmd.Stdin = strings.NewReader(text)
+	cmd.Stdout = os.Stdout
+	cmd.Stderr = os.Stderr
+
+	if err := cmd.Run(); err != nil {
+		// Without a working pager, still show the text.
+		fmt.Print(text)
+	}
+
+	return nil
+}
+
+// IsTerminal reports whether stdout is a terminal.
+func IsTerminal() bool {
+	_, _, ok := Size()
+	return ok
+}
@@ -0,0 +1,106 @@
+package tracker
+
+import (
+	"encoding/base64"
+	"fmt"
+	"net/url"
+	"strings"
+)
+
+// Jira pulls the unresolved issues assigned to the user. Cloud sites use an
+// email and API token; Server and Data Center use a personal access token on
+// its own.
+type Jira struct {
+	URL   string
+	Email string
+	Token string
+
+	// OnDone is "comment" to comment on the issue when its task is done,
+	// "transition" to move it through the transition named Transition, or
+	// empty to leave it alone.
+	OnDone     string
+	Transition string
+}
+
+func (j *Jira) Name() string {
+	return "Jira"
+}
+
+func (j *Jira) Owns(link string) bool {
+	return strings.HasPrefix(link, strings.TrimRight(j.URL, "/")+"/")
+}
+
+func (j *Jira) Open() ([]Ticket, error) {
+	jql := "assignee = currentUser() AND statusCategory != Done ORDER BY updated DESC"
+
+	var result struct {
+		Issues []struct {
+			Key    string `json:"key"`
+			Fields struct {
+				Summary string `json:"summary"`
+			} `json:"fields"`
+		} `json:"issues"`
+	}
+	if err := send("GET", j.api("search?fields=summary&maxResults=100&jql="+url.QueryEscape(jql)), j.headers(), nil, &result); err != nil {
+		return nil, err
+	}
+
+	tickets := []Ticket{}
+	for _, issue := range result.Issues {
+		tickets = append(tickets, Ticket{
+			Key:   issue.Key,
+			Title: issue.Fields.Summary,
+			URL:   strings.TrimRight(j.URL, "/") + "/browse/" + issue.Key,
+		})
+	}
+
+	return tickets, nil
+}
+
+func (j *Jira) Done(t Ticket) error {
+	switch j.OnDone {
+	case "":
+		return nil
+	case "comment":
+		body := map[string]string{"body": "Done, according to my notes."}
+		return send("POST", j.api("issue/"+t.Key+"/comment"), j.headers(), body, nil)
+	case "transition":
+		return j.transition(t)
+	}
+
+	return fmt.Errorf("unknown on_done action %q for Jira, expected comment or transition", j.OnDone)
+}
+
+func (j *Jira) transition(t Ticket) error {
+	var result struct {
+		Transitions []struct {
+			ID   string `json:"id"`
+			Name string `json:"name"`
+		} `json:"transitions"`
+	}
+	if err := send("GET", j.api("issue/"+t.Key+"/transitions"), j.headers(), nil, &result); err != nil {
+		return err
+	}
+
+	for _, tr := range result.Transitions {
+		if strings.EqualFold(tr.Name, j.Transition) {
+			body := map[string]interface{}{"transition": map[string]string{"id": tr.ID}}
+			return send("POST", j.api("issue/"+t.Key+"/transitions"), j.headers(), body, nil)
+		}
+	}
+
+	return fmt.Errorf("%s has no transition named %q", t.Key, j.Transition)
+}
+
+func (j *Jira) api(path string) string {
+	return strings.TrimRight(j.URL, "/") + "/rest/api/2/" + path
+}
+
+func (j *Jira) headers() map[string]string {
+	if j.Email == "" {
+		return map[string]string{"Authorization": "Bearer " + j.Token}
+	}
+
+	credentials := base64.StdEncoding.EncodeToString([]byte(j.Email + ":" + j.Token))
+	return map[string]string{"Authorization": "Basic " + credentials}
+}
@@ -0,0 +1,129 @@
+package tracker
+
+import (
+	"fmt"
+	"strings"
+)
+
+const linearAPI = "https://api.linear.app/graphql"
+
+// Linear pulls the issues assigned to the user that are not completed or
+// canceled.
+type Linear struct {
+	Token string
+
+	// OnDone is "comment" to comment on the issue when its task is done,
+	// "complete" to move it to its team's completed state, or empty to leave
+	// it alone.
+	OnDone string
+}
+
+func (l *Linear) Name() string {
+	return "Linear"
+}
+
+func (l *Linear) Owns(link string) bool {
+	return strings.HasPrefix(link, "https://linear.app/")
+}
+
+func (l *Linear) Open() ([]Ticket, error) {
+	var result struct {
+		Viewer struct {
+			AssignedIssues struct {
+				Nodes []struct {
+					Identifier string `json:"identifier"`
+					Title      string `json:"title"`
+					URL        string `json:"url"`
+				} `json:"nodes"`
+			} `json:"assignedIssues"`
+		} `json:"viewer"`
+	}
+
+	query := `query {
+  viewer {
+    assignedIssues(first: 100, filter: {state: {type: {nin: ["completed", "canceled"]}}}) {
+      nodes { identifier title url }
+    }
+  }
+}`
+	if err := l.graphql(query, nil, &result); err != nil {
+		return nil, err
+	}
+
+	tickets := []Ticket{}
+	for _, issue := range result.Viewer.AssignedIssues.Nodes {
+		tickets = append(tickets, Ticket{Key: issue.Identifier, Title: issue.Title, URL: issue.URL})
+	}
+
+	return tickets, nil
+}
+
+func (l *Linear) Done(t Ticket) error {
+	if l.OnDone == "" {
+		return nil
+	}
+
+	var issue struct {
+		Issue struct {
+			ID   string `json:"id"`
+			Team struct {
+				States struct {
+					Nodes []struct {
+						ID string `json:"id"`
+					} `json:"nodes"`
+				} `json:"states"`
+			} `json:"team"`
+		} `json:"issue"`
+	}
+
+	query := `query($id: String!) {
+  issue(id: $id) {
+    id
+    team { states(filter: {type: {eq: "completed"}}) { nodes { id } } }
+  }
+}`
+	if err := l.graphql(query, map[string]interface{}{"id": t.Key}, &issue); err != nil {
+		return err
+	}
+
+	switch l.OnDone {
+	case "comment":
+		mutation := `mutation($id: String!, $body: String!) {
+  commentCreate(input: {issueId: $id, body: $body}) { success }
+}`
+		return l.graphql(mutation, map[string]interface{}{"id": issue.Issue.ID, "body": "Done, according to my notes."}, nil)
+	case "complete":
+		states := issue.Issue.Team.States.Nodes
+		if len(states) == 0 {
+			return fmt.Errorf("the team of %s has no completed state", t.Key)
+		}
+
+		mutation := `mutation($id: String!, $state: String!) {
+  issueUpdate(id: $id, input: {stateId: $state}) { success }
+}`
+		return l.graphql(mutation, map[string]interface{}{"id": issue.Issue.ID, "state": states[0].ID}, nil)
+	}
+
+	return fmt.Errorf("unknown on_done action %q for Linear, expected comment or complete", l.OnDone)
+}
+
+func (l *Linear) graphql(query string, variables map[string]interface{}, data interface{}) error {
+	var result struct {
+		Data   interface{} `json:"data"`
+		Errors []struct {
+			Message string `json:"message"`
+		} `json:"errors"`
+	}
+	result.Data = data
+
+	body := map[string]interface{}{"query": query, "variables": variables}
+	if err := send("POST", linearAPI, map[string]string{"Authorization": l.Token}, body, &result); err != nil {
+		return err
+	}
+
+	if len(result.Errors) > 0 {
+		return fmt.Errorf("error from Linear: %s", result.Errors[0].Message)
+	}
+
+	return nil
+}
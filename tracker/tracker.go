@@ -0,0 +1,109 @@
+package tracker
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"regexp"
+	"time"
+
+	"github.com/t-eckert/nb/network"
+)
+
+// Section is the heading of the daily log that open tickets are pulled into.
+const Section = "Work"
+
+// Ticket is an issue assigned to the user in a tracker.
+type Ticket struct {
+	Key   string
+	Title string
+	URL   string
+}
+
+// Line is the task line for the ticket, linking to it by its key.
+func (t Ticket) Line() string {
+	return fmt.Sprintf("[%s](%s) %s", t.Key, t.URL, t.Title)
+}
+
+// Tracker is an issue tracker that open tickets are pulled from. Done is
+// called when the task for one of its tickets is completed, and does whatever
+// the tracker is configured to do then, which may be nothing.
+type Tracker interface {
+	Name() string
+	Open() ([]Ticket, error)
+	Owns(url string) bool
+	Done(t Ticket) error
+}
+
+var ticketLink = regexp.MustCompile(`\[([A-Za-z][A-Za-z0-9]*-\d+)\]\((https?://[^)\s]+)\)`)
+
+// FromText finds a ticket link, as written by Line, in the text of a task.
+func FromText(text string) (Ticket, bool) {
+	match := ticketLink.FindStringSubmatch(text)
+	if match == nil {
+		return Ticket{}, false
+	}
+
+	return Ticket{Key: match[1], URL: match[2]}, true
+}
+
+// For returns the tracker that a ticket belongs to.
+func For(trackers []Tracker, t Ticket) (Tracker, bool) {
+	for _, tr := range trackers {
+		if tr.Owns(t.URL) {
+			return tr, true
+		}
+	}
+
+	return nil, false
+}
+
+// send makes a JSON request and decodes the JSON response into out, which may
+// be nil.
+func send(method, url string, headers map[string]string, in, out interface{}) error {
+	var body io.Reader
+	if in != nil {
+		encoded, err := json.Marshal(in)
+		if err != nil {
+			return err
+		}
+		body = bytes.NewReader(encoded)
+	}
+
+	req, err := http.NewRequest(method, url, body)
+	if err != nil {
+		return err
+	}
+
+	req.Header.Set("Accept", "application/json")
+	if in != nil {
+		req.Header.Set("Content-Type", "application/json")
+	}
+	for name, value := range headers {
+		req.Header.Set(name, value)
+	}
+
+	client, err := network.Client(30 * time.Second)
+	if err != nil {
+		return err
+	}
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		detail, _ := io.ReadAll(io.LimitReader(resp.Body, 512))
+		return fmt.Errorf("%s %s: %s %s", method, url, resp.Status, bytes.TrimSpace(detail))
+	}
+
+	if out == nil {
+		return nil
+	}
+
+	return json.NewDecoder(resp.Body).Decode(out)
+}
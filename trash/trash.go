@@ -0,0 +1,160 @@
+package trash
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+)
+
+// Item is a note in the trash. Path is where it lived, from the notebook root.
+type Item struct {
+	ID        string    `json:"id"`
+	Path      string    `json:"path"`
+	DeletedAt time.Time `json:"deleted_at"`
+}
+
+// Dir is the trash folder. Being hidden, its notes are left out of everything
+// else that reads the notebook.
+func Dir(root string) string {
+	return filepath.Join(root, ".trash")
+}
+
+// Remove moves the note at path into the trash next to a tombstone recording
+// where it came from.
+func Remove(root, path string, now time.Time) (Item, error) {
+	rel, err := filepath.Rel(root, path)
+	if err != nil || strings.HasPrefix(rel, "..") {
+		return Item{}, fmt.Errorf("%s is not in the notebook", path)
+	}
+
+	sum := sha1.Sum([]byte(rel + now.String()))
+	item := Item{ID: hex.EncodeToString(sum[:])[:6], Path: filepath.ToSlash(rel), DeletedAt: now}
+
+	if err := os.MkdirAll(Dir(root), 0755); err != nil {
+		return Item{}, err
+	}
+
+	tombstone, err := json.MarshalIndent(item, "", "  ")
+	if err != nil {
+		return Item{}, err
+	}
+
+	if err := os.WriteFile(tombstonePath(root, item.ID), tombstone, 0644); err != nil {
+		return Item{}, err
+	}
+
+	if err := os.Rename(path, contentPath(root, item.ID)); err != nil {
+		os.Remove(tombstonePath(root, item.ID))
+		return Item{}, err
+	}
+
+	return item, nil
+}
+
+// List returns the items in the trash, most recently deleted first.
+func List(root string) ([]Item, error) {
+	paths, err := filepath.Glob(filepath.Join(Dir(root), "*.json"))
+	if err != nil {
+		return nil, err
+	}
+
+	items := []Item{}
+	for _, path := range paths {
+		content, err := os.ReadFile(path)
+		if err != nil {
+			return nil, err
+		}
+
+		var item Item
+		if err := json.Unmarshal(content, &item); err != nil {
+			return nil, fmt.Errorf("could not read %s: %v", path, err)
+		}
+		items = append(items, item)
+	}
+
+	sort.Slice(items, func(i, j int) bool {
+		return items[i].DeletedAt.After(items[j].DeletedAt)
+	})
+
+	return items, nil
+}
+
+// Restore moves the item back to where it was deleted from, unless another
+// note has taken its place since.
+func Restore(root, id string) (Item, error) {
+	item, err := find(root, id)
+	if err != nil {
+		return Item{}, err
+	}
+
+	path := filepath.Join(root, filepath.FromSlash(item.Path))
+	if _, err := os.Stat(path); err == nil {
+		return Item{}, fmt.Errorf("%s already exists", path)
+	}
+
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return Item{}, err
+	}
+
+	if err := os.Rename(contentPath(root, item.ID), path); err != nil {
+		return Item{}, err
+	}
+
+	return item, os.Remove(tombstonePath(root, item.ID))
+}
+
+// Empty permanently deletes the items deleted before the given time and
+// returns how many there were.
+func Empty(root string, before time.Time) (int, error) {
+	items, err := List(root)
+	if err != nil {
+		return 0, err
+	}
+
+	removed := 0
+	for _, item := range items {
+		if !item.DeletedAt.Before(before) {
+			continue
+		}
+
+		if err := os.Remove(contentPath(root, item.ID)); err != nil && !os.IsNotExist(err) {
+			return removed, err
+		}
+
+		if err := os.Remove(tombstonePath(root, item.ID)); err != nil {
+			return removed, err
+		}
+		removed++
+	}
+
+	return removed, nil
+}
+
+func find(root, id string) (Item, error) {
+	items, err := List(root)
+	if err != nil {
+		return Item{}, err
+	}
+
+	for _, item := range items {
+		if item.ID == id {
+			return item, nil
+		}
+	}
+
+	return Item{}, fmt.Errorf("no item %s in the trash", id)
+}
+
+func contentPath(root, id string) string {
+	return filepath.Join(Dir(root), id+".md")
+}
+
+func tombstonePath(root, id string) string {
+	return filepath.Join(Dir(root), id+".json")
+}
@@ -0,0 +1,88 @@
+package ui
+
+import (
+	"os"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/config"
+	"github.com/t-eckert/nb/date"
+	"github.com/t-eckert/nb/render"
+	"github.com/t-eckert/nb/terminal"
+)
+
+var colors = map[string]string{
+	"black":   "30",
+	"red":     "31",
+	"green":   "32",
+	"yellow":  "33",
+	"blue":    "34",
+	"magenta": "35",
+	"cyan":    "36",
+	"white":   "37",
+}
+
+// Color reports whether output should be colored. NO_COLOR turns color off
+// whatever the config says.
+func Color() bool {
+	if _, ok := os.LookupEnv("NO_COLOR"); ok {
+		return false
+	}
+
+	switch strings.ToLower(config.UIColor()) {
+	case "on", "always", "true":
+		return true
+	case "off", "never", "false":
+		return false
+	}
+
+	return terminal.IsTerminal()
+}
+
+// Style is the style notes are rendered with, using the accent color for
+// headings.
+func Style() render.Style {
+	if !Color() {
+		return render.Plain
+	}
+
+	s := render.Colors
+	if code, ok := colors[strings.ToLower(config.UIAccent())]; ok {
+		s.Heading = "\033[1;" + code + "m"
+	}
+
+	return s
+}
+
+// Accent colors text with the accent color.
+func Accent(text string) string {
+	code, ok := colors[strings.ToLower(config.UIAccent())]
+	if !ok {
+		code = colors["cyan"]
+	}
+
+	return paint(code, text)
+}
+
+// Dim fades text that is there for reference.
+func Dim(text string) string {
+	return paint("2", text)
+}
+
+// Error colors the prefix of error messages.
+func Error(text string) string {
+	return paint("1;31", text)
+}
+
+// Date formats a date with the configured layout and locale.
+func Date(t time.Time) string {
+	return date.Format(t, config.UIDateFormat(), config.Locale())
+}
+
+func paint(code, text string) string {
+	if !Color() || text == "" {
+		return text
+	}
+
+	return "\033[" + code + "m" + text + "\033[0m"
+}
@@ -0,0 +1,92 @@
+package vcard
+
+import (
+	"io"
+	"regexp"
+	"strings"
+
+	"github.com/t-eckert/nb/rfc"
+)
+
+// Card holds the fields of a vCard that nb keeps on People pages. Birthday is
+// either YYYY-MM-DD or --MM-DD when the year is not known.
+type Card struct {
+	Name     string
+	Email    string
+	Org      string
+	Phone    string
+	Birthday string
+}
+
+var (
+	fullDate   = regexp.MustCompile(`^(\d{4})-?(\d{2})-?(\d{2})`)
+	noYearDate = regexp.MustCompile(`^--(\d{2})-?(\d{2})`)
+)
+
+// Parse reads every card in a vCard (3.0 or 4.0) stream.
+func Parse(r io.Reader) ([]Card, error) {
+	lines, err := rfc.Unfold(r)
+	if err != nil {
+		return nil, err
+	}
+
+	cards := []Card{}
+	var card *Card
+	for _, line := range lines {
+		name, _, value, ok := rfc.Property(line)
+		if !ok {
+			continue
+		}
+		value = strings.TrimSpace(value)
+
+		switch name {
+		case "BEGIN":
+			card = &Card{}
+		case "END":
+			if card != nil && card.Name != "" {
+				cards = append(cards, *card)
+			}
+			card = nil
+		}
+
+		if card == nil {
+			continue
+		}
+
+		switch name {
+		case "FN":
+			card.Name = singleLine(rfc.Unescape(value))
+		case "EMAIL":
+			if card.Email == "" {
+				card.Email = value
+			}
+		case "TEL":
+			if card.Phone == "" {
+				card.Phone = value
+			}
+		case "ORG":
+			card.Org = singleLine(rfc.Unescape(strings.Split(value, ";")[0]))
+		case "BDAY":
+			card.Birthday = birthday(value)
+		}
+	}
+
+	return cards, nil
+}
+
+func birthday(value string) string {
+	if match := fullDate.FindStringSubmatch(value); match != nil {
+		return match[1] + "-" + match[2] + "-" + match[3]
+	}
+
+	if match := noYearDate.FindStringSubmatch(value); match != nil {
+		return "--" + match[1] + "-" + match[2]
+	}
+
+	return ""
+}
+
+// singleLine puts the lines of a value that nb keeps on one line together.
+func singleLine(value string) string {
+	return strings.ReplaceAll(value, "\n", " ")
+}
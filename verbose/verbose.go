@@ -0,0 +1,56 @@
+package verbose
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// How much nb says about what it is doing, on stderr.
+const (
+	Quiet = 0
+	Debug = 1
+	Trace = 2
+)
+
+var level = Quiet
+
+// FromEnv reads a level from the NB_VERBOSE environment variable, which can
+// be debug, trace, or a number.
+func FromEnv() int {
+	value := strings.ToLower(strings.TrimSpace(os.Getenv("NB_VERBOSE")))
+	switch value {
+	case "debug":
+		return Debug
+	case "trace":
+		return Trace
+	}
+
+	n, _ := strconv.Atoi(value)
+	return n
+}
+
+// SetLevel sets how much is written: Debug for -v and Trace for -vv.
+func SetLevel(l int) {
+	level = l
+}
+
+// Debugf writes a message when running with -v or more.
+func Debugf(format string, args ...interface{}) {
+	write(Debug, "debug", format, args...)
+}
+
+// Tracef writes a message when running with -vv.
+func Tracef(format string, args ...interface{}) {
+	write(Trace, "trace", format, args...)
+}
+
+func write(l int, name, format string, args ...interface{}) {
+	if level < l {
+		return
+	}
+
+	fmt.Fprintf(os.Stderr, "%s %s: %s\n", time.Now().Format("15:04:05.000"), name, fmt.Sprintf(format, args...))
+}
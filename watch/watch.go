@@ -0,0 +1,117 @@
+package watch
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/fsnotify/fsnotify"
+)
+
+// Debounce is how long the watcher waits for the notebook to settle before
+// reporting changes, so that an editor saving a file in several steps only
+// causes one update.
+const Debounce = 500 * time.Millisecond
+
+// Watcher reports the markdown files that change under a directory.
+type Watcher struct {
+	Changes <-chan []string
+	Errors  <-chan error
+
+	fs *fsnotify.Watcher
+}
+
+// New watches root and every directory below it, except hidden ones.
+// Directories created later are watched as they appear.
+func New(root string) (*Watcher, error) {
+	fs, err := fsnotify.NewWatcher()
+	if err != nil {
+		return nil, err
+	}
+
+	if err := addTree(fs, root); err != nil {
+		fs.Close()
+		return nil, err
+	}
+
+	changes := make(chan []string)
+	errs := make(chan error)
+	go run(fs, changes, errs)
+
+	return &Watcher{Changes: changes, Errors: errs, fs: fs}, nil
+}
+
+// Close stops watching.
+func (w *Watcher) Close() error {
+	return w.fs.Close()
+}
+
+func run(fs *fsnotify.Watcher, changes chan<- []string, errs chan<- error) {
+	defer close(changes)
+	defer close(errs)
+
+	pending := map[string]bool{}
+	timer := time.NewTimer(Debounce)
+	timer.Stop()
+
+	for {
+		select {
+		case event, ok := <-fs.Events:
+			if !ok {
+				return
+			}
+
+			if event.Op&fsnotify.Create != 0 {
+				if info, err := os.Stat(event.Name); err == nil && info.IsDir() {
+					addTree(fs, event.Name)
+					continue
+				}
+			}
+
+			if filepath.Ext(event.Name) != ".md" || hidden(filepath.Base(event.Name)) {
+				continue
+			}
+
+			pending[event.Name] = true
+			timer.Reset(Debounce)
+
+		case err, ok := <-fs.Errors:
+			if !ok {
+				return
+			}
+			errs <- err
+
+		case <-timer.C:
+			paths := []string{}
+			for path := range pending {
+				paths = append(paths, path)
+			}
+			pending = map[string]bool{}
+
+			changes <- paths
+		}
+	}
+}
+
+func addTree(fs *fsnotify.Watcher, root string) error {
+	return filepath.Walk(root, func(path string, info os.FileInfo, err error) error {
+		if err != nil {
+			return err
+		}
+
+		if !info.IsDir() {
+			return nil
+		}
+
+		if path != root && hidden(info.Name()) {
+			return filepath.SkipDir
+		}
+
+		return fs.Add(path)
+	})
+}
+
+func hidden(name string) bool {
+	return strings.HasPrefix(name, ".")
+}
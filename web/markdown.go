@@ -0,0 +1,299 @@
+package web
+
+import (
+	"html"
+	"net/url"
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+type token struct {
+	text  string
+	tag   string
+	attrs map[string]string
+	end   bool
+}
+
+var (
+	tagPattern  = regexp.MustCompile(`(?s)<!--.*?-->|<(/?)([a-zA-Z][a-zA-Z0-9]*)((?:[^>"']|"[^"]*"|'[^']*')*)>`)
+	attrPattern = regexp.MustCompile(`([a-zA-Z_:][-a-zA-Z0-9_:.]*)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+)))?`)
+	blankLines  = regexp.MustCompile(`\n{3,}`)
+)
+
+// skipped elements hold no readable content.
+var skipped = map[string]bool{
+	"script": true, "style": true, "noscript": true, "svg": true, "nav": true,
+	"header": true, "footer": true, "aside": true, "form": true, "button": true,
+	"iframe": true, "template": true, "head": true,
+}
+
+var voids = map[string]bool{
+	"br": true, "hr": true, "img": true, "input": true, "meta": true, "link": true,
+	"source": true, "wbr": true, "area": true, "base": true, "col": true, "embed": true,
+}
+
+// Markdown converts the main content of a page to markdown. The first
+// <article>, or failing that <main> or <body>, is taken as the content, and
+// navigation, scripts, and other page furniture are dropped. Relative links
+// are resolved against base.
+func Markdown(page []byte, base string) string {
+	content := mainContent(string(page))
+	baseURL, _ := url.Parse(base)
+
+	c := &converter{base: baseURL}
+	for _, t := range tokenize(content) {
+		c.handle(t)
+	}
+
+	out := blankLines.ReplaceAllString(c.out.String(), "\n\n")
+	lines := strings.Split(out, "\n")
+	for i, line := range lines {
+		lines[i] = strings.TrimRight(line, " \t")
+	}
+
+	return strings.TrimSpace(strings.Join(lines, "\n")) + "\n"
+}
+
+func mainContent(page string) string {
+	lower := strings.ToLower(page)
+
+	for _, tag := range []string{"article", "main", "body"} {
+		start := strings.Index(lower, "<"+tag)
+		if start == -1 {
+			continue
+		}
+
+		end := strings.LastIndex(lower, "</"+tag+">")
+		if end == -1 || end < start {
+			end = len(page)
+		}
+
+		return page[start:end]
+	}
+
+	return page
+}
+
+func tokenize(content string) []token {
+	tokens := []token{}
+
+	last := 0
+	for _, m := range tagPattern.FindAllStringSubmatchIndex(content, -1) {
+		if m[0] > last {
+			tokens = append(tokens, token{text: content[last:m[0]]})
+		}
+		last = m[1]
+
+		// Comments have no tag name.
+		if m[4] == -1 {
+			continue
+		}
+
+		t := token{
+			tag:   strings.ToLower(content[m[4]:m[5]]),
+			end:   m[3] > m[2],
+			attrs: map[string]string{},
+		}
+		for _, a := range attrPattern.FindAllStringSubmatch(content[m[6]:m[7]], -1) {
+			t.attrs[strings.ToLower(a[1])] = html.UnescapeString(a[2] + a[3] + a[4])
+		}
+		tokens = append(tokens, t)
+	}
+
+	if last < len(content) {
+		tokens = append(tokens, token{text: content[last:]})
+	}
+
+	return tokens
+}
+
+type list struct {
+	ordered bool
+	count   int
+}
+
+type converter struct {
+	out   strings.Builder
+	base  *url.URL
+	skip  []string
+	pre   bool
+	lists []list
+	links []string
+}
+
+func (c *converter) handle(t token) {
+	if len(c.skip) > 0 {
+		if t.tag == c.skip[len(c.skip)-1] {
+			if t.end {
+				c.skip = c.skip[:len(c.skip)-1]
+			} else {
+				c.skip = append(c.skip, t.tag)
+			}
+		}
+		return
+	}
+
+	if t.tag == "" {
+		c.text(t.text)
+		return
+	}
+
+	if skipped[t.tag] && !t.end && !voids[t.tag] {
+		c.skip = append(c.skip, t.tag)
+		return
+	}
+
+	switch t.tag {
+	case "h1", "h2", "h3", "h4", "h5", "h6":
+		c.block()
+		if !t.end {
+			c.out.WriteString(strings.Repeat("#", int(t.tag[1]-'0')) + " ")
+		}
+	case "p", "div", "section", "table", "tr", "figure", "dl":
+		c.block()
+	case "blockquote":
+		c.block()
+		if !t.end {
+			c.out.WriteString("> ")
+		}
+	case "br":
+		c.out.WriteString("\n")
+	case "hr":
+		c.block()
+		c.out.WriteString("---")
+		c.block()
+	case "pre":
+		if t.end {
+			c.pre = false
+			c.newline()
+			c.out.WriteString("```")
+			c.block()
+		} else {
+			c.block()
+			c.out.WriteString("```\n")
+			c.pre = true
+		}
+	case "code":
+		if !c.pre {
+			c.out.WriteString("`")
+		}
+	case "strong", "b":
+		c.out.WriteString("**")
+	case "em", "i":
+		c.out.WriteString("*")
+	case "ul", "ol":
+		if t.end {
+			if len(c.lists) > 0 {
+				c.lists = c.lists[:len(c.lists)-1]
+			}
+			if len(c.lists) == 0 {
+				c.block()
+			}
+		} else {
+			if len(c.lists) == 0 {
+				c.block()
+			}
+			c.lists = append(c.lists, list{ordered: t.tag == "ol"})
+		}
+	case "li":
+		if t.end || len(c.lists) == 0 {
+			return
+		}
+		l := &c.lists[len(c.lists)-1]
+		l.count++
+
+		c.newline()
+		c.out.WriteString(strings.Repeat("  ", len(c.lists)-1))
+		if l.ordered {
+			c.out.WriteString(strconv.Itoa(l.count) + ". ")
+		} else {
+			c.out.WriteString("- ")
+		}
+	case "a":
+		if t.end {
+			if len(c.links) > 0 {
+				href := c.links[len(c.links)-1]
+				c.links = c.links[:len(c.links)-1]
+				if href != "" {
+					c.out.WriteString("](" + href + ")")
+				}
+			}
+			return
+		}
+
+		href := c.resolve(t.attrs["href"])
+		if strings.HasPrefix(href, "javascript:") || strings.HasPrefix(t.attrs["href"], "#") {
+			href = ""
+		}
+		c.links = append(c.links, href)
+		if href != "" {
+			c.out.WriteString("[")
+		}
+	case "img":
+		if src := c.resolve(t.attrs["src"]); src != "" {
+			c.out.WriteString("![" + t.attrs["alt"] + "](" + src + ")")
+		}
+	}
+}
+
+func (c *converter) text(raw string) {
+	text := html.UnescapeString(raw)
+	if c.pre {
+		c.out.WriteString(text)
+		return
+	}
+
+	text = strings.Join(strings.Fields(text), " ")
+	if text == "" {
+		if strings.TrimSpace(raw) != raw && raw != "" && !c.afterSpace() {
+			c.out.WriteString(" ")
+		}
+		return
+	}
+
+	if raw[0] == ' ' || raw[0] == '\n' || raw[0] == '\t' {
+		if !c.afterSpace() {
+			text = " " + text
+		}
+	}
+	last := raw[len(raw)-1]
+	if last == ' ' || last == '\n' || last == '\t' {
+		text += " "
+	}
+
+	c.out.WriteString(text)
+}
+
+// afterSpace reports whether the output is empty or ends in whitespace, so
+// that text does not need a space in front of it.
+func (c *converter) afterSpace() bool {
+	s := c.out.String()
+	return s == "" || strings.HasSuffix(s, "\n") || strings.HasSuffix(s, " ")
+}
+
+func (c *converter) newline() {
+	if s := c.out.String(); s != "" && !strings.HasSuffix(s, "\n") {
+		c.out.WriteString("\n")
+	}
+}
+
+func (c *converter) block() {
+	if c.out.Len() == 0 {
+		return
+	}
+	c.out.WriteString("\n\n")
+}
+
+func (c *converter) resolve(ref string) string {
+	if ref == "" || c.base == nil {
+		return ref
+	}
+
+	parsed, err := url.Parse(ref)
+	if err != nil {
+		return ref
+	}
+
+	return c.base.ResolveReference(parsed).String()
+}
@@ -0,0 +1,26 @@
+package web
+
+import (
+	"testing"
+)
+
+func TestMarkdown(t *testing.T) {
+	given := `<html><head><title>Post</title></head><body>
+<nav><a href="/">Home</a></nav>
+<article>
+  <h1>A  Post</h1>
+  <p>Some <strong>bold</strong> text with a <a href="/other">link</a>.</p>
+  <ul><li>one</li><li>two</li></ul>
+  <pre><code>x := 1
+</code></pre>
+  <script>track()</script>
+</article>
+</body></html>`
+	expected := "# A Post\n\nSome **bold** text with a [link](https://example.com/other).\n\n- one\n- two\n\n```\nx := 1\n```\n"
+
+	actual := Markdown([]byte(given), "https://example.com/post")
+
+	if expected != actual {
+		t.Fatalf("given: %s\nexpected: %q\nactual: %q\n", given, expected, actual)
+	}
+}
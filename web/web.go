@@ -0,0 +1,49 @@
+package web
+
+import (
+	"fmt"
+	"html"
+	"io"
+	"net/http"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/network"
+)
+
+// maxPageSize keeps a misbehaving server from filling memory.
+const maxPageSize = 10 << 20
+
+// Fetch downloads the page at url.
+func Fetch(url string) ([]byte, error) {
+	client, err := network.Client(30 * time.Second)
+	if err != nil {
+		return nil, err
+	}
+
+	resp, err := client.Get(url)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("unexpected status %s", resp.Status)
+	}
+
+	return io.ReadAll(io.LimitReader(resp.Body, maxPageSize))
+}
+
+var title = regexp.MustCompile(`(?is)<title[^>]*>(.*?)</title>`)
+
+// Title is the text of the <title> element of a page, or empty when it has
+// none.
+func Title(page []byte) string {
+	match := title.FindSubmatch(page)
+	if match == nil {
+		return ""
+	}
+
+	return strings.Join(strings.Fields(html.UnescapeString(string(match[1]))), " ")
+}
@@ -0,0 +1,58 @@
+package zk
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/t-eckert/nb/hook"
+	"github.com/t-eckert/nb/link"
+	"github.com/t-eckert/nb/note"
+)
+
+// New creates a note in folder under root with a fresh id in its frontmatter
+// and returns its path. The note is named after its title, or its id when it
+// has no title. Links to the note can use the id, as in `[[202503041231]]`,
+// and keep working when the note is renamed.
+func New(root, folder, title string, now time.Time) (string, error) {
+	paths, err := note.Paths(root)
+	if err != nil {
+		return "", err
+	}
+	resolver := link.NewResolver(root, paths)
+
+	id := note.NewID(now, resolver.IsID)
+
+	name := strings.ReplaceAll(title, "/", "-")
+	if name == "" {
+		name = id
+	}
+
+	dir := filepath.Join(root, folder)
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return "", err
+	}
+
+	path := filepath.Join(dir, name+".md")
+	if _, err := os.Stat(path); err == nil {
+		return "", fmt.Errorf("%s already exists", path)
+	}
+
+	n := &note.Note{Path: path}
+	n.Frontmatter.Set("id", id)
+	if title != "" {
+		n.Frontmatter.Set("title", title)
+	}
+	n.Body = "# " + title + "\n\n"
+	if title == "" {
+		n.Body = "# " + id + "\n\n"
+	}
+
+	if err := n.Write(); err != nil {
+		return "", err
+	}
+
+	return path, hook.Run(hook.PostNoteCreate, path)
+}